@@ -7,6 +7,9 @@ pub mod secret;
 use axum::extract::State;
 use axum_extra::TypedHeader;
 use ploys::client::{Client, Token};
+use ploys::package::{Package, PackageKind};
+use ploys::project::{Project, ReleaseRequest};
+use ploys::repository::Repository;
 use semver::Version;
 use tracing::{debug, error, instrument};
 
@@ -66,6 +69,9 @@ async fn create_release(
 }
 
 /// Creates a new release.
+///
+/// This creates a release for every package listed in the release request
+/// body, falling back to the package and version in the branch name.
 fn create_release_sync(
     token: Token,
     release: String,
@@ -74,28 +80,126 @@ fn create_release_sync(
     let client = Client::build().with_access_token_flow(token).finished()?;
     let project = client.get_project(&payload.repository.full_name)?;
 
-    let package = project
-        .packages()
-        .find(|package| match package.is_primary() {
-            true => release.parse::<Version>().ok() == Some(package.version()),
-            false => {
-                release.starts_with(package.name())
-                    && release.as_bytes().get(package.name().len()) == Some(&b'-')
-                    && release[package.name().len() + 1..].parse::<Version>().ok()
-                        == Some(package.version())
-            }
-        })
-        .ok_or(ploys::package::Error::NotFound(release))
+    let packages = find_release_packages(&project, payload.pull_request.body.as_deref(), release)
         .map_err(ploys::project::Error::Package)?;
 
-    project
-        .create_package_release(package.name())?
-        .finish()
-        .map_err(ploys::project::Error::Repository)?;
+    for (package, version) in packages {
+        let result = project
+            .create_package_release(&package)
+            .and_then(|builder| builder.version(version).finish());
+
+        if let Err(err) = result {
+            error!(package, "Error creating release: {err}");
+        }
+    }
 
     Ok(())
 }
 
+/// Finds the packages and versions to release.
+///
+/// The packages listed in the release request body are only released when the
+/// version matches the package on the default branch so that an edited body
+/// cannot release arbitrary versions. Go modules have no manifest version and
+/// must instead match the version planned in the release branch name.
+fn find_release_packages<T>(
+    project: &Project<T>,
+    body: Option<&str>,
+    release: String,
+) -> Result<Vec<(String, Version)>, ploys::package::Error<T::Error>>
+where
+    T: Repository,
+{
+    let planned = find_branch_packages(project, &release);
+
+    let Some(packages) = body.and_then(ReleaseRequest::parse_packages) else {
+        let packages = planned
+            .iter()
+            .filter(|(name, version)| {
+                project
+                    .get_package(name)
+                    .is_some_and(|package| is_release_version(&package, version, &planned))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        return match packages.is_empty() {
+            true => Err(ploys::package::Error::NotFound(release)),
+            false => Ok(packages),
+        };
+    };
+
+    let packages = packages
+        .into_iter()
+        .filter(|(name, version)| match project.get_package(name) {
+            Some(package) if is_release_version(&package, version, &planned) => true,
+            Some(package) => {
+                error!(
+                    package = name,
+                    "Skipping release of version {version} for package version {}",
+                    package.version()
+                );
+
+                false
+            }
+            None => {
+                error!(package = name, "Skipping release of missing package");
+
+                false
+            }
+        })
+        .collect();
+
+    Ok(packages)
+}
+
+/// Finds the packages and versions from the release branch name.
+///
+/// The branch name lists the version of the primary package or the name and
+/// version of each package separated by `+`.
+fn find_branch_packages<T>(project: &Project<T>, release: &str) -> Vec<(String, Version)>
+where
+    T: Repository,
+{
+    release
+        .split('+')
+        .filter_map(|entry| {
+            project.packages().find_map(|package| {
+                let version = match package.is_primary() {
+                    true => entry.parse::<Version>().ok()?,
+                    false => entry
+                        .strip_prefix(package.name())?
+                        .strip_prefix('-')?
+                        .parse::<Version>()
+                        .ok()?,
+                };
+
+                Some((package.name().to_owned(), version))
+            })
+        })
+        .collect()
+}
+
+/// Checks if the version is the release version of the package.
+///
+/// Go modules are versioned by tags that do not exist until release so the
+/// version must be the one planned in the release branch name.
+fn is_release_version<T>(
+    package: &Package<T>,
+    version: &Version,
+    planned: &[(String, Version)],
+) -> bool {
+    match package.kind() {
+        PackageKind::Go => {
+            *version > package.version()
+                && planned
+                    .iter()
+                    .any(|(name, planned)| name == package.name() && planned == version)
+        }
+        _ => package.version() == *version,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -105,6 +209,9 @@ mod tests {
     use axum::routing::post;
     use axum::{Extension, Router};
     use hmac::{Hmac, Mac};
+    use ploys::package::Package;
+    use ploys::project::Project;
+    use semver::Version;
     use serde_json::{Value, json};
     use sha2::Sha256;
     use tower_service::Service;
@@ -200,4 +307,100 @@ mod tests {
 
         assert_eq!(response.status(), StatusCode::FORBIDDEN);
     }
+
+    #[test]
+    fn test_find_release_packages() {
+        let mut project = Project::new("example");
+
+        project
+            .add_package(Package::new_cargo("example-one").with_version(Version::new(0, 2, 0)))
+            .unwrap();
+        project
+            .add_package(Package::new_cargo("example-two").with_version(Version::new(0, 1, 0)))
+            .unwrap();
+
+        let body = "<!-- ploys-release: example-one@0.2.0, example-two@1.0.0, missing@0.1.0 -->";
+
+        assert_eq!(
+            super::find_release_packages(&project, Some(body), String::from("example-two-1.0.0"))
+                .unwrap(),
+            [(String::from("example-one"), Version::new(0, 2, 0))]
+        );
+        assert_eq!(
+            super::find_release_packages(&project, None, String::from("example-one-0.2.0"))
+                .unwrap(),
+            [(String::from("example-one"), Version::new(0, 2, 0))]
+        );
+        assert!(
+            super::find_release_packages(&project, None, String::from("example-two-1.0.0"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_find_release_packages_go() {
+        let mut project = Project::new("example");
+
+        project
+            .add_package(Package::new_go("github.com/ploys/example-one"))
+            .unwrap();
+
+        let body = "<!-- ploys-release: example-one@0.3.0 -->";
+
+        assert_eq!(
+            super::find_release_packages(&project, Some(body), String::from("example-one-0.2.0"))
+                .unwrap(),
+            []
+        );
+
+        let body = "<!-- ploys-release: example-one@0.2.0 -->";
+
+        assert_eq!(
+            super::find_release_packages(&project, Some(body), String::from("example-one-0.2.0"))
+                .unwrap(),
+            [(String::from("example-one"), Version::new(0, 2, 0))]
+        );
+        assert_eq!(
+            super::find_release_packages(&project, None, String::from("example-one-0.2.0"))
+                .unwrap(),
+            [(String::from("example-one"), Version::new(0, 2, 0))]
+        );
+    }
+
+    #[test]
+    fn test_find_release_packages_combined() {
+        let mut project = Project::new("example");
+
+        project
+            .add_package(Package::new_go("github.com/ploys/example-one"))
+            .unwrap();
+        project
+            .add_package(Package::new_cargo("example-two").with_version(Version::new(0, 2, 0)))
+            .unwrap();
+
+        let release = String::from("example-one-0.2.0+example-two-0.2.0");
+        let body = "<!-- ploys-release: example-one@0.2.0, example-two@0.2.0 -->";
+
+        assert_eq!(
+            super::find_release_packages(&project, Some(body), release.clone()).unwrap(),
+            [
+                (String::from("example-one"), Version::new(0, 2, 0)),
+                (String::from("example-two"), Version::new(0, 2, 0)),
+            ]
+        );
+
+        let body = "<!-- ploys-release: example-one@1.0.0, example-two@0.2.0 -->";
+
+        assert_eq!(
+            super::find_release_packages(&project, Some(body), release.clone()).unwrap(),
+            [(String::from("example-two"), Version::new(0, 2, 0))]
+        );
+        assert_eq!(
+            super::find_release_packages(&project, None, release).unwrap(),
+            [
+                (String::from("example-one"), Version::new(0, 2, 0)),
+                (String::from("example-two"), Version::new(0, 2, 0)),
+            ]
+        );
+    }
 }
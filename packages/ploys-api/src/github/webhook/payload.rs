@@ -102,6 +102,7 @@ pub struct Installation {
 #[derive(Debug, Deserialize)]
 pub struct PullRequest {
     pub head: Branch,
+    pub body: Option<String>,
     pub merged: bool,
 }
 
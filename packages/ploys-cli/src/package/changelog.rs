@@ -1,10 +1,13 @@
 use std::convert::Infallible;
 
 use anyhow::{Error, bail};
-use clap::Args;
+use clap::{Args, ValueEnum};
+use console::style;
+use ploys::changelog::{Diagnostic, Release};
 use ploys::client::{Client, ServAddr, Token};
 use ploys::repository::RepoAddr;
 use semver::Version;
+use serde_json::json;
 
 use crate::auth::init_keyring;
 
@@ -18,17 +21,25 @@ pub struct Changelog {
     package: String,
 
     /// Query the specified version.
-    #[arg(long, conflicts_with_all = ["latest", "unreleased"])]
+    #[arg(long, conflicts_with_all = ["latest", "unreleased", "check"])]
     version: Option<Version>,
 
     /// Query only the latest changes.
-    #[arg(long, conflicts_with_all = ["version", "unreleased"])]
+    #[arg(long, conflicts_with_all = ["version", "unreleased", "check"])]
     latest: bool,
 
     /// Query only the unreleased changes.
-    #[arg(long, conflicts_with_all = ["version", "latest"])]
+    #[arg(long, conflicts_with_all = ["version", "latest", "check"])]
     unreleased: bool,
 
+    /// Check the changelog for formatting problems.
+    #[arg(long, conflicts_with_all = ["version", "latest", "unreleased"])]
+    check: bool,
+
+    /// The output format.
+    #[arg(long, value_enum, default_value_t = Format::Markdown)]
+    format: Format,
+
     /// The management server address.
     #[arg(long, default_value = "api.ploys.dev")]
     server: ServAddr,
@@ -68,7 +79,20 @@ impl Changelog {
                     bail!("Invalid version {version}");
                 };
 
-                println!("{release:#}");
+                print_release(&release.to_owned(), self.format)?;
+            }
+            None if self.check => {
+                let Some(changelog) = package.changelog() else {
+                    bail!("Missing changelog");
+                };
+
+                let diagnostics = changelog.validate_with(&project.changelog_labels());
+
+                print_diagnostics(&diagnostics, self.format)?;
+
+                if !diagnostics.is_empty() {
+                    bail!("Found {} changelog problems", diagnostics.len());
+                }
             }
             None if self.latest => {
                 let Some(changelog) = package.changelog() else {
@@ -79,25 +103,96 @@ impl Changelog {
                     bail!("Missing release");
                 };
 
-                println!("{release:#}");
+                print_release(&release.to_owned(), self.format)?;
             }
             None if self.unreleased => {
                 let version = Version::new(package.version().major + 1, 0, 0);
-                let release = package
-                    .build_release_notes(&version)?
-                    .with_version("Unreleased");
+                let mut changelog = package.changelog().unwrap_or_default();
+                let mut release = changelog
+                    .unreleased()
+                    .map(|release| release.to_owned())
+                    .unwrap_or_else(Release::unreleased);
 
-                println!("{release:#}");
+                release.merge(package.build_release_notes(&version)?);
+                changelog.set_unreleased(release);
+
+                let Some(release) = changelog.unreleased() else {
+                    bail!("Missing unreleased changes");
+                };
+
+                print_release(&release.to_owned(), self.format)?;
             }
             None => {
                 let Some(changelog) = package.changelog() else {
                     bail!("Missing changelog");
                 };
 
-                println!("{changelog:#}");
+                match self.format {
+                    Format::Markdown => println!("{changelog:#}"),
+                    Format::Json => println!("{}", changelog.to_json()?),
+                    Format::Yaml => print!("{}", changelog.to_yaml()?),
+                }
             }
         }
 
         Ok(())
     }
 }
+
+/// The changelog output format.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    Markdown,
+    Json,
+    Yaml,
+}
+
+/// Prints the release in the given format.
+fn print_release(release: &Release, format: Format) -> Result<(), Error> {
+    match format {
+        Format::Markdown => println!("{release:#}"),
+        Format::Json => println!("{}", serde_json::to_string_pretty(release)?),
+        Format::Yaml => print!("{}", serde_yaml_ng::to_string(release)?),
+    }
+
+    Ok(())
+}
+
+/// Prints the changelog diagnostics in the given format.
+fn print_diagnostics(diagnostics: &[Diagnostic], format: Format) -> Result<(), Error> {
+    match format {
+        Format::Markdown => {
+            for diagnostic in diagnostics {
+                println!("{}: {diagnostic}", style("warning").yellow().bold());
+            }
+
+            if diagnostics.is_empty() {
+                println!("Changelog is valid");
+            }
+        }
+        Format::Json | Format::Yaml => {
+            let diagnostics = diagnostics
+                .iter()
+                .map(|diagnostic| {
+                    json!({
+                        "message": diagnostic.kind().to_string(),
+                        "line": diagnostic.line(),
+                        "column": diagnostic.column(),
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let output = json!({
+                "valid": diagnostics.is_empty(),
+                "diagnostics": diagnostics,
+            });
+
+            match format {
+                Format::Yaml => print!("{}", serde_yaml_ng::to_string(&output)?),
+                _ => println!("{}", serde_json::to_string_pretty(&output)?),
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anyhow::Error;
 use clap::Args;
+use console::style;
 use ploys::client::{Client, ServAddr, Token};
 use ploys::package::BumpOrVersion;
 use ploys::repository::RepoAddr;
@@ -15,9 +16,21 @@ pub struct Release {
     /// The package identifier.
     package: String,
 
-    /// The package version or level (major, minor, patch, rc, beta, alpha).
+    /// The package version or level (major, minor, patch, rc, beta, alpha, auto).
     version: BumpOrVersion,
 
+    /// Bump and release the dependent packages.
+    #[arg(long)]
+    bump_dependents: bool,
+
+    /// Consolidate the prerelease changelog sections into a stable release.
+    #[arg(long)]
+    consolidate_prereleases: bool,
+
+    /// Print the release plan without creating the release request.
+    #[arg(long)]
+    dry_run: bool,
+
     /// The management server address.
     #[arg(long, default_value = "api.ploys.dev")]
     server: ServAddr,
@@ -42,11 +55,38 @@ impl Release {
                 .finished()?,
         };
 
-        let id = client
-            .get_project(self.repo.clone())?
+        let project = client.get_project(self.repo.clone())?;
+        let builder = project
             .create_package_release_request(self.package, self.version)?
-            .finish()?
-            .id();
+            .bump_dependent_packages(self.bump_dependents)
+            .consolidate_prereleases(self.consolidate_prereleases);
+
+        if self.dry_run {
+            let plan = builder.plan()?;
+
+            println!("{}:\n", style("Release Plan").underlined().bold());
+            println!("Title:  {}", plan.title());
+            println!("Branch: {}", plan.branch());
+
+            println!("\n{}:\n", style("Packages").underlined().bold());
+
+            for (name, version) in plan.packages() {
+                println!("{name}  {version}");
+            }
+
+            println!("\n{}:\n", style("Body").underlined().bold());
+            println!("{}", plan.body());
+
+            println!("\n{}:\n", style("Changes").underlined().bold());
+
+            for file in plan.files() {
+                print!("{}", file.diff());
+            }
+
+            return Ok(());
+        }
+
+        let id = builder.finish()?.id();
 
         println!("Release request created at `{}/pull/{id}`", self.repo.url());
 
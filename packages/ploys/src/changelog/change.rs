@@ -2,14 +2,18 @@ use std::fmt::{self, Display};
 
 use markdown::ParseOptions;
 use markdown::mdast::Node;
+use serde::{Deserialize, Serialize};
 
 use super::Text;
 
 /// A changelog change.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Change {
     message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "link")]
     url: Option<(String, String)>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    authors: Vec<String>,
 }
 
 impl Change {
@@ -18,6 +22,7 @@ impl Change {
         Self {
             message: message.into(),
             url: None,
+            authors: Vec::new(),
         }
     }
 
@@ -26,6 +31,18 @@ impl Change {
         &self.message
     }
 
+    /// Sets the change message.
+    pub fn set_message(&mut self, message: impl Into<String>) -> &mut Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Builds the change with the given message.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.set_message(message);
+        self
+    }
+
     /// Gets the change URL.
     pub fn url(&self) -> Option<(&str, &str)> {
         self.url.as_ref().map(|(label, url)| (&**label, &**url))
@@ -42,6 +59,31 @@ impl Change {
         self.set_url(label, url);
         self
     }
+
+    /// Gets an iterator over the change authors.
+    pub fn authors(&self) -> impl Iterator<Item = &str> {
+        self.authors.iter().map(String::as_str)
+    }
+
+    /// Adds an author to the change.
+    ///
+    /// The author is the username without the leading `@` and is ignored if it
+    /// has already been added.
+    pub fn add_author(&mut self, author: impl Into<String>) -> &mut Self {
+        let author = author.into();
+
+        if !self.authors.contains(&author) {
+            self.authors.push(author);
+        }
+
+        self
+    }
+
+    /// Builds the change with the given author.
+    pub fn with_author(mut self, author: impl Into<String>) -> Self {
+        self.add_author(author);
+        self
+    }
 }
 
 impl Change {
@@ -55,18 +97,28 @@ impl Change {
             nodes.extend(root.children);
         }
 
+        if let Some(Node::Paragraph(paragraph)) = nodes.first_mut() {
+            let mut suffix = format_authors(&self.authors);
+
+            if self.url.is_some() {
+                suffix.push_str(" (");
+            }
+
+            if !suffix.is_empty() {
+                if let Some(Node::Text(text)) = paragraph.children.last_mut() {
+                    text.value.push_str(&suffix);
+                } else {
+                    paragraph.children.push(Node::Text(markdown::mdast::Text {
+                        value: suffix,
+                        position: None,
+                    }));
+                }
+            }
+        }
+
         if let Some(Node::Paragraph(paragraph)) = nodes.first_mut()
             && let Some((label, url)) = self.url
         {
-            if let Some(Node::Text(text)) = paragraph.children.last_mut() {
-                text.value.push_str(" (");
-            } else {
-                paragraph.children.push(Node::Text(markdown::mdast::Text {
-                    value: String::from(" ("),
-                    position: None,
-                }));
-            }
-
             paragraph.children.push(Node::Link(markdown::mdast::Link {
                 children: vec![Node::Text(markdown::mdast::Text {
                     value: label,
@@ -89,7 +141,7 @@ impl Change {
 
 impl Display for Change {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "- {}", self.message)?;
+        write!(f, "- {}{}", self.message, format_authors(&self.authors))?;
 
         if let Some((label, url)) = &self.url {
             if f.alternate() {
@@ -167,18 +219,26 @@ impl ChangeRef<'_> {
                 nodes: &link.children,
             };
 
+            let (message, authors) = parse_authors(&message.to_string());
+
             Some(Change {
-                message: message.to_string(),
+                message,
                 url: Some((text.to_string(), link.url.to_owned())),
+                authors,
             })
         }
 
         match inner(self.text.nodes) {
             Some(change) => change,
-            None => Change {
-                message: self.message().to_string(),
-                url: None,
-            },
+            None => {
+                let (message, authors) = parse_authors(&self.message());
+
+                Change {
+                    message,
+                    url: None,
+                    authors,
+                }
+            }
         }
     }
 }
@@ -206,10 +266,85 @@ impl Display for ChangeRef<'_> {
     }
 }
 
+/// Formats the authors as a ` by @one, @two and @three` message suffix.
+fn format_authors(authors: &[String]) -> String {
+    match authors {
+        [] => String::new(),
+        [author] => format!(" by @{author}"),
+        [authors @ .., last] => {
+            let authors = authors
+                .iter()
+                .map(|author| format!("@{author}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!(" by {authors} and @{last}")
+        }
+    }
+}
+
+/// Splits the authors suffix from the message if it exists.
+fn parse_authors(message: &str) -> (String, Vec<String>) {
+    let Some((text, suffix)) = message.rsplit_once(" by @") else {
+        return (message.to_owned(), Vec::new());
+    };
+
+    let authors = match suffix.rsplit_once(" and @") {
+        Some((authors, last)) => authors.split(", @").chain([last]).collect::<Vec<_>>(),
+        None => vec![suffix],
+    };
+
+    if authors.iter().any(|author| {
+        author.is_empty()
+            || !author
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '[' | ']'))
+    }) {
+        return (message.to_owned(), Vec::new());
+    }
+
+    (
+        text.to_owned(),
+        authors.into_iter().map(str::to_owned).collect(),
+    )
+}
+
+/// Serializes the change URL as a labelled link.
+mod link {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Link {
+        label: String,
+        url: String,
+    }
+
+    pub fn serialize<S>(url: &Option<(String, String)>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        url.as_ref()
+            .map(|(label, url)| Link {
+                label: label.clone(),
+                url: url.clone(),
+            })
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<(String, String)>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Link>::deserialize(deserializer)?.map(|link| (link.label, link.url)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
 
+    use crate::changelog::{Changelog, Changeset, Release};
+
     use super::Change;
 
     #[test]
@@ -227,4 +362,46 @@ mod tests {
             "- Changed `something` ([#1](https://github.com/ploys/example/pull/1))"
         );
     }
+
+    #[test]
+    fn test_change_authors() {
+        let change = Change::new("Fixed `something`")
+            .with_url("#2", "https://github.com/ploys/example/pull/2")
+            .with_author("one")
+            .with_author("two")
+            .with_author("one");
+
+        assert_eq!(change.authors().collect::<Vec<_>>(), ["one", "two"]);
+        assert_eq!(
+            change.to_string(),
+            "- Fixed `something` by @one and @two ([#2](https://github.com/ploys/example/pull/2))"
+        );
+
+        let changelog = Changelog::new().with_release(
+            Release::new("0.1.0")
+                .with_url("https://github.com/ploys/example/releases/tag/0.1.0")
+                .with_changeset(
+                    Changeset::fixed()
+                        .with_change(change.clone())
+                        .with_change(Change::new("Fixed another").with_author("three"))
+                        .with_change(Change::new("Fixed by @ the end")),
+                ),
+        );
+
+        let changelog = changelog.to_string().parse::<Changelog>().unwrap();
+        let release = changelog.get_release("0.1.0").unwrap();
+        let changes = release
+            .changesets()
+            .flat_map(|changeset| changeset.changes().map(|change| change.to_owned()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            changes,
+            [
+                change,
+                Change::new("Fixed another").with_author("three"),
+                Change::new("Fixed by @ the end"),
+            ]
+        );
+    }
 }
@@ -2,14 +2,18 @@ use std::fmt::{self, Display};
 
 use markdown::ParseOptions;
 use markdown::mdast::Node;
+use serde::{Deserialize, Serialize};
 
+use super::labels::NEW_CONTRIBUTORS_LABEL;
 use super::{Change, ChangeRef, MultilineText};
 
 /// A changelog changeset.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Changeset {
     label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     description: Option<String>,
+    #[serde(default)]
     changes: Vec<Change>,
 }
 
@@ -53,6 +57,13 @@ impl Changeset {
         Self::new("Security")
     }
 
+    /// Constructs a new `New Contributors` changeset.
+    ///
+    /// This lists the authors whose first contribution is part of the release.
+    pub fn new_contributors() -> Self {
+        Self::new(NEW_CONTRIBUTORS_LABEL)
+    }
+
     /// Gets the changeset label.
     pub fn label(&self) -> &str {
         &self.label
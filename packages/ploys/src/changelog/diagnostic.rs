@@ -0,0 +1,88 @@
+use std::fmt::{self, Display};
+
+use markdown::mdast::Node;
+
+/// A changelog diagnostic.
+///
+/// This describes a problem with the changelog format and where it was found
+/// in the markdown source. The position is only available for changelogs that
+/// were parsed rather than built.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    kind: DiagnosticKind,
+    position: Option<(usize, usize)>,
+}
+
+impl Diagnostic {
+    /// Constructs a new diagnostic for the given node.
+    pub(super) fn new(kind: DiagnosticKind, node: &Node) -> Self {
+        Self {
+            kind,
+            position: node
+                .position()
+                .map(|position| (position.start.line, position.start.column)),
+        }
+    }
+
+    /// Gets the diagnostic kind.
+    pub fn kind(&self) -> &DiagnosticKind {
+        &self.kind
+    }
+
+    /// Gets the line number where the problem starts.
+    pub fn line(&self) -> Option<usize> {
+        self.position.map(|(line, _)| line)
+    }
+
+    /// Gets the column number where the problem starts.
+    pub fn column(&self) -> Option<usize> {
+        self.position.map(|(_, column)| column)
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some((line, column)) => write!(f, "{line}:{column}: {}", self.kind),
+            None => Display::fmt(&self.kind, f),
+        }
+    }
+}
+
+/// The changelog diagnostic kind.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// A section heading that is not a release.
+    InvalidHeading(String),
+    /// A release version that is not a semver version.
+    InvalidVersion(String),
+    /// A release version that is not lower than the previous release.
+    UnorderedVersion(String),
+    /// A release version that appears more than once.
+    DuplicateVersion(String),
+    /// A release date that is not an ISO 8601 date.
+    InvalidDate(String),
+    /// A changeset label that is not defined by keep a changelog.
+    UnknownChangeset(String),
+    /// A release reference without a link definition.
+    MissingReference(String),
+    /// A link definition that is not referenced.
+    UnusedReference(String),
+}
+
+impl Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeading(heading) => write!(f, "Invalid release heading `{heading}`"),
+            Self::InvalidVersion(version) => write!(f, "Invalid release version `{version}`"),
+            Self::UnorderedVersion(version) => {
+                write!(f, "Release version `{version}` is not in descending order")
+            }
+            Self::DuplicateVersion(version) => write!(f, "Duplicate release version `{version}`"),
+            Self::InvalidDate(date) => write!(f, "Invalid release date `{date}`"),
+            Self::UnknownChangeset(label) => write!(f, "Unknown changeset label `{label}`"),
+            Self::MissingReference(id) => write!(f, "Missing link definition for `{id}`"),
+            Self::UnusedReference(id) => write!(f, "Unused link definition for `{id}`"),
+        }
+    }
+}
@@ -4,14 +4,26 @@ use std::fmt::{self, Display};
 /// The changelog error.
 #[derive(Debug)]
 pub enum Error {
+    /// An invalid changeset file error.
+    InvalidChangeset,
+    /// An invalid changelog grouping error.
+    InvalidGrouping(String),
+    /// A JSON error.
+    Json(serde_json::Error),
     /// A UTF-8 error.
     Utf8(std::str::Utf8Error),
+    /// A YAML error.
+    Yaml(serde_yaml_ng::Error),
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::InvalidChangeset => write!(f, "Invalid changeset file"),
+            Self::InvalidGrouping(grouping) => write!(f, "Invalid changelog grouping `{grouping}`"),
+            Self::Json(err) => Display::fmt(err, f),
             Self::Utf8(err) => Display::fmt(err, f),
+            Self::Yaml(err) => Display::fmt(err, f),
         }
     }
 }
@@ -19,7 +31,10 @@ impl Display for Error {
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
+            Self::InvalidChangeset | Self::InvalidGrouping(_) => None,
+            Self::Json(err) => Some(err),
             Self::Utf8(err) => Some(err),
+            Self::Yaml(err) => Some(err),
         }
     }
 }
@@ -30,8 +45,20 @@ impl From<Infallible> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
 impl From<std::str::Utf8Error> for Error {
     fn from(err: std::str::Utf8Error) -> Self {
         Self::Utf8(err)
     }
 }
+
+impl From<serde_yaml_ng::Error> for Error {
+    fn from(err: serde_yaml_ng::Error) -> Self {
+        Self::Yaml(err)
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::package::Bump;
+
+use super::Error;
+
+/// A changeset file.
+///
+/// This is a markdown file in the `.changeset` directory with a frontmatter
+/// section that lists the bump level of each affected package, followed by a
+/// summary of the change.
+///
+/// ```markdown
+/// ---
+/// "example-one": minor
+/// "example-two": patch
+/// ---
+///
+/// Add a new feature.
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChangesetFile {
+    packages: Vec<(String, Bump)>,
+    summary: String,
+}
+
+impl ChangesetFile {
+    /// Constructs a new changeset file.
+    pub fn new(summary: impl Into<String>) -> Self {
+        Self {
+            packages: Vec::new(),
+            summary: summary.into(),
+        }
+    }
+
+    /// Gets the change summary.
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// Gets an iterator over the packages and their bump levels.
+    pub fn packages(&self) -> impl Iterator<Item = (&str, Bump)> {
+        self.packages
+            .iter()
+            .map(|(package, bump)| (package.as_str(), *bump))
+    }
+
+    /// Gets the bump level for the given package.
+    pub fn get_package(&self, package: impl AsRef<str>) -> Option<Bump> {
+        self.packages
+            .iter()
+            .find(|(name, _)| name == package.as_ref())
+            .map(|(_, bump)| *bump)
+    }
+
+    /// Adds a package with the given bump level.
+    pub fn add_package(&mut self, package: impl Into<String>, bump: Bump) -> &mut Self {
+        let package = package.into();
+
+        match self.packages.iter_mut().find(|(name, _)| *name == package) {
+            Some((_, current)) => *current = bump,
+            None => self.packages.push((package, bump)),
+        }
+
+        self
+    }
+
+    /// Builds the changeset file with the given package.
+    pub fn with_package(mut self, package: impl Into<String>, bump: Bump) -> Self {
+        self.add_package(package, bump);
+        self
+    }
+
+    /// Removes the given package.
+    pub fn remove_package(&mut self, package: impl AsRef<str>) -> Option<Bump> {
+        let index = self
+            .packages
+            .iter()
+            .position(|(name, _)| name == package.as_ref())?;
+
+        Some(self.packages.remove(index).1)
+    }
+}
+
+impl ChangesetFile {
+    /// Creates a changeset file from the given bytes.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        std::str::from_utf8(bytes)?.parse()
+    }
+}
+
+impl Display for ChangesetFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "---")?;
+
+        for (package, bump) in &self.packages {
+            writeln!(f, "\"{package}\": {bump}")?;
+        }
+
+        writeln!(f, "---")?;
+        writeln!(f)?;
+        writeln!(f, "{}", self.summary)
+    }
+}
+
+impl FromStr for ChangesetFile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.trim_start().lines();
+
+        if lines.next().map(str::trim_end) != Some("---") {
+            return Err(Error::InvalidChangeset);
+        }
+
+        let mut packages = Vec::new();
+
+        loop {
+            let line = lines.next().ok_or(Error::InvalidChangeset)?.trim();
+
+            if line == "---" {
+                break;
+            }
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let (package, bump) = line.split_once(':').ok_or(Error::InvalidChangeset)?;
+            let package = unquote(package.trim());
+            let bump = match unquote(bump.trim()) {
+                "major" => Bump::Major,
+                "minor" => Bump::Minor,
+                "patch" => Bump::Patch,
+                _ => return Err(Error::InvalidChangeset),
+            };
+
+            if package.is_empty() {
+                return Err(Error::InvalidChangeset);
+            }
+
+            packages.push((package.to_owned(), bump));
+        }
+
+        Ok(Self {
+            packages,
+            summary: lines.collect::<Vec<_>>().join("\n").trim().to_owned(),
+        })
+    }
+}
+
+/// Removes the surrounding quotes from a frontmatter key or value.
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::package::Bump;
+
+    use super::ChangesetFile;
+
+    #[test]
+    fn test_changeset_file() {
+        let content = indoc::indoc! {r#"
+            ---
+            "example-one": minor
+            'example-two': "patch"
+            example-three: major
+            ---
+
+            Add a new feature.
+
+            This is a longer description.
+        "#};
+
+        let mut file = content.parse::<ChangesetFile>().unwrap();
+
+        assert_eq!(file.get_package("example-one"), Some(Bump::Minor));
+        assert_eq!(file.get_package("example-two"), Some(Bump::Patch));
+        assert_eq!(file.get_package("example-three"), Some(Bump::Major));
+        assert_eq!(file.get_package("example-four"), None);
+        assert_eq!(
+            file.summary(),
+            "Add a new feature.\n\nThis is a longer description."
+        );
+
+        assert_eq!(file.remove_package("example-three"), Some(Bump::Major));
+        assert_eq!(file.remove_package("example-three"), None);
+
+        let expected = indoc::indoc! {r#"
+            ---
+            "example-one": minor
+            "example-two": patch
+            ---
+
+            Add a new feature.
+
+            This is a longer description.
+        "#};
+
+        assert_eq!(file.to_string(), expected);
+        assert_eq!(expected.parse::<ChangesetFile>().unwrap(), file);
+
+        assert!("Add a new feature.".parse::<ChangesetFile>().is_err());
+        assert!("---\nexample: minor\n".parse::<ChangesetFile>().is_err());
+        assert!("---\nexample: rc\n---\n".parse::<ChangesetFile>().is_err());
+    }
+}
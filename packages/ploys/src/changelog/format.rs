@@ -0,0 +1,532 @@
+use relative_path::RelativePath;
+use strum::EnumIter;
+
+use super::{Changelog, Release};
+
+/// The changelog file format.
+///
+/// Changelogs in each format share the same structure as the markdown format
+/// with level one to three headings for the title, releases and changesets.
+/// The AsciiDoc and reStructuredText formats are converted to and from the
+/// markdown format, including inline code and links.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter)]
+pub enum ChangelogFormat {
+    /// The markdown format.
+    Markdown,
+    /// The AsciiDoc format.
+    AsciiDoc,
+    /// The reStructuredText format.
+    ReStructuredText,
+}
+
+impl ChangelogFormat {
+    /// Gets the changelog file names in order of preference.
+    pub fn file_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Markdown => &["CHANGELOG.md", "CHANGES.md"],
+            Self::AsciiDoc => &["CHANGELOG.adoc", "CHANGES.adoc"],
+            Self::ReStructuredText => &["CHANGELOG.rst", "CHANGES.rst"],
+        }
+    }
+
+    /// Gets the changelog format from the file extension of the given path.
+    pub fn from_path(path: impl AsRef<RelativePath>) -> Option<Self> {
+        match path.as_ref().extension()? {
+            "md" | "markdown" => Some(Self::Markdown),
+            "adoc" | "asciidoc" => Some(Self::AsciiDoc),
+            "rst" => Some(Self::ReStructuredText),
+            _ => None,
+        }
+    }
+
+    /// Renders the changelog in this format.
+    pub(super) fn render(&self, changelog: &Changelog) -> String {
+        if *self == Self::Markdown {
+            return changelog.to_string();
+        }
+
+        let data = changelog.to_data();
+        let mut blocks = vec![self.heading(1, data.title.as_deref().unwrap_or("Changelog"))];
+
+        if let Some(description) = &data.description {
+            blocks.push(self.block(description));
+        }
+
+        for release in data.unreleased.iter().chain(&data.releases) {
+            blocks.push(self.heading(2, &self.release_heading(release)));
+
+            if let Some(description) = release.description() {
+                blocks.push(self.block(description));
+            }
+
+            for changeset in release.changesets() {
+                blocks.push(self.heading(3, changeset.label()));
+
+                if let Some(description) = changeset.description() {
+                    blocks.push(self.block(description));
+                }
+
+                let changes = changeset
+                    .changes()
+                    .map(|change| self.block(&change.to_string()))
+                    .collect::<Vec<_>>();
+
+                if !changes.is_empty() {
+                    blocks.push(changes.join("\n"));
+                }
+            }
+        }
+
+        format!("{}\n", blocks.join("\n\n"))
+    }
+
+    /// Parses the changelog in this format.
+    pub(super) fn parse(&self, text: &str) -> Changelog {
+        let mut lines = Vec::new();
+        let mut definitions = Vec::new();
+
+        match self {
+            Self::Markdown => return text.parse().expect("infallible"),
+            Self::AsciiDoc => {
+                for line in text.lines().filter(|line| !line.starts_with("//")) {
+                    let line = match line {
+                        _ if line.starts_with("=== ") => format!("### {}", self.inline(&line[4..])),
+                        _ if line.starts_with("== ") => {
+                            heading_to_markdown(&self.inline(&line[3..]), &mut definitions)
+                        }
+                        _ if line.starts_with("= ") => format!("# {}", self.inline(&line[2..])),
+                        _ if line.starts_with("* ") => format!("- {}", self.inline(&line[2..])),
+                        _ => self.inline(line),
+                    };
+
+                    lines.push(line);
+                }
+            }
+            Self::ReStructuredText => {
+                let source = text.lines().collect::<Vec<_>>();
+                let mut adornments = Vec::new();
+                let mut index = 0;
+
+                while let Some(line) = source.get(index) {
+                    if let Some(adornment) =
+                        source.get(index + 1).and_then(|next| adornment(line, next))
+                    {
+                        let depth = match adornments.iter().position(|char| *char == adornment) {
+                            Some(depth) => depth,
+                            None => {
+                                adornments.push(adornment);
+                                adornments.len() - 1
+                            }
+                        };
+
+                        let heading = self.inline(line.trim());
+                        let heading = match depth {
+                            0 => format!("# {heading}"),
+                            1 => heading_to_markdown(&heading, &mut definitions),
+                            depth => format!("{} {heading}", "#".repeat(depth + 1)),
+                        };
+
+                        lines.push(heading);
+                        index += 2;
+
+                        continue;
+                    }
+
+                    let line = match line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+                        Some(item) => format!("- {}", self.inline(item)),
+                        None => self.inline(line.trim_start()),
+                    };
+
+                    lines.push(line);
+                    index += 1;
+                }
+            }
+        }
+
+        let mut markdown = lines.join("\n").trim_end().to_owned();
+
+        if !definitions.is_empty() {
+            markdown.push_str("\n\n");
+
+            for (label, url) in definitions {
+                markdown.push_str(&format!("[{label}]: {url}\n"));
+            }
+        }
+
+        markdown.parse().expect("infallible")
+    }
+
+    /// Formats a heading at the given depth.
+    fn heading(&self, depth: usize, text: &str) -> String {
+        match self {
+            Self::Markdown => format!("{} {text}", "#".repeat(depth)),
+            Self::AsciiDoc => format!("{} {}", "=".repeat(depth), self.inline_from(text)),
+            Self::ReStructuredText => {
+                let text = self.inline_from(text);
+                let adornment = ['=', '-', '~', '^'][depth.clamp(1, 4) - 1];
+
+                format!(
+                    "{text}\n{}",
+                    adornment.to_string().repeat(text.chars().count())
+                )
+            }
+        }
+    }
+
+    /// Formats the release heading as markdown.
+    fn release_heading(&self, release: &Release) -> String {
+        let mut heading = match release.url() {
+            Some(url) => format!("[{}]({url})", release.version()),
+            None => release.version().to_owned(),
+        };
+
+        if let Some(date) = release.date() {
+            heading.push_str(&format!(" - {date}"));
+        }
+
+        heading
+    }
+
+    /// Converts a block of markdown text into this format.
+    ///
+    /// The continuation lines of list items are indented as required by the
+    /// reStructuredText format.
+    fn block(&self, text: &str) -> String {
+        let mut lines = Vec::new();
+        let mut item = false;
+
+        for line in text.lines() {
+            let line = match line.strip_prefix("- ") {
+                Some(rest) => {
+                    item = true;
+
+                    match self {
+                        Self::AsciiDoc => format!("* {}", self.inline_from(rest)),
+                        _ => format!("- {}", self.inline_from(rest)),
+                    }
+                }
+                None if line.trim().is_empty() => {
+                    item = false;
+
+                    String::new()
+                }
+                None if item && *self == Self::ReStructuredText => {
+                    format!("  {}", self.inline_from(line))
+                }
+                None => self.inline_from(line),
+            };
+
+            lines.push(line);
+        }
+
+        lines.join("\n")
+    }
+
+    /// Converts inline markdown code and links into this format.
+    fn inline_from(&self, text: &str) -> String {
+        let mut output = String::new();
+        let mut rest = text;
+
+        while let Some(index) = rest.find(['`', '[']) {
+            output.push_str(&rest[..index]);
+            rest = &rest[index..];
+
+            let code = rest.strip_prefix('`').and_then(|rest| rest.split_once('`'));
+
+            if let Some((code, remaining)) = code {
+                match self {
+                    Self::ReStructuredText => output.push_str(&format!("``{code}``")),
+                    _ => output.push_str(&format!("`{code}`")),
+                }
+
+                rest = remaining;
+            } else if let Some((label, url, remaining)) = markdown_link(rest) {
+                match self {
+                    Self::Markdown => output.push_str(&format!("[{label}]({url})")),
+                    Self::AsciiDoc if url.starts_with("http://") || url.starts_with("https://") => {
+                        output.push_str(&format!("{url}[{label}]"))
+                    }
+                    Self::AsciiDoc => output.push_str(&format!("link:{url}[{label}]")),
+                    Self::ReStructuredText => output.push_str(&format!("`{label} <{url}>`_")),
+                }
+
+                rest = remaining;
+            } else {
+                output.push_str(&rest[..1]);
+                rest = &rest[1..];
+            }
+        }
+
+        output.push_str(rest);
+        output
+    }
+
+    /// Converts inline code and links in this format into markdown.
+    fn inline(&self, text: &str) -> String {
+        let mut output = String::new();
+        let mut rest = text;
+
+        loop {
+            let next = match self {
+                Self::Markdown => None,
+                Self::AsciiDoc => ["`", "link:", "http://", "https://"]
+                    .into_iter()
+                    .filter_map(|pattern| rest.find(pattern))
+                    .min(),
+                Self::ReStructuredText => rest.find('`'),
+            };
+
+            let Some(index) = next else {
+                break;
+            };
+
+            output.push_str(&rest[..index]);
+            rest = &rest[index..];
+
+            let converted = match self {
+                Self::Markdown => None,
+                Self::AsciiDoc => asciidoc_inline(rest),
+                Self::ReStructuredText => rst_inline(rest),
+            };
+
+            match converted {
+                Some((markdown, remaining)) => {
+                    output.push_str(&markdown);
+                    rest = remaining;
+                }
+                None => {
+                    let len = rest.chars().next().map_or(0, char::len_utf8);
+
+                    output.push_str(&rest[..len]);
+                    rest = &rest[len..];
+                }
+            }
+        }
+
+        output.push_str(rest);
+        output
+    }
+}
+
+/// Parses a markdown link at the start of the text.
+fn markdown_link(text: &str) -> Option<(&str, &str, &str)> {
+    let (label, rest) = text.strip_prefix('[')?.split_once("](")?;
+    let (url, rest) = rest.split_once(')')?;
+
+    if label.contains(['[', ']']) || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((label, url, rest))
+}
+
+/// Converts the AsciiDoc code or link at the start of the text into markdown.
+fn asciidoc_inline(text: &str) -> Option<(String, &str)> {
+    if let Some(rest) = text.strip_prefix('`') {
+        let (code, rest) = rest.split_once('`')?;
+
+        return Some((format!("`{code}`"), rest));
+    }
+
+    let target = text.strip_prefix("link:").unwrap_or(text);
+    let (url, rest) = target.split_once('[')?;
+    let (label, rest) = rest.split_once(']')?;
+
+    if url.is_empty() || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((format!("[{label}]({url})"), rest))
+}
+
+/// Converts the reStructuredText code or link at the start of the text into
+/// markdown.
+fn rst_inline(text: &str) -> Option<(String, &str)> {
+    if let Some(rest) = text.strip_prefix("``") {
+        let (code, rest) = rest.split_once("``")?;
+
+        return Some((format!("`{code}`"), rest));
+    }
+
+    let (content, rest) = text.strip_prefix('`')?.split_once("`_")?;
+    let rest = rest.strip_prefix('_').unwrap_or(rest);
+    let (label, url) = content.strip_suffix('>')?.rsplit_once(" <")?;
+
+    Some((format!("[{label}]({url})"), rest))
+}
+
+/// Checks whether the line is a section adornment for the given title.
+fn adornment(title: &str, line: &str) -> Option<char> {
+    let char = line.chars().next()?;
+
+    if title.trim().is_empty() || title.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    if !"=-~^\"'*+#".contains(char) || !line.chars().all(|current| current == char) {
+        return None;
+    }
+
+    if line.chars().count() < title.trim().chars().count() {
+        return None;
+    }
+
+    Some(char)
+}
+
+/// Converts a release heading into the markdown format.
+///
+/// The release link is replaced by a reference to a link definition as used
+/// by the markdown format.
+fn heading_to_markdown(heading: &str, definitions: &mut Vec<(String, String)>) -> String {
+    if let Some((label, url, rest)) = markdown_link(heading) {
+        definitions.push((label.to_lowercase(), url.to_owned()));
+
+        return format!("## [{label}]{rest}");
+    }
+
+    if heading.starts_with('[') {
+        return format!("## {heading}");
+    }
+
+    match heading.split_once(" - ") {
+        Some((version, date)) => format!("## [{version}] - {date}"),
+        None => format!("## [{heading}]"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    use crate::changelog::Changelog;
+
+    use super::ChangelogFormat;
+
+    const CHANGELOG: &str = indoc! {"
+        # Changelog
+
+        All notable changes to this project will be documented in this file.
+
+        The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/).
+
+        ## [Unreleased]
+
+        ## [0.2.0] - 2024-01-02
+
+        ### Added
+
+        - Added `something` by @one ([#2](https://github.com/ploys/example/pull/2))
+        - Added another
+
+        ## [0.1.0] - 2024-01-01
+
+        Initial release of [example](https://example.com).
+
+        ### Fixed
+
+        - Fixed one thing
+
+        [unreleased]: https://github.com/ploys/example/compare/0.2.0...HEAD
+        [0.2.0]: https://github.com/ploys/example/releases/tag/0.2.0
+        [0.1.0]: https://github.com/ploys/example/releases/tag/0.1.0
+    "};
+
+    #[test]
+    fn test_changelog_asciidoc() {
+        let changelog = CHANGELOG.parse::<Changelog>().unwrap();
+        let asciidoc = changelog.to_format(ChangelogFormat::AsciiDoc);
+
+        let expected = indoc! {"
+            = Changelog
+
+            All notable changes to this project will be documented in this file.
+
+            The format is based on https://keepachangelog.com/en/1.1.0/[Keep a Changelog].
+
+            == https://github.com/ploys/example/compare/0.2.0...HEAD[Unreleased]
+
+            == https://github.com/ploys/example/releases/tag/0.2.0[0.2.0] - 2024-01-02
+
+            === Added
+
+            * Added `something` by @one (https://github.com/ploys/example/pull/2[#2])
+            * Added another
+
+            == https://github.com/ploys/example/releases/tag/0.1.0[0.1.0] - 2024-01-01
+
+            Initial release of https://example.com[example].
+
+            === Fixed
+
+            * Fixed one thing
+        "};
+
+        assert_eq!(asciidoc, expected);
+        assert_eq!(
+            Changelog::from_format(&asciidoc, ChangelogFormat::AsciiDoc),
+            changelog
+        );
+    }
+
+    #[test]
+    fn test_changelog_rst() {
+        let changelog = CHANGELOG.parse::<Changelog>().unwrap();
+        let rst = changelog.to_format(ChangelogFormat::ReStructuredText);
+
+        let expected = indoc! {"
+            Changelog
+            =========
+
+            All notable changes to this project will be documented in this file.
+
+            The format is based on `Keep a Changelog <https://keepachangelog.com/en/1.1.0/>`_.
+
+            `Unreleased <https://github.com/ploys/example/compare/0.2.0...HEAD>`_
+            ---------------------------------------------------------------------
+
+            `0.2.0 <https://github.com/ploys/example/releases/tag/0.2.0>`_ - 2024-01-02
+            ---------------------------------------------------------------------------
+
+            Added
+            ~~~~~
+
+            - Added ``something`` by @one (`#2 <https://github.com/ploys/example/pull/2>`_)
+            - Added another
+
+            `0.1.0 <https://github.com/ploys/example/releases/tag/0.1.0>`_ - 2024-01-01
+            ---------------------------------------------------------------------------
+
+            Initial release of `example <https://example.com>`_.
+
+            Fixed
+            ~~~~~
+
+            - Fixed one thing
+        "};
+
+        assert_eq!(rst, expected);
+        assert_eq!(
+            Changelog::from_format(&rst, ChangelogFormat::ReStructuredText),
+            changelog
+        );
+    }
+
+    #[test]
+    fn test_changelog_format_from_path() {
+        assert_eq!(
+            ChangelogFormat::from_path("CHANGELOG.md"),
+            Some(ChangelogFormat::Markdown)
+        );
+        assert_eq!(
+            ChangelogFormat::from_path("docs/CHANGELOG.adoc"),
+            Some(ChangelogFormat::AsciiDoc)
+        );
+        assert_eq!(
+            ChangelogFormat::from_path("CHANGES.rst"),
+            Some(ChangelogFormat::ReStructuredText)
+        );
+        assert_eq!(ChangelogFormat::from_path("CHANGELOG.txt"), None);
+    }
+}
@@ -0,0 +1,38 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use super::Error;
+
+/// The project changelog grouping.
+///
+/// This determines how the package releases are grouped into the sections of
+/// a project changelog that aggregates the releases of every package.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChangelogGrouping {
+    /// Groups the package releases by release date.
+    #[default]
+    Date,
+    /// Groups the package releases that were released together.
+    ReleaseTrain,
+}
+
+impl Display for ChangelogGrouping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Date => write!(f, "date"),
+            Self::ReleaseTrain => write!(f, "release-train"),
+        }
+    }
+}
+
+impl FromStr for ChangelogGrouping {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "date" => Ok(Self::Date),
+            "release-train" => Ok(Self::ReleaseTrain),
+            _ => Err(Error::InvalidGrouping(s.to_owned())),
+        }
+    }
+}
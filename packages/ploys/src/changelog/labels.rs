@@ -0,0 +1,134 @@
+use super::Changeset;
+
+/// The standard changeset labels in the keep a changelog order.
+pub(super) const CHANGESET_LABELS: [&str; 6] = [
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+
+/// The generated changeset label for first-time contributors.
+pub(super) const NEW_CONTRIBUTORS_LABEL: &str = "New Contributors";
+
+/// A mapping of change labels to changesets.
+///
+/// This maps labels such as `type: feature` on each change to the changeset
+/// that it belongs to. Labels are matched in the order that they were added
+/// and changes without a matching label fall back to the `Changed` changeset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LabelMapping(Vec<(String, String)>);
+
+impl LabelMapping {
+    /// Constructs a new empty label mapping.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Gets an iterator over the change labels and their changeset labels.
+    pub fn labels(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(label, changeset)| (label.as_str(), changeset.as_str()))
+    }
+
+    /// Adds a change label mapped to the given changeset label.
+    pub fn add_label(
+        &mut self,
+        label: impl Into<String>,
+        changeset: impl Into<String>,
+    ) -> &mut Self {
+        let label = label.into();
+        let changeset = changeset.into();
+
+        match self.0.iter_mut().find(|(current, _)| *current == label) {
+            Some((_, current)) => *current = changeset,
+            None => self.0.push((label, changeset)),
+        }
+
+        self
+    }
+
+    /// Builds the label mapping with the given change label.
+    pub fn with_label(mut self, label: impl Into<String>, changeset: impl Into<String>) -> Self {
+        self.add_label(label, changeset);
+        self
+    }
+
+    /// Gets the changeset label for a change with the given labels.
+    pub fn get_changeset_label<'a>(&self, labels: impl IntoIterator<Item = &'a str>) -> &str {
+        let labels = labels.into_iter().collect::<Vec<_>>();
+
+        self.0
+            .iter()
+            .find(|(label, _)| labels.contains(&label.as_str()))
+            .map(|(_, changeset)| changeset.as_str())
+            .unwrap_or("Changed")
+    }
+
+    /// Sorts the changesets into the keep a changelog order.
+    ///
+    /// Changesets with other labels are placed after the standard changesets
+    /// in their existing order.
+    pub(crate) fn sort_changesets(changesets: &mut [Changeset]) {
+        changesets.sort_by_key(|changeset| {
+            CHANGESET_LABELS
+                .iter()
+                .position(|label| *label == changeset.label())
+                .unwrap_or(CHANGESET_LABELS.len())
+        });
+    }
+}
+
+impl Default for LabelMapping {
+    fn default() -> Self {
+        Self::new()
+            .with_label("type: feature", "Added")
+            .with_label("type: deprecation", "Deprecated")
+            .with_label("type: removal", "Removed")
+            .with_label("type: fix", "Fixed")
+            .with_label("type: security", "Security")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::changelog::Changeset;
+
+    use super::LabelMapping;
+
+    #[test]
+    fn test_label_mapping() {
+        let mapping = LabelMapping::default().with_label("type: docs", "Documentation");
+
+        assert_eq!(mapping.get_changeset_label(["type: feature"]), "Added");
+        assert_eq!(
+            mapping.get_changeset_label(["package: example", "type: fix"]),
+            "Fixed"
+        );
+        assert_eq!(mapping.get_changeset_label(["type: docs"]), "Documentation");
+        assert_eq!(mapping.get_changeset_label(["type: chore"]), "Changed");
+        assert_eq!(mapping.get_changeset_label([]), "Changed");
+
+        let mapping = mapping.with_label("type: feature", "Changed");
+
+        assert_eq!(mapping.get_changeset_label(["type: feature"]), "Changed");
+        assert_eq!(mapping.labels().count(), 6);
+
+        let mut changesets = vec![
+            Changeset::new("Documentation"),
+            Changeset::fixed(),
+            Changeset::changed(),
+            Changeset::added(),
+        ];
+
+        LabelMapping::sort_changesets(&mut changesets);
+
+        assert_eq!(
+            changesets.iter().map(Changeset::label).collect::<Vec<_>>(),
+            ["Added", "Changed", "Fixed", "Documentation"]
+        );
+    }
+}
@@ -1,24 +1,42 @@
 mod change;
 mod changeset;
+mod diagnostic;
 mod error;
+mod file;
+mod format;
+mod grouping;
+mod labels;
 mod reference;
 mod release;
 mod text;
 
+use std::collections::HashSet;
 use std::convert::Infallible;
 use std::fmt::{self, Display};
+use std::ops::Range;
 use std::str::FromStr;
 
 use markdown::ParseOptions;
 use markdown::mdast::{Node, Root};
+use semver::Version;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::format_description::well_known::Iso8601;
+use time::{Date, OffsetDateTime};
 
 pub use self::change::{Change, ChangeRef};
 pub use self::changeset::{Changeset, ChangesetRef};
+pub use self::diagnostic::{Diagnostic, DiagnosticKind};
 pub use self::error::Error;
+pub use self::file::ChangesetFile;
+pub use self::format::ChangelogFormat;
+pub use self::grouping::ChangelogGrouping;
+pub use self::labels::LabelMapping;
 pub use self::reference::ReferenceRef;
 pub use self::release::{Release, ReleaseRef};
 pub use self::text::{MultilineText, Text};
 
+use self::labels::{CHANGESET_LABELS, NEW_CONTRIBUTORS_LABEL};
+
 /// Represents a changelog file.
 ///
 /// This uses the [keep a changelog](https://keepachangelog.com) format to parse
@@ -33,6 +51,14 @@ impl Changelog {
         Self::default()
     }
 
+    /// Constructs a new project changelog.
+    ///
+    /// This describes the changes to the whole project rather than a package
+    /// for use with [`Changelog::add_package_releases`].
+    pub fn new_project() -> Self {
+        Self::with_subject("project")
+    }
+
     /// Gets the changelog title.
     pub fn title(&self) -> Option<Text<'_>> {
         self.0
@@ -76,24 +102,390 @@ impl Changelog {
         self
     }
 
+    /// Adds the package releases to a project changelog.
+    ///
+    /// This combines the releases of several packages into a single section
+    /// with each change prefixed by the package name. Packages released on the
+    /// same date are merged into the same section when grouped by date, while
+    /// each call adds a new section when grouped by release train.
+    pub fn add_package_releases<I, S>(
+        &mut self,
+        releases: I,
+        grouping: ChangelogGrouping,
+    ) -> &mut Self
+    where
+        I: IntoIterator<Item = (S, Release)>,
+        S: AsRef<str>,
+    {
+        let releases = releases.into_iter().collect::<Vec<_>>();
+        let Some((_, first)) = releases.first() else {
+            return self;
+        };
+
+        let date = first.date().map(ToOwned::to_owned).unwrap_or_else(|| {
+            OffsetDateTime::now_utc()
+                .format(&Iso8601::DATE)
+                .expect("date")
+        });
+
+        let mut section = match grouping {
+            ChangelogGrouping::Date => Release::new(&date),
+            ChangelogGrouping::ReleaseTrain => Release::new(
+                releases
+                    .iter()
+                    .map(|(package, release)| format!("{}@{}", package.as_ref(), release.version()))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+            .with_date(&date),
+        };
+
+        if let Some(url) = first.url() {
+            section.set_url(url);
+        }
+
+        for (package, release) in &releases {
+            for changeset in release.changesets() {
+                let mut prefixed = Changeset::new(changeset.label());
+
+                for change in changeset.changes() {
+                    prefixed.add_change(change.clone().with_message(format!(
+                        "`{}`: {}",
+                        package.as_ref(),
+                        change.message()
+                    )));
+                }
+
+                section.merge(Release::new(release.version()).with_changeset(prefixed));
+            }
+        }
+
+        if grouping == ChangelogGrouping::Date
+            && let Some(mut existing) = self
+                .get_release(&date)
+                .map(|release| self.get_owned_release(release))
+        {
+            self.remove_release(&date);
+            existing.merge(section);
+            section = existing;
+        }
+
+        self.add_release(section)
+    }
+
+    /// Adds a new release with the changes from the unreleased section.
+    ///
+    /// This merges the unreleased section into the release and leaves it empty
+    /// for future changes. When the unreleased reference compares the previous
+    /// tag with `HEAD`, the release reference is set to compare the previous
+    /// tag with the given tag and the unreleased reference is updated to start
+    /// from the given tag.
+    pub fn release_unreleased(
+        &mut self,
+        release: impl Into<Release>,
+        tag: impl AsRef<str>,
+    ) -> &mut Self {
+        let mut release = release.into();
+        let tag = tag.as_ref();
+
+        let Some(unreleased) = self.unreleased().map(|release| release.to_owned()) else {
+            return self.add_release(release);
+        };
+
+        release.merge(unreleased);
+
+        let compare = self
+            .get_unreleased_url()
+            .and_then(|url| url.strip_suffix("...HEAD"))
+            .and_then(|url| Some((url, url.rsplit_once("/compare/")?.0)))
+            .map(|(url, base)| {
+                (
+                    format!("{url}...{tag}"),
+                    format!("{base}/compare/{tag}...HEAD"),
+                )
+            });
+
+        self.set_unreleased(Release::unreleased());
+
+        if let Some((url, unreleased)) = compare {
+            release.set_url(url);
+            self.set_unreleased_url(unreleased);
+        }
+
+        self.add_release(release)
+    }
+
+    /// Gets the unreleased section.
+    pub fn unreleased(&self) -> Option<ReleaseRef<'_>> {
+        self.get_sections()
+            .filter_map(ReleaseRef::from_nodes)
+            .find(|release| release.is_unreleased())
+    }
+
+    /// Sets the unreleased section.
+    ///
+    /// This replaces the existing unreleased section or adds a new one before
+    /// the releases, using the release URL as the unreleased reference.
+    pub fn set_unreleased(&mut self, release: impl Into<Release>) -> &mut Self {
+        let release = release.into().with_version("Unreleased");
+        let url = release.url().map(ToOwned::to_owned);
+
+        let range = self.get_unreleased_range();
+        let nodes = self.0.children_mut().expect("children");
+        let range = range.unwrap_or_else(|| {
+            let index = nodes
+                .iter()
+                .position(|node| matches!(node, Node::Heading(heading) if heading.depth == 2))
+                .unwrap_or(nodes.len());
+
+            index..index
+        });
+
+        let _ = nodes
+            .splice(range, release.into_nodes())
+            .collect::<Vec<_>>();
+
+        if let Some(url) = url {
+            self.set_unreleased_url(url);
+        }
+
+        self
+    }
+
+    /// Builds the changelog with the given unreleased section.
+    pub fn with_unreleased(mut self, release: impl Into<Release>) -> Self {
+        self.set_unreleased(release);
+        self
+    }
+
     /// Gets a release for the given version.
     pub fn get_release(&self, version: impl AsRef<str>) -> Option<ReleaseRef<'_>> {
         self.releases()
             .find(|release| release.version() == version.as_ref())
     }
 
+    /// Removes the release for the given version.
+    ///
+    /// This also removes the release reference.
+    pub fn remove_release(&mut self, version: impl AsRef<str>) -> Option<Release> {
+        let version = version.as_ref();
+        let release = self.get_release(version)?.to_owned();
+        let range = self.get_release_range(|release| {
+            !release.is_unreleased() && release.version() == version
+        })?;
+
+        let nodes = self.0.children_mut().expect("children");
+
+        nodes.drain(range);
+        nodes.retain(|node| {
+            !matches!(
+                node,
+                Node::Definition(definition)
+                    if ReferenceRef::from_definition(definition).id() == version
+            )
+        });
+
+        Some(release)
+    }
+
+    /// Gets an iterator over the prereleases of the given version.
+    ///
+    /// These are the releases that share the same major, minor and patch
+    /// version but include a prerelease identifier, such as the `1.0.0-rc.1`
+    /// and `1.0.0-beta.1` releases that precede the `1.0.0` release.
+    pub fn get_prereleases(
+        &self,
+        version: impl AsRef<str>,
+    ) -> impl Iterator<Item = ReleaseRef<'_>> {
+        let version = version.as_ref().parse::<Version>().ok();
+
+        self.releases().filter(move |release| {
+            let Some(version) = &version else {
+                return false;
+            };
+
+            release
+                .version()
+                .parse::<Version>()
+                .is_ok_and(|prerelease| {
+                    !prerelease.pre.is_empty()
+                        && prerelease.major == version.major
+                        && prerelease.minor == version.minor
+                        && prerelease.patch == version.patch
+                        && prerelease < *version
+                })
+        })
+    }
+
     /// Gets an iterator over the releases.
+    ///
+    /// This does not include the unreleased section.
     pub fn releases(&self) -> impl Iterator<Item = ReleaseRef<'_>> {
-        self.get_sections().filter_map(ReleaseRef::from_nodes)
+        self.get_sections()
+            .filter_map(ReleaseRef::from_nodes)
+            .filter(|release| !release.is_unreleased())
     }
 
     /// Gets an iterator over the references.
     pub fn references(&self) -> impl Iterator<Item = ReferenceRef<'_>> {
-        self.releases()
+        self.get_sections()
+            .filter_map(ReleaseRef::from_nodes)
             .last()
             .into_iter()
             .flat_map(|release| release.references())
     }
+
+    /// Serializes the changelog as JSON.
+    pub fn to_json(&self) -> Result<String, Error> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Deserializes the changelog from JSON.
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Serializes the changelog as YAML.
+    pub fn to_yaml(&self) -> Result<String, Error> {
+        Ok(serde_yaml_ng::to_string(self)?)
+    }
+
+    /// Deserializes the changelog from YAML.
+    pub fn from_yaml(yaml: &str) -> Result<Self, Error> {
+        Ok(serde_yaml_ng::from_str(yaml)?)
+    }
+
+    /// Renders the changelog in the given format.
+    pub fn to_format(&self, format: ChangelogFormat) -> String {
+        format.render(self)
+    }
+
+    /// Parses the changelog in the given format.
+    pub fn from_format(text: &str, format: ChangelogFormat) -> Self {
+        format.parse(text)
+    }
+
+    /// Validates the changelog against the keep a changelog format.
+    ///
+    /// This finds problems such as invalid or unordered release headings that
+    /// would otherwise cause sections to be ignored or misread, returning no
+    /// diagnostics for a valid changelog. The release headings of a project
+    /// changelog grouped by date or release train are also accepted.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        self.validate_with(&LabelMapping::default())
+    }
+
+    /// Validates the changelog with the given label mapping.
+    ///
+    /// This accepts the custom changeset labels of the mapping in addition to
+    /// the keep a changelog labels.
+    pub fn validate_with(&self, labels: &LabelMapping) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut versions = Vec::<Version>::new();
+        let mut dates = Vec::<Date>::new();
+        let mut references = HashSet::new();
+
+        collect_references(&self.0, &mut references);
+
+        for section in self.get_sections() {
+            let Some(node @ Node::Heading(heading)) = section.first() else {
+                continue;
+            };
+
+            if heading.depth != 2 {
+                continue;
+            }
+
+            let Some(release) = ReleaseRef::from_nodes(section) else {
+                let heading = node.to_string();
+                let kind = match heading
+                    .trim()
+                    .strip_prefix('[')
+                    .and_then(|heading| heading.split_once(']'))
+                {
+                    Some((id, _)) => DiagnosticKind::MissingReference(id.to_owned()),
+                    None => DiagnosticKind::InvalidHeading(heading),
+                };
+
+                diagnostics.push(Diagnostic::new(kind, node));
+
+                continue;
+            };
+
+            if !release.is_unreleased() {
+                let kind = match release.version().parse::<Version>() {
+                    Ok(version) if versions.contains(&version) => {
+                        Some(DiagnosticKind::DuplicateVersion(version.to_string()))
+                    }
+                    Ok(version) => {
+                        let unordered = versions.last().is_some_and(|last| version > *last);
+
+                        versions.push(version);
+
+                        unordered
+                            .then(|| DiagnosticKind::UnorderedVersion(release.version().into()))
+                    }
+                    Err(_) => match Date::parse(release.version(), &Iso8601::DATE) {
+                        Ok(date) if dates.contains(&date) => {
+                            Some(DiagnosticKind::DuplicateVersion(release.version().into()))
+                        }
+                        Ok(date) => {
+                            let unordered = dates.last().is_some_and(|last| date > *last);
+
+                            dates.push(date);
+
+                            unordered
+                                .then(|| DiagnosticKind::UnorderedVersion(release.version().into()))
+                        }
+                        Err(_) if is_release_train(release.version()) => None,
+                        Err(_) => Some(DiagnosticKind::InvalidVersion(release.version().into())),
+                    },
+                };
+
+                diagnostics.extend(kind.map(|kind| Diagnostic::new(kind, node)));
+
+                if let Some(date) = release.date()
+                    && Date::parse(date, &Iso8601::DATE).is_err()
+                {
+                    diagnostics.push(Diagnostic::new(
+                        DiagnosticKind::InvalidDate(date.to_owned()),
+                        node,
+                    ));
+                }
+            }
+
+            for node in &section[1..] {
+                if let Node::Heading(heading) = node
+                    && heading.depth == 3
+                {
+                    let label = node.to_string();
+
+                    if !CHANGESET_LABELS.contains(&label.as_str())
+                        && label != NEW_CONTRIBUTORS_LABEL
+                        && !labels.labels().any(|(_, changeset)| changeset == label)
+                    {
+                        diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::UnknownChangeset(label),
+                            node,
+                        ));
+                    }
+                }
+            }
+        }
+
+        for node in self.0.children().into_iter().flatten() {
+            if let Node::Definition(definition) = node
+                && !references.contains(definition.identifier.as_str())
+            {
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::UnusedReference(definition.identifier.clone()),
+                    node,
+                ));
+            }
+        }
+
+        diagnostics
+    }
 }
 
 impl Changelog {
@@ -104,13 +496,140 @@ impl Changelog {
         })
     }
 
+    /// Gets the node range of the unreleased section.
+    fn get_unreleased_range(&self) -> Option<Range<usize>> {
+        self.get_release_range(|release| release.is_unreleased())
+    }
+
+    /// Gets the node range of the first release section matching the filter.
+    ///
+    /// This excludes the trailing definitions when the release section is the
+    /// last section.
+    fn get_release_range<F>(&self, filter: F) -> Option<Range<usize>>
+    where
+        F: Fn(&ReleaseRef<'_>) -> bool,
+    {
+        let nodes = self.0.children()?;
+        let start = nodes.iter().position(|node| match node {
+            Node::Heading(heading) if heading.depth == 2 => {
+                ReleaseRef::from_nodes(std::slice::from_ref(node))
+                    .is_some_and(|release| filter(&release))
+            }
+            _ => false,
+        })?;
+
+        let end = nodes[start + 1..]
+            .iter()
+            .position(|node| matches!(node, Node::Heading(heading) if heading.depth == 2))
+            .map(|index| start + 1 + index)
+            .unwrap_or_else(|| {
+                let definitions = nodes[start + 1..]
+                    .iter()
+                    .rev()
+                    .take_while(|node| matches!(node, Node::Definition(_)))
+                    .count();
+
+                nodes.len() - definitions
+            });
+
+        Some(start..end)
+    }
+
+    /// Converts the changelog into the structured representation.
+    fn to_data(&self) -> ChangelogData {
+        ChangelogData {
+            title: self.title().map(|title| title.to_string()),
+            description: self.description().map(|text| text.to_string()),
+            unreleased: self
+                .unreleased()
+                .map(|release| self.get_owned_release(release)),
+            releases: self
+                .releases()
+                .map(|release| self.get_owned_release(release))
+                .collect(),
+        }
+    }
+
+    /// Constructs the changelog from the structured representation.
+    fn from_data(data: ChangelogData) -> Self {
+        let title = data.title.as_deref().unwrap_or("Changelog");
+        let mut changelog = match data.description {
+            Some(description) => format!("# {title}\n\n{description}\n"),
+            None => format!("# {title}\n"),
+        }
+        .parse::<Changelog>()
+        .expect("infallible");
+
+        for release in data.releases.into_iter().rev() {
+            changelog.add_release(release);
+        }
+
+        if let Some(unreleased) = data.unreleased {
+            changelog.set_unreleased(unreleased);
+        }
+
+        changelog
+    }
+
+    /// Converts a release entry into an owned release with its URL.
+    ///
+    /// The references are shared by every release in the changelog so these
+    /// are replaced by the URL of the release reference.
+    fn get_owned_release(&self, release: ReleaseRef<'_>) -> Release {
+        let mut owned = release.to_owned();
+        let id = match release.is_unreleased() {
+            true => String::from("unreleased"),
+            false => release.version().to_owned(),
+        };
+
+        if let Some(reference) = self.references().find(|reference| reference.id() == id) {
+            owned.set_url(reference.url());
+        }
+
+        owned.clear_references();
+        owned
+    }
+
+    /// Gets the unreleased reference URL.
+    fn get_unreleased_url(&self) -> Option<&str> {
+        self.0.children()?.iter().find_map(|node| match node {
+            Node::Definition(definition) if definition.identifier == "unreleased" => {
+                Some(definition.url.as_str())
+            }
+            _ => None,
+        })
+    }
+
+    /// Sets the unreleased reference URL.
+    fn set_unreleased_url(&mut self, url: String) {
+        let nodes = self.0.children_mut().expect("children");
+        let definition = nodes.iter_mut().find_map(|node| match node {
+            Node::Definition(definition) if definition.identifier == "unreleased" => {
+                Some(definition)
+            }
+            _ => None,
+        });
+
+        match definition {
+            Some(definition) => definition.url = url,
+            None => self.add_release_reference(String::from("unreleased"), url),
+        }
+    }
+
     /// Adds a release section.
+    ///
+    /// This is added after the unreleased section if it exists, otherwise
+    /// before the first release.
     fn add_release_section(&mut self, release: Release) {
+        let range = self.get_unreleased_range();
         let nodes = self.0.children_mut().expect("children");
-        let index = nodes
-            .iter()
-            .position(|node| matches!(node, Node::Heading(heading) if heading.depth == 2))
-            .unwrap_or(nodes.len());
+        let index = match range {
+            Some(range) => range.end,
+            None => nodes
+                .iter()
+                .position(|node| matches!(node, Node::Heading(heading) if heading.depth == 2))
+                .unwrap_or(nodes.len()),
+        };
 
         let _ = nodes
             .splice(index..index, release.into_nodes())
@@ -131,7 +650,13 @@ impl Changelog {
             }
         }
 
-        let position = position.unwrap_or(nodes.len());
+        let mut position = position.unwrap_or(nodes.len());
+
+        if let Some(Node::Definition(definition)) = nodes.get(position)
+            && definition.identifier == "unreleased"
+        {
+            position += 1;
+        }
 
         nodes.insert(
             position,
@@ -146,8 +671,44 @@ impl Changelog {
     }
 }
 
+/// Collects the identifiers of the link references in the node tree.
+fn collect_references<'a>(node: &'a Node, references: &mut HashSet<&'a str>) {
+    match node {
+        Node::LinkReference(link) => {
+            references.insert(&link.identifier);
+        }
+        Node::ImageReference(image) => {
+            references.insert(&image.identifier);
+        }
+        _ => {}
+    }
+
+    for child in node.children().into_iter().flatten() {
+        collect_references(child, references);
+    }
+}
+
+/// Checks whether the version is a release train of package versions.
+///
+/// This is the `{package}@{version}` list of the packages released together in
+/// a project changelog grouped by release train.
+fn is_release_train(version: &str) -> bool {
+    version.split(", ").all(|entry| {
+        entry
+            .rsplit_once('@')
+            .is_some_and(|(name, version)| !name.is_empty() && version.parse::<Version>().is_ok())
+    })
+}
+
 impl Default for Changelog {
     fn default() -> Self {
+        Self::with_subject("package")
+    }
+}
+
+impl Changelog {
+    /// Constructs a new changelog describing the changes to the subject.
+    fn with_subject(subject: &str) -> Self {
         Self(Node::Root(Root {
             children: vec![
                 Node::Heading(markdown::mdast::Heading {
@@ -160,8 +721,8 @@ impl Default for Changelog {
                 }),
                 Node::Paragraph(markdown::mdast::Paragraph {
                     children: vec![Node::Text(markdown::mdast::Text {
-                        value: String::from(
-                            "All notable changes to this package will be documented in this file.",
+                        value: format!(
+                            "All notable changes to this {subject} will be documented in this file."
                         ),
                         position: None,
                     })],
@@ -228,7 +789,10 @@ impl Display for Changelog {
             }
         }
 
-        let mut releases = self.releases().peekable();
+        let mut releases = self
+            .get_sections()
+            .filter_map(ReleaseRef::from_nodes)
+            .peekable();
 
         if releases.peek().is_some() {
             write!(f, "\n\n")?;
@@ -258,6 +822,37 @@ impl PartialEq for Changelog {
     }
 }
 
+impl Serialize for Changelog {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_data().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Changelog {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self::from_data(ChangelogData::deserialize(deserializer)?))
+    }
+}
+
+/// The structured changelog representation.
+#[derive(Serialize, Deserialize)]
+struct ChangelogData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unreleased: Option<Release>,
+    #[serde(default)]
+    releases: Vec<Release>,
+}
+
 impl FromStr for Changelog {
     type Err = Infallible;
 
@@ -273,7 +868,9 @@ mod tests {
     use indoc::indoc;
     use pretty_assertions::assert_eq;
 
-    use super::{Change, Changelog, Changeset, Release};
+    use super::{
+        Change, Changelog, ChangelogGrouping, Changeset, DiagnosticKind, LabelMapping, Release,
+    };
 
     #[test]
     fn test_changelog_parser() {
@@ -484,4 +1081,424 @@ mod tests {
 
         assert_eq!(changelog.to_string(), output);
     }
+
+    #[test]
+    fn test_changelog_unreleased() {
+        let changelog_text = indoc! {"
+            # Changelog
+
+            ## [Unreleased]
+
+            ### Fixed
+
+            - Fixed two
+
+            ## [0.1.0] - 2024-01-01
+
+            This is the initial release.
+
+            [unreleased]: https://github.com/ploys/example/compare/0.1.0...HEAD
+            [0.1.0]: https://github.com/ploys/example/releases/tag/0.1.0
+        "};
+
+        let mut changelog = changelog_text.parse::<Changelog>().unwrap();
+
+        assert_eq!(changelog.to_string(), changelog_text);
+        assert_eq!(changelog.releases().count(), 1);
+        assert_eq!(changelog.releases().next().unwrap().version(), "0.1.0");
+
+        let unreleased = changelog.unreleased().unwrap();
+
+        assert!(unreleased.is_unreleased());
+        assert_eq!(unreleased.version(), "Unreleased");
+        assert_eq!(unreleased.fixed().unwrap().changes().count(), 1);
+
+        changelog.release_unreleased(
+            Release::new("0.2.0")
+                .with_date("2024-01-02")
+                .with_changeset(Changeset::added().with_change(Change::new("Added one")))
+                .with_url("https://github.com/ploys/example/releases/tag/0.2.0"),
+            "0.2.0",
+        );
+
+        let output = indoc! {"
+            # Changelog
+
+            ## [Unreleased]
+
+            ## [0.2.0] - 2024-01-02
+
+            ### Added
+
+            - Added one
+
+            ### Fixed
+
+            - Fixed two
+
+            ## [0.1.0] - 2024-01-01
+
+            This is the initial release.
+
+            [unreleased]: https://github.com/ploys/example/compare/0.2.0...HEAD
+            [0.2.0]: https://github.com/ploys/example/compare/0.1.0...0.2.0
+            [0.1.0]: https://github.com/ploys/example/releases/tag/0.1.0
+        "};
+
+        assert_eq!(changelog.to_string(), output);
+        assert_eq!(changelog.unreleased().unwrap().changesets().count(), 0);
+
+        let changelog = "# Changelog\n\n## [Unreleased]\n\nSome notes.\n"
+            .parse::<Changelog>()
+            .unwrap()
+            .with_release(Release::new("0.1.0"));
+
+        assert!(changelog.unreleased().is_some());
+        assert_eq!(
+            changelog.to_string(),
+            "# Changelog\n\n## [Unreleased]\n\nSome notes.\n\n## [0.1.0]\n"
+        );
+    }
+
+    #[test]
+    fn test_changelog_validate() {
+        let changelog_text = indoc! {"
+            # Changelog
+
+            ## [Unreleased]
+
+            ## [0.1.0] - 2024-01-02
+
+            ### Fixed
+
+            - Fixed one by @one
+
+            ### New Contributors
+
+            - @one made their first contribution
+
+            ## [0.2.0] - 2024-01-03
+
+            ### Improved
+
+            - Improved two
+
+            ## [0.1.0] - 01/01/2024
+
+            ## [0.0.1]
+
+            ## Initial
+
+            ## [latest]
+
+            [unreleased]: https://github.com/ploys/example/compare/0.2.0...HEAD
+            [0.2.0]: https://github.com/ploys/example/releases/tag/0.2.0
+            [0.1.0]: https://github.com/ploys/example/releases/tag/0.1.0
+            [0.0.0]: https://github.com/ploys/example/releases/tag/0.0.0
+        "};
+
+        let diagnostics = changelog_text.parse::<Changelog>().unwrap().validate();
+        let kinds = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.kind().clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            [
+                DiagnosticKind::UnorderedVersion("0.2.0".into()),
+                DiagnosticKind::UnknownChangeset("Improved".into()),
+                DiagnosticKind::DuplicateVersion("0.1.0".into()),
+                DiagnosticKind::InvalidDate("01/01/2024".into()),
+                DiagnosticKind::MissingReference("0.0.1".into()),
+                DiagnosticKind::InvalidHeading("Initial".into()),
+                DiagnosticKind::MissingReference("latest".into()),
+                DiagnosticKind::UnusedReference("0.0.0".into()),
+            ]
+        );
+
+        let labels = LabelMapping::default().with_label("type: improvement", "Improved");
+        let diagnostics = changelog_text
+            .parse::<Changelog>()
+            .unwrap()
+            .validate_with(&labels);
+
+        assert!(
+            !diagnostics
+                .iter()
+                .any(|diagnostic| matches!(diagnostic.kind(), DiagnosticKind::UnknownChangeset(_)))
+        );
+        assert_eq!(diagnostics.len(), kinds.len() - 1);
+        assert_eq!(diagnostics[0].line(), Some(15));
+        assert_eq!(diagnostics[0].column(), Some(1));
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "15:1: Release version `0.2.0` is not in descending order"
+        );
+
+        let changelog = Changelog::new().with_release(
+            Release::new("0.1.0")
+                .with_date("2024-01-01")
+                .with_url("https://github.com/ploys/example/releases/tag/0.1.0"),
+        );
+
+        assert_eq!(changelog.validate(), []);
+        assert_eq!(
+            changelog
+                .to_string()
+                .parse::<Changelog>()
+                .unwrap()
+                .validate(),
+            []
+        );
+    }
+
+    #[test]
+    fn test_changelog_json() {
+        let changelog_text = indoc! {"
+            # Changelog
+
+            All notable changes to this project will be documented in this file.
+
+            ## [Unreleased]
+
+            ### Added
+
+            - Added two
+
+            ## [0.1.0] - 2024-01-01
+
+            This is the initial release.
+
+            ### Fixed
+
+            - Fixed one ([#1](https://github.com/ploys/example/pull/1))
+
+            [unreleased]: https://github.com/ploys/example/compare/0.1.0...HEAD
+            [0.1.0]: https://github.com/ploys/example/releases/tag/0.1.0
+        "};
+
+        let changelog = changelog_text.parse::<Changelog>().unwrap();
+        let json = changelog.to_json().unwrap();
+        let value = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+
+        assert_eq!(value["title"], "Changelog");
+        assert_eq!(value["unreleased"]["version"], "Unreleased");
+        assert_eq!(
+            value["unreleased"]["url"],
+            "https://github.com/ploys/example/compare/0.1.0...HEAD"
+        );
+        assert_eq!(value["releases"][0]["version"], "0.1.0");
+        assert_eq!(value["releases"][0]["date"], "2024-01-01");
+        assert_eq!(value["releases"][0]["changesets"][0]["label"], "Fixed");
+        assert_eq!(
+            value["releases"][0]["changesets"][0]["changes"][0],
+            serde_json::json!({
+                "message": "Fixed one",
+                "url": {
+                    "label": "#1",
+                    "url": "https://github.com/ploys/example/pull/1",
+                },
+            })
+        );
+
+        let parsed = Changelog::from_json(&json).unwrap();
+
+        assert_eq!(parsed.to_string(), changelog_text);
+        assert!(Changelog::from_json(r#"{"releases": 1}"#).is_err());
+
+        let yaml = changelog.to_yaml().unwrap();
+        let value = serde_yaml_ng::from_str::<serde_json::Value>(&yaml).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::from_str::<serde_json::Value>(&json).unwrap()
+        );
+        assert_eq!(
+            Changelog::from_yaml(&yaml).unwrap().to_string(),
+            changelog_text
+        );
+        assert!(Changelog::from_yaml("releases: 1").is_err());
+    }
+
+    #[test]
+    fn test_changelog_prereleases() {
+        let changelog_text = indoc! {"
+            # Changelog
+
+            ## [1.0.0-rc.2] - 2024-01-03
+
+            ### Fixed
+
+            - Fixed two
+
+            ## [1.0.0-rc.1] - 2024-01-02
+
+            ### Added
+
+            - Added one
+
+            ## [0.1.0] - 2024-01-01
+
+            [1.0.0-rc.2]: https://github.com/ploys/example/releases/tag/1.0.0-rc.2
+            [1.0.0-rc.1]: https://github.com/ploys/example/releases/tag/1.0.0-rc.1
+            [0.1.0]: https://github.com/ploys/example/releases/tag/0.1.0
+        "};
+
+        let mut changelog = changelog_text.parse::<Changelog>().unwrap();
+
+        assert_eq!(
+            changelog
+                .get_prereleases("1.0.0")
+                .map(|release| release.version().to_owned())
+                .collect::<Vec<_>>(),
+            ["1.0.0-rc.2", "1.0.0-rc.1"]
+        );
+        assert_eq!(changelog.get_prereleases("1.0.0-rc.2").count(), 1);
+        assert_eq!(changelog.get_prereleases("0.1.0").count(), 0);
+
+        let release = changelog.remove_release("1.0.0-rc.1").unwrap();
+
+        assert_eq!(release.version(), "1.0.0-rc.1");
+        assert_eq!(release.changesets().count(), 1);
+        assert!(changelog.remove_release("1.0.0-rc.1").is_none());
+
+        let release = changelog.remove_release("0.1.0").unwrap();
+
+        assert_eq!(release.changesets().count(), 0);
+
+        let output = indoc! {"
+            # Changelog
+
+            ## [1.0.0-rc.2] - 2024-01-03
+
+            ### Fixed
+
+            - Fixed two
+
+            [1.0.0-rc.2]: https://github.com/ploys/example/releases/tag/1.0.0-rc.2
+        "};
+
+        assert_eq!(changelog.to_string(), output);
+    }
+
+    #[test]
+    fn test_changelog_package_releases() {
+        let one = Release::new("0.2.0")
+            .with_date("2024-01-02")
+            .with_url("https://github.com/ploys/example/releases/tag/example-one-0.2.0")
+            .with_changeset(Changeset::added().with_change(Change::new("Added one")));
+        let two = Release::new("0.1.1")
+            .with_date("2024-01-02")
+            .with_url("https://github.com/ploys/example/releases/tag/example-two-0.1.1")
+            .with_changeset(Changeset::fixed().with_change(Change::new("Fixed two")))
+            .with_changeset(Changeset::added().with_change(Change::new("Added two")));
+
+        let mut changelog = Changelog::new();
+
+        changelog.add_package_releases([("example-one", one.clone())], ChangelogGrouping::Date);
+        changelog.add_package_releases([("example-two", two.clone())], ChangelogGrouping::Date);
+
+        let expected = indoc! {"
+            ## [2024-01-02]
+
+            ### Added
+
+            - `example-one`: Added one
+            - `example-two`: Added two
+
+            ### Fixed
+
+            - `example-two`: Fixed two
+
+            [2024-01-02]: https://github.com/ploys/example/releases/tag/example-one-0.2.0"
+        };
+
+        assert_eq!(changelog.releases().count(), 1);
+        assert_eq!(
+            changelog.get_release("2024-01-02").unwrap().to_string(),
+            expected
+        );
+
+        let mut changelog = Changelog::new();
+
+        changelog.add_package_releases(
+            [("example-one", one), ("example-two", two)],
+            ChangelogGrouping::ReleaseTrain,
+        );
+
+        let release = changelog.releases().next().unwrap();
+
+        assert_eq!(release.version(), "example-one@0.2.0, example-two@0.1.1");
+        assert_eq!(release.date(), Some("2024-01-02"));
+        assert_eq!(release.changesets().count(), 2);
+        assert_eq!(
+            "release-train".parse::<ChangelogGrouping>().unwrap(),
+            ChangelogGrouping::ReleaseTrain
+        );
+        assert!("weekly".parse::<ChangelogGrouping>().is_err());
+    }
+
+    #[test]
+    fn test_changelog_package_releases_round_trip() {
+        let release = |version: &str, date: &str| {
+            Release::new(version)
+                .with_date(date)
+                .with_url(format!(
+                    "https://github.com/ploys/example/releases/tag/example-one-{version}"
+                ))
+                .with_changeset(Changeset::fixed().with_change(Change::new("Fixed one")))
+        };
+
+        for grouping in [ChangelogGrouping::Date, ChangelogGrouping::ReleaseTrain] {
+            let mut changelog = Changelog::new_project();
+
+            changelog
+                .add_package_releases([("example-one", release("0.1.0", "2024-01-01"))], grouping);
+            changelog.add_package_releases(
+                [
+                    ("example-one", release("0.2.0", "2024-01-02")),
+                    ("example-two", release("0.1.0", "2024-01-02")),
+                ],
+                grouping,
+            );
+
+            let parsed = changelog.to_string().parse::<Changelog>().unwrap();
+
+            assert_eq!(parsed.to_string(), changelog.to_string());
+            assert_eq!(parsed.validate(), []);
+            assert_eq!(
+                parsed.description().unwrap().to_string(),
+                "All notable changes to this project will be documented in this file.\n\nThe format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),\nand this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html)."
+            );
+        }
+
+        let changelog = indoc! {"
+            # Changelog
+
+            ## [2024-01-01]
+
+            ## [2024-01-02]
+
+            ## [example-one@0.1.0, example-two]
+
+            [2024-01-01]: https://github.com/ploys/example/releases/tag/0.1.0
+            [2024-01-02]: https://github.com/ploys/example/releases/tag/0.2.0
+            [example-one@0.1.0, example-two]: https://github.com/ploys/example/releases/tag/0.3.0
+        "}
+        .parse::<Changelog>()
+        .unwrap();
+
+        assert_eq!(
+            changelog
+                .validate()
+                .iter()
+                .map(|diagnostic| diagnostic.kind().clone())
+                .collect::<Vec<_>>(),
+            [
+                DiagnosticKind::UnorderedVersion(String::from("2024-01-02")),
+                DiagnosticKind::InvalidVersion(String::from("example-one@0.1.0, example-two")),
+            ]
+        );
+    }
 }
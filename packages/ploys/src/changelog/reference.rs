@@ -9,8 +9,14 @@ pub struct ReferenceRef<'a> {
 
 impl ReferenceRef<'_> {
     /// Gets the reference ID.
+    ///
+    /// This is the label as written in the source when available as parsing
+    /// normalizes the identifier and drops any whitespace.
     pub fn id(&self) -> &str {
-        &self.definition.identifier
+        self.definition
+            .label
+            .as_deref()
+            .unwrap_or(&self.definition.identifier)
     }
 
     /// Gets the reference URL.
@@ -33,10 +39,6 @@ impl<'a> ReferenceRef<'a> {
 
 impl Display for ReferenceRef<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "[{}]: {}",
-            self.definition.identifier, self.definition.url
-        )
+        write!(f, "[{}]: {}", self.id(), self.url())
     }
 }
@@ -2,17 +2,23 @@ use std::fmt::{self, Display};
 
 use markdown::ParseOptions;
 use markdown::mdast::Node;
+use serde::{Deserialize, Serialize};
 
-use super::{Changeset, ChangesetRef, MultilineText, ReferenceRef};
+use super::{Changeset, ChangesetRef, LabelMapping, MultilineText, ReferenceRef};
 
 /// A changelog release.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Release {
     version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     date: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     description: Option<String>,
+    #[serde(default)]
     changesets: Vec<Changeset>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty", with = "references")]
     references: Vec<(String, String)>,
 }
 
@@ -29,11 +35,21 @@ impl Release {
         }
     }
 
+    /// Constructs a new `Unreleased` changelog release.
+    pub fn unreleased() -> Self {
+        Self::new("Unreleased")
+    }
+
     /// Gets the release version.
     pub fn version(&self) -> &str {
         &self.version
     }
 
+    /// Checks whether this is the `Unreleased` release.
+    pub fn is_unreleased(&self) -> bool {
+        self.version.eq_ignore_ascii_case("unreleased")
+    }
+
     /// Sets the release version.
     pub fn set_version(&mut self, version: impl Into<String>) -> &mut Self {
         self.version = version.into();
@@ -109,11 +125,66 @@ impl Release {
         self
     }
 
+    /// Gets the changeset for the given label.
+    pub fn get_changeset(&self, label: impl AsRef<str>) -> Option<&Changeset> {
+        self.changesets
+            .iter()
+            .find(|changeset| changeset.label() == label.as_ref())
+    }
+
+    /// Gets the mutable changeset for the given label.
+    pub fn get_changeset_mut(&mut self, label: impl AsRef<str>) -> Option<&mut Changeset> {
+        self.changesets
+            .iter_mut()
+            .find(|changeset| changeset.label() == label.as_ref())
+    }
+
     /// Gets an iterator over the changesets.
     pub fn changesets(&self) -> impl Iterator<Item = &Changeset> {
         self.changesets.iter()
     }
 
+    /// Merges the description and changesets of another release.
+    ///
+    /// The description is only kept if this release does not have one and the
+    /// changes are appended to the changesets with the same label. Changes with
+    /// the same message and URL as an existing change are skipped so that the
+    /// changes shared between releases are only listed once.
+    pub fn merge(&mut self, release: impl Into<Release>) -> &mut Self {
+        let release = release.into();
+
+        if self.description.is_none() {
+            self.description = release.description;
+        }
+
+        for changeset in release.changesets {
+            match self.get_changeset_mut(changeset.label()) {
+                Some(current) => {
+                    for change in changeset.changes() {
+                        let exists = current.changes().any(|current| {
+                            current.message() == change.message() && current.url() == change.url()
+                        });
+
+                        if !exists {
+                            current.add_change(change.clone());
+                        }
+                    }
+                }
+                None => {
+                    self.changesets.push(changeset);
+                }
+            }
+        }
+
+        self.sort_changesets()
+    }
+
+    /// Sorts the changesets into the keep a changelog order.
+    pub(crate) fn sort_changesets(&mut self) -> &mut Self {
+        LabelMapping::sort_changesets(&mut self.changesets);
+        self
+    }
+
     /// Adds a reference to the release.
     pub fn add_reference(&mut self, id: impl Into<String>, url: impl Into<String>) -> &mut Self {
         self.references.push((id.into(), url.into()));
@@ -130,6 +201,11 @@ impl Release {
     pub fn references(&self) -> impl Iterator<Item = (&str, &str)> {
         self.references.iter().map(|(id, url)| (&**id, &**url))
     }
+
+    /// Removes all references.
+    pub(super) fn clear_references(&mut self) {
+        self.references.clear();
+    }
 }
 
 impl Release {
@@ -241,6 +317,11 @@ impl<'a> ReleaseRef<'a> {
         self.version
     }
 
+    /// Checks whether this is the `Unreleased` release.
+    pub fn is_unreleased(&self) -> bool {
+        self.version.eq_ignore_ascii_case("unreleased")
+    }
+
     /// Gets the release date.
     pub fn date(&self) -> Option<&str> {
         self.date
@@ -336,8 +417,23 @@ impl<'a> ReleaseRef<'a> {
             return None;
         }
 
+        // The unreleased heading is only parsed as a link reference when the
+        // changelog includes the matching definition.
+        if let [Node::Text(text)] = &*heading.children
+            && text.value.trim().eq_ignore_ascii_case("[unreleased]")
+        {
+            return Some(Self {
+                version: "Unreleased",
+                date: None,
+                nodes: &nodes[1..],
+            });
+        }
+
         let version = heading.children.iter().find_map(|node| match node {
-            Node::LinkReference(link) => Some(&link.identifier),
+            Node::LinkReference(link) if link.identifier == "unreleased" => {
+                Some(link.label.as_deref().unwrap_or("Unreleased"))
+            }
+            Node::LinkReference(link) => Some(link.label.as_deref().unwrap_or(&link.identifier)),
             _ => None,
         })?;
 
@@ -415,6 +511,37 @@ impl Display for ReleaseRef<'_> {
     }
 }
 
+/// Serializes the release references as link definitions.
+mod references {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Reference {
+        id: String,
+        url: String,
+    }
+
+    pub fn serialize<S>(references: &[(String, String)], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(references.iter().map(|(id, url)| Reference {
+            id: id.clone(),
+            url: url.clone(),
+        }))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<(String, String)>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Vec::<Reference>::deserialize(deserializer)?
+            .into_iter()
+            .map(|reference| (reference.id, reference.url))
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use indoc::indoc;
@@ -509,4 +636,61 @@ mod tests {
 
         assert_eq!(release_ref.to_owned(), release);
     }
+
+    #[test]
+    fn test_release_merge() {
+        let mut release = Release::new("1.0.0")
+            .with_changeset(
+                Changeset::fixed().with_change(
+                    Change::new("Fixed `one`")
+                        .with_url("#1", "https://github.com/ploys/example/pull/1"),
+                ),
+            )
+            .with_changeset(
+                Changeset::new_contributors().with_change(
+                    Change::new("@octocat made their first contribution")
+                        .with_url("#1", "https://github.com/ploys/example/pull/1"),
+                ),
+            );
+
+        let rc2 = Release::new("1.0.0-rc.2")
+            .with_changeset(
+                Changeset::fixed()
+                    .with_change(
+                        Change::new("Fixed `one`")
+                            .with_url("#1", "https://github.com/ploys/example/pull/1"),
+                    )
+                    .with_change(
+                        Change::new("Fixed `two`")
+                            .with_url("#2", "https://github.com/ploys/example/pull/2"),
+                    ),
+            )
+            .with_changeset(
+                Changeset::new_contributors().with_change(
+                    Change::new("@octocat made their first contribution")
+                        .with_url("#1", "https://github.com/ploys/example/pull/1"),
+                ),
+            );
+
+        let rc1 = Release::new("1.0.0-rc.1").with_changeset(Changeset::fixed().with_change(
+            Change::new("Fixed `two`").with_url("#2", "https://github.com/ploys/example/pull/2"),
+        ));
+
+        release.merge(rc2).merge(rc1);
+
+        let output = indoc! {"
+            ## [1.0.0]
+
+            ### Fixed
+
+            - Fixed `one` ([#1](https://github.com/ploys/example/pull/1))
+            - Fixed `two` ([#2](https://github.com/ploys/example/pull/2))
+
+            ### New Contributors
+
+            - @octocat made their first contribution ([#1](https://github.com/ploys/example/pull/1))\
+        "};
+
+        assert_eq!(release.to_string(), output);
+    }
 }
@@ -8,12 +8,13 @@ use super::flows::access_token::AccessTokenFlow;
 use super::flows::device_code::DeviceCodeFlow;
 use super::flows::keyring::KeyringFlow;
 use super::flows::refresh_token::RefreshTokenFlow;
-use super::{Client, Credentials, Error, ServAddr, Token};
+use super::{Client, Credentials, Error, GitHubAddr, ServAddr, Token};
 
 /// The project management client builder.
 #[derive(Clone, Debug, Default)]
 pub struct Builder<T = ()> {
     server: ServAddr,
+    github: GitHubAddr,
     auth_flow: T,
     credentials: Option<Credentials>,
 }
@@ -23,6 +24,7 @@ impl Builder {
     pub fn new() -> Self {
         Self {
             server: ServAddr::default(),
+            github: GitHubAddr::default(),
             auth_flow: (),
             credentials: None,
         }
@@ -91,6 +93,20 @@ impl<T> Builder<T> {
         self
     }
 
+    /// Sets the GitHub address.
+    ///
+    /// This defaults to `github.com` and may be set to a GitHub Enterprise
+    /// Server host.
+    pub fn set_github(&mut self, github: impl Into<GitHubAddr>) {
+        self.github = github.into();
+    }
+
+    /// Builds the client with the given GitHub address.
+    pub fn with_github(mut self, github: impl Into<GitHubAddr>) -> Self {
+        self.set_github(github);
+        self
+    }
+
     /// Sets the client authentication credentials.
     pub fn set_credentials(&mut self, credentials: impl Into<Credentials>) {
         self.credentials = Some(credentials.into());
@@ -106,6 +122,7 @@ impl<T> Builder<T> {
     pub fn map_authentication_flow<U>(self, f: impl FnOnce(T) -> U) -> Builder<U> {
         Builder {
             server: self.server,
+            github: self.github,
             auth_flow: f(self.auth_flow),
             credentials: self.credentials,
         }
@@ -120,6 +137,7 @@ where
     pub fn finished(self) -> Result<Client, Error> {
         Ok(Client {
             server: self.server,
+            github: self.github,
             auth_flow: Arc::new(self.auth_flow),
             credentials: Arc::new(RwLock::new(self.credentials)),
             http_client: HttpClient::builder()
@@ -3,7 +3,7 @@ mod error;
 use reqwest::blocking::Client as HttpClient;
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Credentials, ServAddr, Token, TokenType};
+use crate::client::{Credentials, GitHubAddr, ServAddr, Token, TokenType};
 
 pub use self::error::Error;
 
@@ -35,11 +35,12 @@ impl Authenticate for AccessTokenFlow {
         credentials: &mut Option<Credentials>,
         http_client: &HttpClient,
         _: &ServAddr,
+        github: &GitHubAddr,
     ) -> Result<(), Self::Error> {
         match self.token.token_type() {
             TokenType::Personal | TokenType::OAuth | TokenType::User => {
                 let user = http_client
-                    .get("https://api.github.com/user")
+                    .get(format!("{}/user", github.api_url()))
                     .header("Accept", "application/vnd.github+json")
                     .header("X-GitHub-Api-Version", "2026-03-10")
                     .bearer_auth(self.token.value())
@@ -54,7 +55,7 @@ impl Authenticate for AccessTokenFlow {
             }
             TokenType::Installation => {
                 let user = http_client
-                    .post("https://api.github.com/graphql")
+                    .post(github.graphql_url())
                     .bearer_auth(self.token.value())
                     .json(&GraphQLPayload {
                         query: "query { viewer { login } }",
@@ -11,7 +11,7 @@ use serde_with::{DisplayFromStr, serde_as};
 use time::OffsetDateTime;
 use url::Url;
 
-use crate::client::{Credentials, ServAddr, Token};
+use crate::client::{Credentials, GitHubAddr, ServAddr, Token};
 
 pub use self::error::Error;
 
@@ -43,6 +43,7 @@ impl Authenticate for DeviceCodeFlow {
         credentials: &mut Option<Credentials>,
         http_client: &HttpClient,
         server: &ServAddr,
+        github: &GitHubAddr,
     ) -> Result<(), Self::Error> {
         let client_id = self.client_id.get_or_try_init(|| {
             Ok::<_, Error>(
@@ -57,7 +58,7 @@ impl Authenticate for DeviceCodeFlow {
         })?;
 
         let code_response: CodeResponse = http_client
-            .post("https://github.com/login/device/code")
+            .post(format!("{}/login/device/code", github.url()))
             .header("Accept", "application/json")
             .form(&[("client_id", &**client_id)])
             .send()?
@@ -79,7 +80,7 @@ impl Authenticate for DeviceCodeFlow {
             }
 
             let token_response: TokenResponse = http_client
-                .post("https://github.com/login/oauth/access_token")
+                .post(format!("{}/login/oauth/access_token", github.url()))
                 .header("Accept", "application/json")
                 .form(&[
                     ("client_id", &**client_id),
@@ -112,7 +113,7 @@ impl Authenticate for DeviceCodeFlow {
                     }
 
                     let user = http_client
-                        .get("https://api.github.com/user")
+                        .get(format!("{}/user", github.api_url()))
                         .header("Accept", "application/vnd.github+json")
                         .header("X-GitHub-Api-Version", "2026-03-10")
                         .bearer_auth(access_token.value())
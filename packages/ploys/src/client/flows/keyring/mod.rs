@@ -8,7 +8,7 @@ use dialoguer::Select;
 use keyring_core::CredentialStore;
 use reqwest::blocking::Client as HttpClient;
 
-use crate::client::{Credentials, ServAddr};
+use crate::client::{Credentials, GitHubAddr, ServAddr};
 
 pub use self::error::Error;
 
@@ -74,6 +74,7 @@ where
         credentials: &mut Option<Credentials>,
         http_client: &HttpClient,
         server: &ServAddr,
+        github: &GitHubAddr,
     ) -> Result<(), Self::Error> {
         let store = self.get_store()?;
         let service = format!("ploys:{server}");
@@ -126,7 +127,7 @@ where
                     *credentials = Some(creds);
 
                     self.auth_flow
-                        .authenticate(credentials, http_client, server)
+                        .authenticate(credentials, http_client, server, github)
                         .map_err(Error::Inner)?;
 
                     if let Some(credentials) = credentials {
@@ -144,7 +145,7 @@ where
             }
             None => {
                 self.auth_flow
-                    .authenticate(credentials, http_client, server)
+                    .authenticate(credentials, http_client, server, github)
                     .map_err(Error::Inner)?;
 
                 if let Some(credentials) = credentials {
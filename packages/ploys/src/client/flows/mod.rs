@@ -9,7 +9,7 @@ use std::fmt::Debug;
 
 use reqwest::blocking::Client as HttpClient;
 
-use super::{Credentials, ServAddr};
+use super::{Credentials, GitHubAddr, ServAddr};
 
 pub trait Authenticate: Debug + Send + Sync + 'static {
     type Error: Error + Send + Sync + 'static;
@@ -19,6 +19,7 @@ pub trait Authenticate: Debug + Send + Sync + 'static {
         credentials: &mut Option<Credentials>,
         http_client: &HttpClient,
         server: &ServAddr,
+        github: &GitHubAddr,
     ) -> Result<(), Self::Error>;
 }
 
@@ -30,6 +31,7 @@ impl Authenticate for () {
         _: &mut Option<Credentials>,
         _: &HttpClient,
         _: &ServAddr,
+        _: &GitHubAddr,
     ) -> Result<(), Self::Error> {
         Ok(())
     }
@@ -41,6 +43,7 @@ pub(super) trait DynAuthenticate: Debug {
         credentials: &mut Option<Credentials>,
         http_client: &HttpClient,
         server: &ServAddr,
+        github: &GitHubAddr,
     ) -> Result<(), Box<dyn Error + Send + Sync>>;
 }
 
@@ -53,8 +56,9 @@ where
         credentials: &mut Option<Credentials>,
         http_client: &HttpClient,
         server: &ServAddr,
+        github: &GitHubAddr,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        self.authenticate(credentials, http_client, server)
+        self.authenticate(credentials, http_client, server, github)
             .map_err(Box::new)
             .map_err(Into::into)
     }
@@ -8,7 +8,7 @@ use serde::Deserialize;
 use serde_with::{DisplayFromStr, serde_as};
 use time::OffsetDateTime;
 
-use crate::client::{Credentials, ServAddr, Token};
+use crate::client::{Credentials, GitHubAddr, ServAddr, Token};
 
 pub use self::error::Error;
 
@@ -45,6 +45,7 @@ where
         credentials: &mut Option<Credentials>,
         http_client: &HttpClient,
         server: &ServAddr,
+        github: &GitHubAddr,
     ) -> Result<(), Self::Error> {
         let refresh_token = match credentials.as_ref().and_then(Credentials::refresh_token) {
             Some(refresh_token) if refresh_token.is_expired() => None,
@@ -54,7 +55,7 @@ where
 
         let Some(refresh_token) = refresh_token else {
             self.auth_flow
-                .authenticate(credentials, http_client, server)
+                .authenticate(credentials, http_client, server, github)
                 .map_err(Error::Inner)?;
 
             return Ok(());
@@ -73,7 +74,7 @@ where
         })?;
 
         let token_response: TokenResponse = http_client
-            .post("https://github.com/login/oauth/access_token")
+            .post(format!("{}/login/oauth/access_token", github.url()))
             .header("Accept", "application/json")
             .form(&[
                 ("client_id", &**client_id),
@@ -92,7 +93,7 @@ where
         }
 
         let user = http_client
-            .get("https://api.github.com/user")
+            .get(format!("{}/user", github.api_url()))
             .header("Accept", "application/vnd.github+json")
             .header("X-GitHub-Api-Version", "2026-03-10")
             .bearer_auth(access_token.value())
@@ -0,0 +1,142 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use url::{ParseError, Url};
+
+use crate::repository::RepoAddr;
+
+/// The GitHub address.
+///
+/// This defaults to `github.com` and may instead point to a GitHub Enterprise
+/// Server host where the REST API is served at `/api/v3` and the GraphQL API
+/// at `/api/graphql`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GitHubAddr {
+    url: String,
+    api_url: String,
+}
+
+impl GitHubAddr {
+    /// Gets the host.
+    pub fn host(&self) -> &str {
+        self.url
+            .split_once("://")
+            .map(|(_, host)| host)
+            .unwrap_or(&self.url)
+    }
+
+    /// Gets the web URL with the scheme, host and port.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Gets the REST API URL.
+    pub fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    /// Gets the GraphQL API URL.
+    pub fn graphql_url(&self) -> String {
+        match self.api_url.strip_suffix("/v3") {
+            Some(base) => format!("{base}/graphql"),
+            None => format!("{}/graphql", self.api_url),
+        }
+    }
+
+    /// Sets the REST API URL.
+    ///
+    /// This overrides the URL derived from the host which is useful to target
+    /// a proxy or a local server.
+    pub fn set_api_url(&mut self, api_url: Url) {
+        self.api_url = api_url.as_str().trim_end_matches('/').to_owned();
+    }
+
+    /// Builds the address with the given REST API URL.
+    pub fn with_api_url(mut self, api_url: Url) -> Self {
+        self.set_api_url(api_url);
+        self
+    }
+}
+
+impl Display for GitHubAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.host())
+    }
+}
+
+impl Default for GitHubAddr {
+    fn default() -> Self {
+        Self {
+            url: String::from("https://github.com"),
+            api_url: String::from("https://api.github.com"),
+        }
+    }
+}
+
+impl FromStr for GitHubAddr {
+    type Err = ParseError;
+
+    /// Parses the address from a host or URL.
+    ///
+    /// The scheme defaults to `https` when not specified.
+    fn from_str(host: &str) -> Result<Self, Self::Err> {
+        let url = match host.contains("://") {
+            true => Url::parse(host)?,
+            false => Url::parse(&format!("https://{host}"))?,
+        };
+
+        let Some(host) = url.host_str() else {
+            return Err(ParseError::EmptyHost);
+        };
+
+        if host == "github.com" {
+            return Ok(Self::default());
+        }
+
+        let url = url[..url::Position::BeforePath].to_owned();
+
+        Ok(Self {
+            api_url: format!("{url}/api/v3"),
+            url,
+        })
+    }
+}
+
+impl From<&RepoAddr> for GitHubAddr {
+    fn from(repo: &RepoAddr) -> Self {
+        repo.base_url().parse().expect("valid url")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::GitHubAddr;
+
+    #[test]
+    fn test_github_addr() {
+        let github = GitHubAddr::default();
+
+        assert_eq!(github.host(), "github.com");
+        assert_eq!(github.api_url(), "https://api.github.com");
+        assert_eq!(github.graphql_url(), "https://api.github.com/graphql");
+        assert_eq!("github.com".parse::<GitHubAddr>().unwrap(), github);
+
+        let github = "ghe.example.com".parse::<GitHubAddr>().unwrap();
+
+        assert_eq!(github.host(), "ghe.example.com");
+        assert_eq!(github.url(), "https://ghe.example.com");
+        assert_eq!(github.api_url(), "https://ghe.example.com/api/v3");
+        assert_eq!(github.graphql_url(), "https://ghe.example.com/api/graphql");
+
+        let github = "http://localhost:8080"
+            .parse::<GitHubAddr>()
+            .unwrap()
+            .with_api_url(Url::parse("http://localhost:8081/").unwrap());
+
+        assert_eq!(github.host(), "localhost:8080");
+        assert_eq!(github.api_url(), "http://localhost:8081");
+        assert_eq!(github.graphql_url(), "http://localhost:8081/graphql");
+    }
+}
@@ -2,6 +2,7 @@ mod builder;
 mod credentials;
 mod error;
 pub mod flows;
+mod github;
 mod projects;
 mod server;
 
@@ -16,6 +17,7 @@ use crate::repository::types::github::{Error as RepoError, GitHub};
 pub use self::builder::Builder;
 pub use self::credentials::{Credentials, Token, TokenError, TokenType};
 pub use self::error::Error;
+pub use self::github::GitHubAddr;
 pub use self::server::ServAddr;
 
 use self::error::MissingCredentials;
@@ -26,6 +28,7 @@ use self::projects::Projects;
 #[derive(Clone, Debug)]
 pub struct Client {
     server: ServAddr,
+    github: GitHubAddr,
     auth_flow: Arc<dyn DynAuthenticate>,
     credentials: Arc<RwLock<Option<Credentials>>>,
     http_client: HttpClient,
@@ -49,8 +52,9 @@ impl Client {
     where
         R: TryInto<RepoAddr, Error: Into<RepoError>>,
     {
+        let repo = self.repo_addr(repo).map_err(ProjError::Repository)?;
         let repo = GitHub::new(self.clone(), repo).map_err(ProjError::Repository)?;
-        let proj = Project::open(repo)?;
+        let proj = Project::open(repo)?.with_tags()?;
 
         Ok(proj)
     }
@@ -60,10 +64,7 @@ impl Client {
     where
         R: TryInto<RepoAddr, Error: Into<RepoError>>,
     {
-        let repo = repo
-            .try_into()
-            .map_err(Into::into)
-            .map_err(ProjError::Repository)?;
+        let repo = self.repo_addr(repo).map_err(ProjError::Repository)?;
 
         Ok(ProjectBuilder::new(repo, self.clone()))
     }
@@ -98,6 +99,32 @@ impl Client {
         &self.http_client
     }
 
+    /// Gets the GitHub address.
+    pub(crate) fn github(&self) -> &GitHubAddr {
+        &self.github
+    }
+
+    /// Converts the repository address to one on the configured GitHub host.
+    ///
+    /// Addresses parsed from the `owner/name` format default to `github.com`
+    /// so these are moved to the configured host when it differs.
+    fn repo_addr<R>(&self, repo: R) -> Result<RepoAddr, RepoError>
+    where
+        R: TryInto<RepoAddr, Error: Into<RepoError>>,
+    {
+        let repo = repo.try_into().map_err(Into::into)?;
+
+        if repo.host() == "github.com" && self.github != GitHubAddr::default() {
+            return Ok(RepoAddr::with_host(
+                self.github.url(),
+                repo.owner(),
+                repo.name(),
+            )?);
+        }
+
+        Ok(repo)
+    }
+
     /// Gets the authentication credentials.
     pub(crate) fn get_credentials(
         &self,
@@ -112,8 +139,12 @@ impl Client {
                 .as_ref()
                 .is_some_and(|credentials| credentials.access_token().is_expired())
         {
-            self.auth_flow
-                .dyn_authenticate(&mut credentials, &self.http_client, &self.server)?;
+            self.auth_flow.dyn_authenticate(
+                &mut credentials,
+                &self.http_client,
+                &self.server,
+                &self.github,
+            )?;
         }
 
         Ok(credentials.clone())
@@ -65,7 +65,8 @@ impl<'a> Repositories<'a> {
                     Some(installation) => {
                         self.next = Some(
                             Url::parse(&format!(
-                                "https://api.github.com/user/installations/{}/repositories",
+                                "{}/user/installations/{}/repositories",
+                                self.installations.client.github().api_url(),
                                 installation.id
                             ))
                             .expect("valid url"),
@@ -123,7 +124,10 @@ impl<'a> Installations<'a> {
         Self {
             client,
             installations: Vec::new().into_iter(),
-            next: Some(Url::parse("https://api.github.com/user/installations").expect("valid url")),
+            next: Some(
+                Url::parse(&format!("{}/user/installations", client.github().api_url()))
+                    .expect("valid url"),
+            ),
         }
     }
 
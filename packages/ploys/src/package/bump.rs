@@ -120,6 +120,33 @@ impl Bump {
         Ok(())
     }
 
+    /// Infers the bump from the given Conventional Commits messages.
+    ///
+    /// Breaking changes are marked with a `!` before the colon or with a
+    /// `BREAKING CHANGE` footer and result in a major bump, features result in
+    /// a minor bump and everything else results in a patch bump. Versions
+    /// before `1.0.0` treat the minor version as the major version so breaking
+    /// changes result in a minor bump and features in a patch bump.
+    pub fn infer<I>(messages: I, version: &Version) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let bump = messages
+            .into_iter()
+            .map(|message| infer_message(message.as_ref()))
+            .max()
+            .unwrap_or(Level::Patch);
+
+        match (bump, version.major) {
+            (Level::Breaking, 0) => Self::Minor,
+            (Level::Breaking, _) => Self::Major,
+            (Level::Feature, 0) => Self::Patch,
+            (Level::Feature, _) => Self::Minor,
+            (Level::Patch, _) => Self::Patch,
+        }
+    }
+
     /// Updates the given version string slice.
     pub fn bump_str(self, version: &str) -> Result<Version, Error> {
         let mut version = version.parse::<Version>()?;
@@ -164,6 +191,8 @@ impl FromStr for Bump {
 pub enum BumpOrVersion {
     Bump(Bump),
     Version(Version),
+    /// The bump inferred from the unreleased changes.
+    Auto,
 }
 
 impl Display for BumpOrVersion {
@@ -171,6 +200,7 @@ impl Display for BumpOrVersion {
         match self {
             Self::Bump(bump) => Display::fmt(bump, f),
             Self::Version(version) => Display::fmt(version, f),
+            Self::Auto => write!(f, "auto"),
         }
     }
 }
@@ -179,6 +209,10 @@ impl FromStr for BumpOrVersion {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "auto" {
+            return Ok(Self::Auto);
+        }
+
         match s.parse::<Bump>() {
             Ok(bump) => Ok(Self::Bump(bump)),
             Err(_) => match s.parse::<Version>() {
@@ -230,6 +264,40 @@ impl From<semver::Error> for Error {
     }
 }
 
+/// The change level of a Conventional Commits message.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Level {
+    Patch,
+    Feature,
+    Breaking,
+}
+
+/// Infers the change level of a Conventional Commits message.
+fn infer_message(message: &str) -> Level {
+    let breaking = message
+        .lines()
+        .skip(1)
+        .any(|line| line.starts_with("BREAKING CHANGE:") || line.starts_with("BREAKING-CHANGE:"));
+
+    let Some((prefix, _)) = message.lines().next().unwrap_or_default().split_once(':') else {
+        return match breaking {
+            true => Level::Breaking,
+            false => Level::Patch,
+        };
+    };
+
+    let kind = prefix.split(['(', '!']).next().unwrap_or_default().trim();
+
+    if breaking || prefix.trim_end().ends_with('!') {
+        return Level::Breaking;
+    }
+
+    match kind.eq_ignore_ascii_case("feat") {
+        true => Level::Feature,
+        false => Level::Patch,
+    }
+}
+
 enum Tag {
     Rc,
     Beta,
@@ -261,7 +329,37 @@ fn parse_prelease(prerelease: &Prerelease) -> Option<(Tag, Option<u64>)> {
 
 #[cfg(test)]
 mod tests {
-    use super::{Bump, Error};
+    use semver::Version;
+
+    use super::{Bump, BumpOrVersion, Error};
+
+    #[test]
+    fn test_bump_infer() {
+        let stable = Version::new(1, 2, 3);
+        let unstable = Version::new(0, 2, 3);
+
+        assert_eq!(Bump::infer(["fix: typo"], &stable), Bump::Patch);
+        assert_eq!(Bump::infer(["Update docs"], &stable), Bump::Patch);
+        assert_eq!(Bump::infer(Vec::<String>::new(), &stable), Bump::Patch);
+        assert_eq!(
+            Bump::infer(["fix: typo", "feat(cli): add flag"], &stable),
+            Bump::Minor
+        );
+        assert_eq!(
+            Bump::infer(["feat(cli)!: remove flag", "fix: typo"], &stable),
+            Bump::Major
+        );
+        assert_eq!(
+            Bump::infer(["refactor: rename\n\nBREAKING CHANGE: renamed"], &stable),
+            Bump::Major
+        );
+        assert_eq!(Bump::infer(["feat: add flag"], &unstable), Bump::Patch);
+        assert_eq!(Bump::infer(["feat!: remove flag"], &unstable), Bump::Minor);
+        assert_eq!(
+            "auto".parse::<BumpOrVersion>().unwrap(),
+            BumpOrVersion::Auto
+        );
+    }
 
     #[test]
     fn test_bump_major() -> Result<(), Error> {
@@ -5,6 +5,12 @@ use strum::EnumIter;
 pub enum PackageKind {
     /// The cargo package kind.
     Cargo,
+    /// The npm package kind.
+    Npm,
+    /// The python package kind.
+    Python,
+    /// The go package kind.
+    Go,
 }
 
 impl PackageKind {
@@ -12,13 +18,22 @@ impl PackageKind {
     pub fn file_name(&self) -> &'static str {
         match self {
             Self::Cargo => "Cargo.toml",
+            Self::Npm => "package.json",
+            Self::Python => "pyproject.toml",
+            Self::Go => "go.mod",
         }
     }
 
-    /// Gets the lockfile name.
-    pub(crate) fn lockfile_name(&self) -> Option<&'static str> {
+    /// Gets the lockfile names.
+    ///
+    /// Package managers that share the same manifest each have their own
+    /// lockfile so there may be more than one.
+    pub(crate) fn lockfile_names(&self) -> &'static [&'static str] {
         match self {
-            Self::Cargo => Some("Cargo.lock"),
+            Self::Cargo => &["Cargo.lock"],
+            Self::Npm => &["package-lock.json", "pnpm-lock.yaml", "yarn.lock"],
+            Self::Python => &[],
+            Self::Go => &[],
         }
     }
 }
@@ -3,17 +3,23 @@ use std::fmt::{self, Display};
 /// A package lockfile error.
 #[derive(Debug)]
 pub enum Error {
+    /// A JSON error.
+    Json(serde_json::Error),
     /// A TOML error.
     Toml(toml_edit::TomlError),
     /// A UTF-8 error.
     Utf8(std::str::Utf8Error),
+    /// An unsupported lockfile error.
+    Unsupported(String),
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::Json(err) => Display::fmt(err, f),
             Self::Toml(err) => Display::fmt(err, f),
             Self::Utf8(err) => Display::fmt(err, f),
+            Self::Unsupported(file_name) => write!(f, "Unsupported lockfile: {file_name}"),
         }
     }
 }
@@ -21,12 +27,20 @@ impl Display for Error {
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
+            Self::Json(err) => Some(err),
             Self::Toml(err) => Some(err),
             Self::Utf8(err) => Some(err),
+            Self::Unsupported(_) => None,
         }
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
 impl From<toml_edit::TomlError> for Error {
     fn from(err: toml_edit::TomlError) -> Self {
         Self::Toml(err)
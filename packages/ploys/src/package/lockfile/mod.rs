@@ -5,6 +5,9 @@
 
 pub mod cargo;
 mod error;
+pub mod npm;
+pub mod pnpm;
+pub mod yarn;
 
 use std::fmt::{self, Display};
 
@@ -15,12 +18,21 @@ use crate::package::PackageKind;
 
 pub use self::cargo::CargoLockfile;
 pub use self::error::Error;
+pub use self::npm::NpmLockfile;
+pub use self::pnpm::PnpmLockfile;
+pub use self::yarn::YarnLockfile;
 
 /// The package lockfile.
 #[derive(Clone, Debug, PartialEq, Eq, EnumIs, EnumTryAs)]
 pub enum Lockfile {
     /// A cargo package lockfile.
     Cargo(CargoLockfile),
+    /// An npm package lockfile.
+    Npm(NpmLockfile),
+    /// A pnpm package lockfile.
+    Pnpm(PnpmLockfile),
+    /// A yarn package lockfile.
+    Yarn(YarnLockfile),
 }
 
 impl Lockfile {
@@ -28,6 +40,7 @@ impl Lockfile {
     pub fn kind(&self) -> PackageKind {
         match self {
             Self::Cargo(_) => PackageKind::Cargo,
+            Self::Npm(_) | Self::Pnpm(_) | Self::Yarn(_) => PackageKind::Npm,
         }
     }
 
@@ -35,15 +48,25 @@ impl Lockfile {
     pub fn set_package_version(&mut self, package: impl AsRef<str>, version: impl Into<Version>) {
         match self {
             Self::Cargo(cargo) => cargo.set_package_version(package, version),
+            Self::Npm(npm) => npm.set_package_version(package, version),
+            Self::Pnpm(pnpm) => pnpm.set_package_version(package, version),
+            Self::Yarn(yarn) => yarn.set_package_version(package, version),
         }
     }
 }
 
 impl Lockfile {
-    /// Constructs a lockfile from the given bytes.
-    pub(crate) fn from_bytes(kind: PackageKind, bytes: &[u8]) -> Result<Self, Error> {
-        match kind {
-            PackageKind::Cargo => Ok(Self::Cargo(CargoLockfile::from_bytes(bytes)?)),
+    /// Constructs a lockfile from the given file name and bytes.
+    ///
+    /// This returns an error if the file name is not one of the [`PackageKind`]
+    /// lockfile names.
+    pub(crate) fn from_bytes(file_name: &str, bytes: &[u8]) -> Result<Self, Error> {
+        match file_name {
+            "Cargo.lock" => Ok(Self::Cargo(CargoLockfile::from_bytes(bytes)?)),
+            "package-lock.json" => Ok(Self::Npm(NpmLockfile::from_bytes(bytes)?)),
+            "pnpm-lock.yaml" => Ok(Self::Pnpm(PnpmLockfile::from_bytes(bytes)?)),
+            "yarn.lock" => Ok(Self::Yarn(YarnLockfile::from_bytes(bytes)?)),
+            _ => Err(Error::Unsupported(file_name.to_owned())),
         }
     }
 }
@@ -52,6 +75,66 @@ impl Display for Lockfile {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Cargo(cargo) => Display::fmt(cargo, f),
+            Self::Npm(npm) => Display::fmt(npm, f),
+            Self::Pnpm(pnpm) => Display::fmt(pnpm, f),
+            Self::Yarn(yarn) => Display::fmt(yarn, f),
         }
     }
 }
+
+/// Sets the version of a version requirement.
+///
+/// This preserves any prefix such as `^`, `~` or `npm:^` and returns `None` for
+/// requirements without a single version such as `*` or `workspace:^`.
+fn set_requirement_version(requirement: &str, version: &Version) -> Option<String> {
+    let index = requirement.find(|c: char| c.is_ascii_digit())?;
+    let (operator, current) = requirement.split_at(index);
+
+    current.parse::<Version>().ok()?;
+
+    Some(format!("{operator}{version}"))
+}
+
+/// Splits an indented `key: value` line from a YAML lockfile.
+///
+/// This returns the indentation along with the unquoted key and value.
+fn yaml_entry(line: &str) -> Option<(usize, &str, &str)> {
+    let trimmed = line.trim_start();
+
+    if trimmed.is_empty() || trimmed.starts_with('#') {
+        return None;
+    }
+
+    let (key, value) = match trimmed.split_once(": ") {
+        Some((key, value)) => (key, value.trim()),
+        None => (trimmed.strip_suffix(':')?, ""),
+    };
+
+    Some((line.len() - trimmed.len(), unquote(key), unquote(value)))
+}
+
+/// Sets the value of a `key: value` line from a YAML lockfile.
+///
+/// This preserves the existing quotes around the value.
+fn set_yaml_value(line: &mut String, value: &str) {
+    let Some(index) = line.find(": ") else {
+        return;
+    };
+
+    let current = line[index + 2..].trim_end();
+    let quote = match current.chars().next() {
+        Some(quote @ ('"' | '\'')) => quote.to_string(),
+        _ => String::new(),
+    };
+
+    line.truncate(index + 2);
+    line.push_str(&format!("{quote}{value}{quote}"));
+}
+
+/// Removes the surrounding quotes from a YAML scalar.
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(value)
+}
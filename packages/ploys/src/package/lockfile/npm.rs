@@ -0,0 +1,191 @@
+//! The `package-lock.json` package lockfile for npm.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use semver::Version;
+use serde_json::{Map, Value};
+
+use super::{Error, set_requirement_version};
+
+/// The dependency groups of a package entry.
+const DEPENDENCY_KEYS: [&str; 4] = [
+    "dependencies",
+    "devDependencies",
+    "optionalDependencies",
+    "peerDependencies",
+];
+
+/// The npm package lockfile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NpmLockfile(Map<String, Value>);
+
+impl NpmLockfile {
+    /// Gets the version of a workspace package.
+    pub fn get_package_version(&self, package: impl AsRef<str>) -> Option<Version> {
+        let path = self.package_path(package.as_ref())?;
+
+        self.0
+            .get("packages")?
+            .get(path)?
+            .get("version")?
+            .as_str()?
+            .parse()
+            .ok()
+    }
+
+    /// Sets the version of a workspace package.
+    ///
+    /// This also updates the requirements on the package from other workspace
+    /// packages so that they match the updated `package.json` manifests.
+    pub fn set_package_version(&mut self, package: impl AsRef<str>, version: impl Into<Version>) {
+        let package = package.as_ref();
+        let version = version.into();
+
+        let Some(path) = self.package_path(package).map(ToOwned::to_owned) else {
+            return;
+        };
+
+        if path.is_empty() {
+            self.0
+                .insert("version".into(), Value::from(version.to_string()));
+        }
+
+        let Some(packages) = self.0.get_mut("packages").and_then(Value::as_object_mut) else {
+            return;
+        };
+
+        if let Some(entry) = packages.get_mut(&path).and_then(Value::as_object_mut) {
+            entry.insert("version".into(), Value::from(version.to_string()));
+        }
+
+        let entries = packages
+            .iter_mut()
+            .filter(|(path, _)| !path.contains("node_modules/"))
+            .filter_map(|(_, entry)| entry.as_object_mut());
+
+        for entry in entries {
+            for key in DEPENDENCY_KEYS {
+                let requirement = entry
+                    .get_mut(key)
+                    .and_then(|dependencies| dependencies.get_mut(package));
+
+                if let Some(requirement) = requirement
+                    && let Some(updated) = requirement
+                        .as_str()
+                        .and_then(|current| set_requirement_version(current, &version))
+                {
+                    *requirement = Value::from(updated);
+                }
+            }
+        }
+    }
+
+    /// Gets the path of a workspace package.
+    ///
+    /// Workspace packages are linked into `node_modules` from their location
+    /// in the workspace, except for the root package which has an empty path.
+    fn package_path(&self, package: &str) -> Option<&str> {
+        let packages = self.0.get("packages")?.as_object()?;
+
+        if self.0.get("name").and_then(Value::as_str) == Some(package) {
+            return Some("");
+        }
+
+        let link = packages.get(&format!("node_modules/{package}"))?;
+
+        match link.get("link").and_then(Value::as_bool) {
+            Some(true) => link.get("resolved")?.as_str(),
+            _ => None,
+        }
+    }
+}
+
+impl NpmLockfile {
+    /// Creates a lockfile from the given bytes.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        std::str::from_utf8(bytes)?.parse()
+    }
+}
+
+impl Display for NpmLockfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string_pretty(&self.0).map_err(|_| fmt::Error)?;
+
+        writeln!(f, "{json}")
+    }
+}
+
+impl FromStr for NpmLockfile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(serde_json::from_str(s)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use super::NpmLockfile;
+
+    #[test]
+    fn test_package_version() {
+        let content = indoc::indoc! {r#"
+            {
+              "name": "example",
+              "version": "0.1.0",
+              "lockfileVersion": 3,
+              "requires": true,
+              "packages": {
+                "": {
+                  "name": "example",
+                  "version": "0.1.0",
+                  "workspaces": [
+                    "packages/*"
+                  ]
+                },
+                "node_modules/example-one": {
+                  "resolved": "packages/example-one",
+                  "link": true
+                },
+                "node_modules/example-two": {
+                  "resolved": "packages/example-two",
+                  "link": true
+                },
+                "packages/example-one": {
+                  "version": "0.1.0"
+                },
+                "packages/example-two": {
+                  "version": "0.2.0",
+                  "dependencies": {
+                    "example-one": "^0.1.0"
+                  }
+                }
+              }
+            }
+        "#};
+
+        let mut lockfile = content.parse::<NpmLockfile>().unwrap();
+
+        assert_eq!(lockfile.to_string(), content);
+        assert_eq!(
+            lockfile.get_package_version("example"),
+            Some(Version::new(0, 1, 0))
+        );
+        assert_eq!(
+            lockfile.get_package_version("example-two"),
+            Some(Version::new(0, 2, 0))
+        );
+        assert_eq!(lockfile.get_package_version("example-three"), None);
+
+        lockfile.set_package_version("example-one", Version::new(0, 2, 0));
+
+        assert_eq!(
+            lockfile.get_package_version("example-one"),
+            Some(Version::new(0, 2, 0))
+        );
+        assert!(lockfile.to_string().contains(r#""example-one": "^0.2.0""#));
+    }
+}
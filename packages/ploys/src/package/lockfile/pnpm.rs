@@ -0,0 +1,149 @@
+//! The `pnpm-lock.yaml` package lockfile for pnpm.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use semver::Version;
+
+use super::{Error, set_requirement_version, set_yaml_value, yaml_entry};
+
+/// The pnpm package lockfile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PnpmLockfile(Vec<String>);
+
+impl PnpmLockfile {
+    /// Sets the version of a workspace package.
+    ///
+    /// The pnpm lockfile does not record the versions of workspace packages so
+    /// this updates the specifiers of other workspace packages that link to the
+    /// package so that they match the updated `package.json` manifests.
+    pub fn set_package_version(&mut self, package: impl AsRef<str>, version: impl Into<Version>) {
+        let package = package.as_ref();
+        let version = version.into();
+
+        let mut importers = false;
+        let mut specifiers = Vec::new();
+
+        for (index, line) in self.0.iter().enumerate() {
+            let Some((indent, key, value)) = yaml_entry(line) else {
+                continue;
+            };
+
+            if indent == 0 {
+                importers = key == "importers";
+
+                continue;
+            }
+
+            if !importers || indent != 6 || key != package || !value.is_empty() {
+                continue;
+            }
+
+            let mut specifier = None;
+            let mut linked = false;
+
+            for (index, line) in self.0.iter().enumerate().skip(index + 1) {
+                match yaml_entry(line) {
+                    Some((indent, _, _)) if indent <= 6 => break,
+                    Some((8, "specifier", _)) => specifier = Some(index),
+                    Some((8, "version", value)) => linked = value.starts_with("link:"),
+                    _ => {}
+                }
+            }
+
+            if let Some(index) = specifier
+                && linked
+            {
+                specifiers.push(index);
+            }
+        }
+
+        for index in specifiers {
+            let line = &mut self.0[index];
+
+            if let Some((_, _, current)) = yaml_entry(line)
+                && let Some(updated) = set_requirement_version(current, &version)
+            {
+                set_yaml_value(line, &updated);
+            }
+        }
+    }
+}
+
+impl PnpmLockfile {
+    /// Creates a lockfile from the given bytes.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        std::str::from_utf8(bytes)?.parse()
+    }
+}
+
+impl Display for PnpmLockfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.0 {
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PnpmLockfile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.lines().map(ToOwned::to_owned).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use super::PnpmLockfile;
+
+    #[test]
+    fn test_package_version() {
+        let content = indoc::indoc! {"
+            lockfileVersion: '9.0'
+
+            importers:
+
+              .: {}
+
+              packages/example-one: {}
+
+              packages/example-two:
+                dependencies:
+                  example-one:
+                    specifier: ^0.1.0
+                    version: link:../example-one
+                devDependencies:
+                  example-three:
+                    specifier: '>=0.1.0'
+                    version: 0.1.0
+
+              packages/example-four:
+                dependencies:
+                  example-one:
+                    specifier: '>=0.1.0'
+                    version: link:../example-one
+        "};
+
+        let mut lockfile = content.parse::<PnpmLockfile>().unwrap();
+
+        assert_eq!(lockfile.to_string(), content);
+
+        lockfile.set_package_version("example-one", Version::new(0, 2, 0));
+        lockfile.set_package_version("example-three", Version::new(0, 2, 0));
+
+        assert_eq!(
+            lockfile.to_string(),
+            content
+                .replace("specifier: ^0.1.0", "specifier: ^0.2.0")
+                .replace(
+                    "specifier: '>=0.1.0'\n        version: link",
+                    "specifier: '>=0.2.0'\n        version: link"
+                )
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! The `yarn.lock` package lockfile for Yarn.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use semver::Version;
+
+use super::{Error, set_requirement_version, set_yaml_value, yaml_entry};
+
+/// The yarn package lockfile.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct YarnLockfile(Vec<String>);
+
+impl YarnLockfile {
+    /// Sets the version of a workspace package.
+    ///
+    /// The yarn lockfile does not record the versions of workspace packages so
+    /// this updates the descriptors that resolve to the workspace package so
+    /// that they match the updated `package.json` manifests. Lockfiles from
+    /// Yarn 1 do not include workspace packages and are left unchanged.
+    pub fn set_package_version(&mut self, package: impl AsRef<str>, version: impl Into<Version>) {
+        let package = package.as_ref();
+        let version = version.into();
+        let workspace = format!("{package}@workspace:");
+
+        let mut ranges = Vec::new();
+
+        for line in &mut self.0 {
+            let Some((0, key, "")) = yaml_entry(line) else {
+                continue;
+            };
+
+            let descriptors = key.split(", ").collect::<Vec<_>>();
+
+            if !descriptors
+                .iter()
+                .any(|descriptor| descriptor.starts_with(&workspace))
+            {
+                continue;
+            }
+
+            let descriptors = descriptors
+                .into_iter()
+                .map(|descriptor| {
+                    let range = descriptor
+                        .strip_prefix(package)
+                        .and_then(|range| range.strip_prefix("@npm:"));
+
+                    match range
+                        .and_then(|range| Some((range, set_requirement_version(range, &version)?)))
+                    {
+                        Some((range, updated)) => {
+                            ranges.push((range.to_owned(), updated.clone()));
+
+                            format!("{package}@npm:{updated}")
+                        }
+                        None => descriptor.to_owned(),
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            *line = format!("\"{}\":", descriptors.join(", "));
+        }
+
+        let mut dependencies = false;
+
+        for line in &mut self.0 {
+            let Some((indent, key, value)) = yaml_entry(line) else {
+                continue;
+            };
+
+            if indent <= 2 {
+                dependencies = indent == 2 && key.ends_with("ependencies");
+
+                continue;
+            }
+
+            if !dependencies || indent != 4 || key != package {
+                continue;
+            }
+
+            let updated = value.strip_prefix("npm:").and_then(|range| {
+                ranges
+                    .iter()
+                    .find(|(current, _)| current == range)
+                    .map(|(_, updated)| format!("npm:{updated}"))
+            });
+
+            if let Some(updated) = updated {
+                set_yaml_value(line, &updated);
+            }
+        }
+    }
+}
+
+impl YarnLockfile {
+    /// Creates a lockfile from the given bytes.
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        std::str::from_utf8(bytes)?.parse()
+    }
+}
+
+impl Display for YarnLockfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.0 {
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for YarnLockfile {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.lines().map(ToOwned::to_owned).collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use super::YarnLockfile;
+
+    #[test]
+    fn test_package_version() {
+        let content = indoc::indoc! {r#"
+            # This file is generated by running "yarn install" inside your project.
+            # Manual changes might be lost - proceed with caution!
+
+            __metadata:
+              version: 8
+              cacheKey: 10c0
+
+            "example-one@npm:^0.1.0, example-one@workspace:packages/example-one":
+              version: 0.0.0-use.local
+              resolution: "example-one@workspace:packages/example-one"
+              languageName: unknown
+              linkType: soft
+
+            "example-two@workspace:packages/example-two":
+              version: 0.0.0-use.local
+              resolution: "example-two@workspace:packages/example-two"
+              dependencies:
+                example-one: "npm:^0.1.0"
+              languageName: unknown
+              linkType: soft
+        "#};
+
+        let mut lockfile = content.parse::<YarnLockfile>().unwrap();
+
+        assert_eq!(lockfile.to_string(), content);
+
+        lockfile.set_package_version("example-one", Version::new(0, 2, 0));
+
+        assert_eq!(
+            lockfile.to_string(),
+            content.replace("npm:^0.1.0", "npm:^0.2.0")
+        );
+    }
+}
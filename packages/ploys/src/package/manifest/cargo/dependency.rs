@@ -5,6 +5,8 @@ use either::Either;
 use semver::Version;
 use toml_edit::{Entry, InlineTable, Item, KeyMut, Table, TableLike, Value, value};
 
+use super::is_inherited;
+
 /// A *Cargo* package dependency.
 pub struct Dependency {
     name: String,
@@ -68,6 +70,7 @@ pub struct DependencyRef<'a> {
     name: &'a str,
     version: Option<&'a str>,
     table: Option<&'a dyn TableLike>,
+    inherited: bool,
 }
 
 impl<'a> DependencyRef<'a> {
@@ -76,6 +79,14 @@ impl<'a> DependencyRef<'a> {
         self.name
     }
 
+    /// Checks if the dependency is inherited from the workspace.
+    ///
+    /// The version and path of an inherited dependency are those of the
+    /// workspace dependency when it has been resolved.
+    pub fn is_inherited(&self) -> bool {
+        self.inherited
+    }
+
     /// Gets the dependency version if it has been set.
     pub fn version(&self) -> Option<&'a str> {
         match self.version {
@@ -118,6 +129,7 @@ impl<'a> From<(&'a str, &'a Item)> for DependencyRef<'a> {
             name,
             version: item.as_str(),
             table: item.as_table_like(),
+            inherited: is_inherited(item),
         }
     }
 }
@@ -126,9 +138,16 @@ impl<'a> From<(&'a str, &'a Item)> for DependencyRef<'a> {
 #[derive(Clone, Default)]
 pub struct Dependencies<'a> {
     pub(super) table: Option<&'a dyn TableLike>,
+    pub(super) workspace: Option<&'a dyn TableLike>,
 }
 
 impl<'a> Dependencies<'a> {
+    /// Resolves inherited dependencies with the given workspace dependencies.
+    pub(crate) fn with_workspace(mut self, workspace: Dependencies<'a>) -> Self {
+        self.workspace = workspace.table;
+        self
+    }
+
     /// Gets the dependency with the given name.
     pub fn get(&self, name: impl AsRef<str>) -> Option<DependencyRef<'a>> {
         self.clone()
@@ -142,8 +161,22 @@ impl<'a> IntoIterator for Dependencies<'a> {
     type IntoIter = Box<dyn Iterator<Item = DependencyRef<'a>> + 'a>;
 
     fn into_iter(self) -> Self::IntoIter {
+        let workspace = self.workspace;
+
         match self.table {
-            Some(table) => Box::new(table.iter().map(Into::into)),
+            Some(table) => Box::new(table.iter().map(move |(name, item)| {
+                let resolved = workspace
+                    .filter(|_| is_inherited(item))
+                    .and_then(|workspace| workspace.get(name));
+
+                match resolved {
+                    Some(resolved) => DependencyRef {
+                        inherited: true,
+                        ..DependencyRef::from((name, resolved))
+                    },
+                    None => DependencyRef::from((name, item)),
+                }
+            })),
             None => Box::new(std::iter::empty()),
         }
     }
@@ -159,6 +192,7 @@ impl<'a> From<&'a Item> for Dependencies<'a> {
     fn from(item: &'a Item) -> Self {
         Self {
             table: item.as_table_like(),
+            workspace: None,
         }
     }
 }
@@ -175,6 +209,11 @@ impl DependencyMut<'_> {
         self.name.get()
     }
 
+    /// Checks if the dependency is inherited from the workspace.
+    pub fn is_inherited(&self) -> bool {
+        is_inherited(self.item)
+    }
+
     /// Gets the dependency version if it has been set.
     pub fn version(&self) -> Option<Version> {
         match self.item.as_str() {
@@ -187,7 +226,14 @@ impl DependencyMut<'_> {
     }
 
     /// Sets the dependency version.
+    ///
+    /// An inherited dependency is left unchanged as the version must be set on
+    /// the workspace dependency instead.
     pub fn set_version(&mut self, version: impl Into<Version>) {
+        if self.is_inherited() {
+            return;
+        }
+
         if let Some(table) = self.item.as_table_like_mut() {
             let item = table.entry("version").or_insert_with(Item::default);
 
@@ -343,6 +389,7 @@ impl Debug for DependenciesMut<'_> {
                 .debug_list()
                 .entries(Dependencies {
                     table: Some(&**table),
+                    workspace: None,
                 })
                 .finish(),
             Either::Right(_) => f.debug_list().finish(),
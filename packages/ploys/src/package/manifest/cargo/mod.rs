@@ -17,7 +17,10 @@ pub use self::dependency::{
     Dependencies, DependenciesMut, Dependency, DependencyMut, DependencyRef,
 };
 pub use self::package::{Package, PackageMut};
-pub use self::workspace::{Workspace, WorkspaceExclude, WorkspaceMembers, WorkspaceMut};
+pub use self::workspace::{
+    Workspace, WorkspaceExclude, WorkspaceMembers, WorkspaceMut, WorkspacePackage,
+    WorkspacePackageMut,
+};
 
 use super::Error;
 
@@ -106,6 +109,13 @@ impl CargoManifest {
         }
     }
 
+    /// Gets the workspace dependencies table.
+    pub fn workspace_dependencies(&self) -> Dependencies<'_> {
+        self.workspace()
+            .map(|workspace| workspace.dependencies())
+            .unwrap_or_default()
+    }
+
     /// Gets the workspace members.
     ///
     /// This follows the [members and exclude fields](https://doc.rust-lang.org/cargo/reference/workspaces.html#the-members-and-exclude-fields)
@@ -172,8 +182,9 @@ impl CargoManifest {
     pub fn dependencies(&self) -> Dependencies<'_> {
         self.0
             .get("dependencies")
-            .map(Into::into)
+            .map(Dependencies::from)
             .unwrap_or_default()
+            .with_workspace(self.workspace_dependencies())
     }
 
     /// Gets the mutable dependencies table.
@@ -209,8 +220,9 @@ impl CargoManifest {
     pub fn dev_dependencies(&self) -> Dependencies<'_> {
         self.0
             .get("dev-dependencies")
-            .map(Into::into)
+            .map(Dependencies::from)
             .unwrap_or_default()
+            .with_workspace(self.workspace_dependencies())
     }
 
     /// Gets the mutable dev dependencies table.
@@ -246,8 +258,9 @@ impl CargoManifest {
     pub fn build_dependencies(&self) -> Dependencies<'_> {
         self.0
             .get("build-dependencies")
-            .map(Into::into)
+            .map(Dependencies::from)
             .unwrap_or_default()
+            .with_workspace(self.workspace_dependencies())
     }
 
     /// Gets the mutable build dependencies table.
@@ -284,6 +297,14 @@ impl FromStr for CargoManifest {
     }
 }
 
+/// Checks if the item is inherited from the workspace.
+fn is_inherited(item: &Item) -> bool {
+    item.as_table_like()
+        .and_then(|table| table.get("workspace"))
+        .and_then(Item::as_bool)
+        == Some(true)
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -367,6 +388,80 @@ mod tests {
         assert_eq!(three.path(), Some("../example-three"));
     }
 
+    #[test]
+    fn test_workspace_inheritance() {
+        let root = indoc::indoc! {r#"
+            [workspace]
+            members = ["packages/*"]
+
+            [workspace.package]
+            version = "0.1.0"
+
+            [workspace.dependencies]
+            example-one = { version = "0.1.0", path = "packages/example-one" }
+        "#};
+
+        let member = indoc::indoc! {r#"
+            [package]
+            name = "example-two"
+            version.workspace = true
+
+            [dependencies]
+            example-one = { workspace = true }
+        "#};
+
+        let mut root = root.parse::<CargoManifest>().unwrap();
+        let mut member = member.parse::<CargoManifest>().unwrap();
+
+        assert!(member.package().unwrap().is_inherited("version"));
+        assert_eq!(
+            root.workspace().unwrap().package().unwrap().version(),
+            Some(Version::new(0, 1, 0))
+        );
+
+        let one = member
+            .dependencies()
+            .with_workspace(root.workspace_dependencies())
+            .get("example-one")
+            .unwrap();
+
+        assert!(one.is_inherited());
+        assert_eq!(one.version(), Some("0.1.0"));
+        assert_eq!(one.path(), Some("packages/example-one"));
+
+        let before = member.to_string();
+
+        member
+            .get_dependency_mut("example-one")
+            .unwrap()
+            .set_version(Version::new(0, 2, 0));
+
+        assert_eq!(member.to_string(), before);
+
+        root.workspace_mut()
+            .package_mut()
+            .unwrap()
+            .set_version(Version::new(0, 2, 0));
+        root.workspace_mut()
+            .dependencies_mut()
+            .unwrap()
+            .get_mut("example-one")
+            .unwrap()
+            .set_version(Version::new(0, 2, 0));
+
+        assert_eq!(
+            root.workspace().unwrap().package().unwrap().version(),
+            Some(Version::new(0, 2, 0))
+        );
+        assert_eq!(
+            root.workspace_dependencies()
+                .get("example-one")
+                .unwrap()
+                .version(),
+            Some("0.2.0")
+        );
+    }
+
     #[test]
     fn test_members() {
         let mut manifest = CargoManifest::new_workspace();
@@ -4,6 +4,8 @@ use url::Url;
 
 use crate::repository::RepoAddr;
 
+use super::is_inherited;
+
 /// The package table.
 pub struct Package<'a>(pub(super) &'a dyn TableLike);
 
@@ -21,7 +23,8 @@ impl<'a> Package<'a> {
     /// Gets the package version.
     ///
     /// This adheres to the [manifest format reference][1] and defaults to
-    /// `0.0.0` if the `version` field has not been set.
+    /// `0.0.0` if the `version` field has not been set. An inherited version
+    /// must be resolved from the workspace package table instead.
     ///
     /// [1]: https://doc.rust-lang.org/cargo/reference/manifest.html#the-version-field
     pub fn version(&self) -> Version {
@@ -38,6 +41,16 @@ impl<'a> Package<'a> {
         self.0.get("repository")?.as_str()?.parse().ok()
     }
 
+    /// Checks if the given field is inherited from the workspace.
+    ///
+    /// This adheres to the [workspace inheritance][1] reference where the field
+    /// is set to `{ workspace = true }`.
+    ///
+    /// [1]: https://doc.rust-lang.org/cargo/reference/workspaces.html#the-package-table
+    pub fn is_inherited(&self, field: &str) -> bool {
+        self.0.get(field).is_some_and(is_inherited)
+    }
+
     /// Gets the package authors.
     pub fn authors(&self) -> Option<impl IntoIterator<Item = &'a str> + use<'a>> {
         Some(
@@ -88,7 +101,15 @@ impl PackageMut<'_> {
             .expect("version should be valid semver")
     }
 
+    /// Checks if the given field is inherited from the workspace.
+    pub fn is_inherited(&self, field: &str) -> bool {
+        self.0.get(field).is_some_and(is_inherited)
+    }
+
     /// Sets the package version.
+    ///
+    /// This replaces an inherited version with a version specific to this
+    /// package.
     pub fn set_version(&mut self, version: impl Into<Version>) -> &mut Self {
         let item = self.0.entry("version").or_insert_with(Item::default);
 
@@ -2,7 +2,11 @@ use std::path::Path;
 
 use either::Either;
 use globset::Glob;
+use semver::Version;
 use toml_edit::{Array, Entry, Item, Table, TableLike, Value};
+use url::Url;
+
+use super::{Dependencies, DependenciesMut};
 
 /// The workspace table.
 pub struct Workspace<'a>(pub(super) &'a dyn TableLike);
@@ -23,6 +27,26 @@ impl<'a> Workspace<'a> {
             None => WorkspaceExclude(None),
         }
     }
+
+    /// Gets the workspace package table.
+    ///
+    /// This contains the package fields that members may inherit.
+    pub fn package(&self) -> Option<WorkspacePackage<'a>> {
+        self.0
+            .get("package")
+            .and_then(Item::as_table_like)
+            .map(WorkspacePackage)
+    }
+
+    /// Gets the workspace dependencies table.
+    ///
+    /// This contains the dependencies that members may inherit.
+    pub fn dependencies(&self) -> Dependencies<'a> {
+        self.0
+            .get("dependencies")
+            .map(Into::into)
+            .unwrap_or_default()
+    }
 }
 
 /// The mutable workspace table.
@@ -64,6 +88,21 @@ impl WorkspaceMut<'_> {
             members.push(path.as_ref().to_string_lossy().to_string());
         }
     }
+
+    /// Gets the mutable workspace package table.
+    pub fn package_mut(&mut self) -> Option<WorkspacePackageMut<'_>> {
+        self.table(false)?
+            .get_mut("package")?
+            .as_table_like_mut()
+            .map(WorkspacePackageMut)
+    }
+
+    /// Gets the mutable workspace dependencies table.
+    pub fn dependencies_mut(&mut self) -> Option<DependenciesMut<'_>> {
+        Some(DependenciesMut::new(
+            self.table(false)?.entry("dependencies"),
+        ))
+    }
 }
 
 impl WorkspaceMut<'_> {
@@ -107,6 +146,56 @@ impl WorkspaceMut<'_> {
     }
 }
 
+/// The workspace package table.
+pub struct WorkspacePackage<'a>(&'a dyn TableLike);
+
+impl<'a> WorkspacePackage<'a> {
+    /// Gets the workspace package description.
+    pub fn description(&self) -> Option<&'a str> {
+        self.0.get("description").and_then(Item::as_str)
+    }
+
+    /// Gets the workspace package version.
+    pub fn version(&self) -> Option<Version> {
+        self.0.get("version")?.as_str()?.parse().ok()
+    }
+
+    /// Gets the workspace package repository.
+    pub fn repository(&self) -> Option<Url> {
+        self.0.get("repository")?.as_str()?.parse().ok()
+    }
+
+    /// Gets the workspace package authors.
+    pub fn authors(&self) -> Option<impl IntoIterator<Item = &'a str> + use<'a>> {
+        Some(
+            self.0
+                .get("authors")?
+                .as_array()?
+                .iter()
+                .flat_map(Value::as_str),
+        )
+    }
+}
+
+/// The mutable workspace package table.
+pub struct WorkspacePackageMut<'a>(&'a mut dyn TableLike);
+
+impl WorkspacePackageMut<'_> {
+    /// Gets the workspace package version.
+    pub fn version(&self) -> Option<Version> {
+        self.0.get("version")?.as_str()?.parse().ok()
+    }
+
+    /// Sets the workspace package version.
+    pub fn set_version(&mut self, version: impl Into<Version>) -> &mut Self {
+        let item = self.0.entry("version").or_insert_with(Item::default);
+
+        *item = Item::Value(Value::from(version.into().to_string()));
+
+        self
+    }
+}
+
 /// The workspace members array.
 pub struct WorkspaceMembers<'a>(Option<&'a Array>);
 
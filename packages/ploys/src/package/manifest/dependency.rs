@@ -4,12 +4,30 @@ use super::cargo::{
     Dependencies as CargoDependencies, DependenciesMut as CargoDependenciesMut,
     DependencyMut as CargoDependencyMut, DependencyRef as CargoDependencyRef,
 };
+use super::go::{
+    Dependencies as GoDependencies, DependenciesMut as GoDependenciesMut,
+    DependencyMut as GoDependencyMut, DependencyRef as GoDependencyRef,
+};
+use super::npm::{
+    Dependencies as NpmDependencies, DependenciesMut as NpmDependenciesMut,
+    DependencyMut as NpmDependencyMut, DependencyRef as NpmDependencyRef,
+};
+use super::python::{
+    Dependencies as PythonDependencies, DependenciesMut as PythonDependenciesMut,
+    DependencyMut as PythonDependencyMut, DependencyRef as PythonDependencyRef,
+};
 
 /// The package dependency.
 #[derive(Clone, Debug)]
 pub enum DependencyRef<'a> {
     /// A cargo package dependency.
     Cargo(CargoDependencyRef<'a>),
+    /// An npm package dependency.
+    Npm(NpmDependencyRef<'a>),
+    /// A python package dependency.
+    Python(PythonDependencyRef<'a>),
+    /// A go module dependency.
+    Go(GoDependencyRef<'a>),
 }
 
 impl<'a> DependencyRef<'a> {
@@ -17,6 +35,9 @@ impl<'a> DependencyRef<'a> {
     pub fn name(&self) -> &'a str {
         match self {
             Self::Cargo(dependency) => dependency.name(),
+            Self::Npm(dependency) => dependency.name(),
+            Self::Python(dependency) => dependency.name(),
+            Self::Go(dependency) => dependency.name(),
         }
     }
 
@@ -24,6 +45,9 @@ impl<'a> DependencyRef<'a> {
     pub fn version(&self) -> Option<&'a str> {
         match self {
             Self::Cargo(dependency) => dependency.version(),
+            Self::Npm(dependency) => dependency.version(),
+            Self::Python(dependency) => dependency.version(),
+            Self::Go(dependency) => dependency.version(),
         }
     }
 
@@ -31,6 +55,9 @@ impl<'a> DependencyRef<'a> {
     pub fn path(&self) -> Option<&'a str> {
         match self {
             Self::Cargo(dependency) => dependency.path(),
+            Self::Npm(dependency) => dependency.path(),
+            Self::Python(dependency) => dependency.path(),
+            Self::Go(dependency) => dependency.path(),
         }
     }
 }
@@ -39,6 +66,9 @@ impl<'a> DependencyRef<'a> {
 #[derive(Clone, Debug)]
 pub enum Dependencies<'a> {
     Cargo(CargoDependencies<'a>),
+    Npm(NpmDependencies<'a>),
+    Python(PythonDependencies<'a>),
+    Go(GoDependencies<'a>),
 }
 
 impl<'a> Dependencies<'a> {
@@ -46,6 +76,9 @@ impl<'a> Dependencies<'a> {
     pub fn get(&self, name: impl AsRef<str>) -> Option<DependencyRef<'a>> {
         match self {
             Self::Cargo(dependencies) => dependencies.get(name).map(DependencyRef::Cargo),
+            Self::Npm(dependencies) => dependencies.get(name).map(DependencyRef::Npm),
+            Self::Python(dependencies) => dependencies.get(name).map(DependencyRef::Python),
+            Self::Go(dependencies) => dependencies.get(name).map(DependencyRef::Go),
         }
     }
 }
@@ -59,6 +92,11 @@ impl<'a> IntoIterator for Dependencies<'a> {
             Self::Cargo(dependencies) => {
                 Box::new(dependencies.into_iter().map(DependencyRef::Cargo))
             }
+            Self::Npm(dependencies) => Box::new(dependencies.into_iter().map(DependencyRef::Npm)),
+            Self::Python(dependencies) => {
+                Box::new(dependencies.into_iter().map(DependencyRef::Python))
+            }
+            Self::Go(dependencies) => Box::new(dependencies.into_iter().map(DependencyRef::Go)),
         }
     }
 }
@@ -68,6 +106,12 @@ impl<'a> IntoIterator for Dependencies<'a> {
 pub enum DependencyMut<'a> {
     /// A cargo package dependency.
     Cargo(CargoDependencyMut<'a>),
+    /// An npm package dependency.
+    Npm(NpmDependencyMut<'a>),
+    /// A python package dependency.
+    Python(PythonDependencyMut<'a>),
+    /// A go module dependency.
+    Go(GoDependencyMut<'a>),
 }
 
 impl DependencyMut<'_> {
@@ -75,6 +119,9 @@ impl DependencyMut<'_> {
     pub fn name(&self) -> &str {
         match self {
             Self::Cargo(dependency) => dependency.name(),
+            Self::Npm(dependency) => dependency.name(),
+            Self::Python(dependency) => dependency.name(),
+            Self::Go(dependency) => dependency.name(),
         }
     }
 
@@ -82,6 +129,9 @@ impl DependencyMut<'_> {
     pub fn version(&self) -> Option<Version> {
         match self {
             Self::Cargo(dependency) => dependency.version(),
+            Self::Npm(dependency) => dependency.version(),
+            Self::Python(dependency) => dependency.version(),
+            Self::Go(dependency) => dependency.version(),
         }
     }
 
@@ -89,6 +139,9 @@ impl DependencyMut<'_> {
     pub fn set_version(&mut self, version: impl Into<Version>) {
         match self {
             Self::Cargo(dependency) => dependency.set_version(version),
+            Self::Npm(dependency) => dependency.set_version(version),
+            Self::Python(dependency) => dependency.set_version(version),
+            Self::Go(dependency) => dependency.set_version(version),
         }
     }
 
@@ -96,6 +149,9 @@ impl DependencyMut<'_> {
     pub fn path(&self) -> Option<&str> {
         match self {
             Self::Cargo(dependency) => dependency.path(),
+            Self::Npm(dependency) => dependency.path(),
+            Self::Python(dependency) => dependency.path(),
+            Self::Go(dependency) => dependency.path(),
         }
     }
 }
@@ -104,6 +160,9 @@ impl DependencyMut<'_> {
 #[derive(Debug)]
 pub enum DependenciesMut<'a> {
     Cargo(CargoDependenciesMut<'a>),
+    Npm(NpmDependenciesMut<'a>),
+    Python(PythonDependenciesMut<'a>),
+    Go(GoDependenciesMut<'a>),
 }
 
 impl DependenciesMut<'_> {
@@ -111,6 +170,9 @@ impl DependenciesMut<'_> {
     pub fn get_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
         match self {
             Self::Cargo(dependencies) => dependencies.get_mut(name).map(DependencyMut::Cargo),
+            Self::Npm(dependencies) => dependencies.get_mut(name).map(DependencyMut::Npm),
+            Self::Python(dependencies) => dependencies.get_mut(name).map(DependencyMut::Python),
+            Self::Go(dependencies) => dependencies.get_mut(name).map(DependencyMut::Go),
         }
     }
 }
@@ -124,6 +186,11 @@ impl<'a> IntoIterator for DependenciesMut<'a> {
             Self::Cargo(dependencies) => {
                 Box::new(dependencies.into_iter().map(DependencyMut::Cargo))
             }
+            Self::Npm(dependencies) => Box::new(dependencies.into_iter().map(DependencyMut::Npm)),
+            Self::Python(dependencies) => {
+                Box::new(dependencies.into_iter().map(DependencyMut::Python))
+            }
+            Self::Go(dependencies) => Box::new(dependencies.into_iter().map(DependencyMut::Go)),
         }
     }
 }
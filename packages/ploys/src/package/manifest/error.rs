@@ -5,18 +5,24 @@ use std::fmt::{self, Display};
 pub enum Error {
     /// A glob error.
     Glob(globset::Error),
+    /// A JSON error.
+    Json(serde_json::Error),
     /// A TOML error.
     Toml(toml_edit::TomlError),
     /// A UTF-8 error.
     Utf8(std::str::Utf8Error),
+    /// An invalid version error.
+    Version(String),
 }
 
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Glob(err) => Display::fmt(err, f),
+            Self::Json(err) => Display::fmt(err, f),
             Self::Toml(err) => Display::fmt(err, f),
             Self::Utf8(err) => Display::fmt(err, f),
+            Self::Version(version) => write!(f, "Invalid version: {version}"),
         }
     }
 }
@@ -25,8 +31,10 @@ impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Self::Glob(err) => Some(err),
+            Self::Json(err) => Some(err),
             Self::Toml(err) => Some(err),
             Self::Utf8(err) => Some(err),
+            Self::Version(_) => None,
         }
     }
 }
@@ -37,6 +45,12 @@ impl From<globset::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
 impl From<toml_edit::TomlError> for Error {
     fn from(err: toml_edit::TomlError) -> Self {
         Self::Toml(err)
@@ -0,0 +1,257 @@
+use std::fmt::{self, Debug};
+
+use semver::Version;
+
+/// The go module dependency.
+#[derive(Clone)]
+pub struct DependencyRef<'a> {
+    name: &'a str,
+    version: &'a str,
+    path: Option<&'a str>,
+}
+
+impl<'a> DependencyRef<'a> {
+    /// Gets the dependency name.
+    ///
+    /// This is the module path of the dependency.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Gets the dependency version.
+    pub fn version(&self) -> Option<&'a str> {
+        Some(self.version)
+    }
+
+    /// Gets the dependency path if it has been replaced with a local module.
+    pub fn path(&self) -> Option<&'a str> {
+        self.path
+    }
+}
+
+impl Debug for DependencyRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DependencyRef")
+            .field("name", &self.name())
+            .field("version", &self.version())
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+/// The go module dependencies.
+///
+/// These are the `require` directives in the `go.mod` file.
+#[derive(Clone, Default)]
+pub struct Dependencies<'a> {
+    pub(super) lines: &'a [String],
+}
+
+impl<'a> Dependencies<'a> {
+    /// Gets the dependency with the given name.
+    pub fn get(&self, name: impl AsRef<str>) -> Option<DependencyRef<'a>> {
+        self.clone()
+            .into_iter()
+            .find(|dependency| dependency.name() == name.as_ref())
+    }
+}
+
+impl<'a> IntoIterator for Dependencies<'a> {
+    type Item = DependencyRef<'a>;
+    type IntoIter = Box<dyn Iterator<Item = DependencyRef<'a>> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let lines = self.lines;
+
+        Box::new(
+            directives(lines, "require")
+                .filter_map(|(line, skip)| {
+                    let mut tokens = line.split_whitespace().skip(skip);
+
+                    Some((tokens.next()?, tokens.next()?))
+                })
+                .map(move |(name, version)| DependencyRef {
+                    name,
+                    version,
+                    path: replace_path(lines, name),
+                }),
+        )
+    }
+}
+
+impl Debug for Dependencies<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// The mutable go module dependency.
+pub struct DependencyMut<'a> {
+    line: &'a mut String,
+    skip: usize,
+}
+
+impl DependencyMut<'_> {
+    /// Gets the dependency name.
+    pub fn name(&self) -> &str {
+        self.line
+            .split_whitespace()
+            .nth(self.skip)
+            .unwrap_or_default()
+    }
+
+    /// Gets the dependency version if it has been set.
+    pub fn version(&self) -> Option<Version> {
+        self.line
+            .split_whitespace()
+            .nth(self.skip + 1)?
+            .trim_start_matches('v')
+            .parse()
+            .ok()
+    }
+
+    /// Sets the dependency version.
+    pub fn set_version(&mut self, version: impl Into<Version>) {
+        let Some(current) = self.line.split_whitespace().nth(self.skip + 1) else {
+            return;
+        };
+
+        let start = current.as_ptr() as usize - self.line.as_ptr() as usize;
+        let range = start..start + current.len();
+
+        self.line
+            .replace_range(range, &format!("v{}", version.into()));
+    }
+
+    /// Gets the dependency path if it has been set.
+    ///
+    /// This is always `None` as local replacements are separate directives.
+    pub fn path(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl Debug for DependencyMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DependencyMut")
+            .field("name", &self.name())
+            .field("version", &self.version())
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+/// The mutable go module dependencies.
+#[derive(Default)]
+pub struct DependenciesMut<'a> {
+    pub(super) lines: Option<&'a mut Vec<String>>,
+}
+
+impl<'a> DependenciesMut<'a> {
+    /// Gets the mutable dependency with the given name.
+    pub fn get_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        DependenciesMut {
+            lines: Some(&mut **self.lines.as_mut()?),
+        }
+        .into_get_mut(name)
+    }
+
+    /// Gets the mutable dependency with the given name.
+    pub fn into_get_mut(self, name: impl AsRef<str>) -> Option<DependencyMut<'a>> {
+        self.into_iter()
+            .find(|dependency| dependency.name() == name.as_ref())
+    }
+}
+
+impl<'a> IntoIterator for DependenciesMut<'a> {
+    type Item = DependencyMut<'a>;
+    type IntoIter = Box<dyn Iterator<Item = DependencyMut<'a>> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let Some(lines) = self.lines else {
+            return Box::new(std::iter::empty());
+        };
+
+        let mut block = false;
+
+        Box::new(lines.iter_mut().filter_map(move |line| {
+            let skip = directive(line, "require", &mut block)?;
+            let versioned = line.split_whitespace().nth(skip + 1).is_some();
+
+            versioned.then_some(DependencyMut { line, skip })
+        }))
+    }
+}
+
+impl Debug for DependenciesMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = self.lines.as_deref().map(Vec::as_slice).unwrap_or_default();
+
+        f.debug_list().entries(Dependencies { lines }).finish()
+    }
+}
+
+/// Gets the lines belonging to the given directive.
+///
+/// This yields each line along with the number of leading tokens to skip,
+/// which is one for single line directives and zero inside a block.
+pub(super) fn directives<'a>(
+    lines: &'a [String],
+    name: &'static str,
+) -> impl Iterator<Item = (&'a str, usize)> + 'a {
+    let mut block = false;
+
+    lines
+        .iter()
+        .filter_map(move |line| directive(line, name, &mut block).map(|skip| (line.as_str(), skip)))
+}
+
+/// Checks whether the line belongs to the given directive.
+fn directive(line: &str, name: &str, block: &mut bool) -> Option<usize> {
+    let line = line.split("//").next().unwrap_or_default().trim();
+
+    if *block {
+        if line == ")" {
+            *block = false;
+
+            return None;
+        }
+
+        return (!line.is_empty()).then_some(0);
+    }
+
+    let rest = line.strip_prefix(name)?;
+
+    if !rest.starts_with(char::is_whitespace) && !rest.starts_with('(') {
+        return None;
+    }
+
+    match rest.trim() {
+        "(" => {
+            *block = true;
+
+            None
+        }
+        _ => Some(1),
+    }
+}
+
+/// Gets the modules that have been replaced with a local path.
+pub(super) fn replaces(lines: &[String]) -> impl Iterator<Item = (&str, &str)> {
+    directives(lines, "replace").filter_map(|(line, skip)| {
+        let line = line.split("//").next().unwrap_or_default();
+        let (from, to) = line.split_once("=>")?;
+        let from = from.split_whitespace().nth(skip)?;
+        let to = to.split_whitespace().next()?;
+
+        match to.starts_with("./") || to.starts_with("../") {
+            true => Some((from, to)),
+            false => None,
+        }
+    })
+}
+
+/// Gets the local path that replaces the given module.
+pub(super) fn replace_path<'a>(lines: &'a [String], module: &str) -> Option<&'a str> {
+    replaces(lines).find_map(|(from, to)| (from == module).then_some(to))
+}
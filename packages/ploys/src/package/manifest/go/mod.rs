@@ -0,0 +1,304 @@
+//! The `go.mod` package manifest for Go.
+//!
+//! Go modules do not declare a version in the manifest and are instead
+//! versioned with `v1.2.3` tags that are prefixed with the module directory.
+//! The version is therefore held alongside the manifest and is only persisted
+//! by creating a new tag.
+
+mod dependency;
+
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use globset::{Glob, GlobSetBuilder};
+use semver::Version;
+
+use crate::package::manifest::Members;
+
+pub use self::dependency::{Dependencies, DependenciesMut, DependencyMut, DependencyRef};
+
+use self::dependency::{directives, replace_path, replaces};
+use super::Error;
+
+/// The go module manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GoManifest {
+    lines: Vec<String>,
+    version: Option<Version>,
+}
+
+impl GoManifest {
+    /// Constructs a new go module manifest.
+    pub fn new_package(path: impl Into<String>) -> Self {
+        Self {
+            lines: vec![format!("module {}", path.into())],
+            version: None,
+        }
+    }
+
+    /// Gets the module path.
+    pub fn module_path(&self) -> Option<&str> {
+        directives(&self.lines, "module")
+            .find_map(|(line, skip)| line.split_whitespace().nth(skip))
+            .map(|path| path.trim_matches('"'))
+    }
+
+    /// Gets the package name.
+    ///
+    /// This is the last element of the module path excluding any major version
+    /// suffix.
+    pub fn name(&self) -> Option<&str> {
+        let path = self.module_path()?;
+        let path = match path.rsplit_once('/') {
+            Some((prefix, suffix)) if major_suffix(suffix).is_some() => prefix,
+            _ => path,
+        };
+
+        path.rsplit('/').next()
+    }
+
+    /// Gets the major version suffix of the module path.
+    ///
+    /// Modules from `v2` onwards must end in a `/vN` suffix that matches the
+    /// major version of the module as described in the [module path][1]
+    /// documentation.
+    ///
+    /// [1]: https://go.dev/ref/mod#major-version-suffixes
+    pub fn major_version_suffix(&self) -> Option<u64> {
+        major_suffix(self.module_path()?.rsplit('/').next()?)
+    }
+
+    /// Gets the package version.
+    ///
+    /// This defaults to `0.0.0` until the version has been loaded from tags.
+    pub fn version(&self) -> Version {
+        self.version.clone().unwrap_or(Version::new(0, 0, 0))
+    }
+
+    /// Sets the package version.
+    ///
+    /// This is not written to the manifest.
+    pub fn set_version(&mut self, version: impl Into<Version>) -> &mut Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Builds the manifest with the given version.
+    pub fn with_version(mut self, version: impl Into<Version>) -> Self {
+        self.set_version(version);
+        self
+    }
+
+    /// Gets the workspace members.
+    ///
+    /// Go modules do not declare workspace members so this includes modules
+    /// that have been replaced with a local path.
+    pub fn members(&self) -> Result<Members, Error> {
+        let mut includes = GlobSetBuilder::new();
+
+        for (_, path) in replaces(&self.lines) {
+            includes.add(Glob::new(
+                path.trim_start_matches("./").trim_end_matches('/'),
+            )?);
+        }
+
+        Ok(Members::new(includes.build()?, Vec::<PathBuf>::new()))
+    }
+
+    /// Creates a manifest from the given bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        std::str::from_utf8(bytes)?.parse()
+    }
+}
+
+impl GoManifest {
+    /// Gets the dependency with the given name.
+    pub fn get_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
+        self.dependencies().get(name)
+    }
+
+    /// Gets the mutable dependency with the given name.
+    pub fn get_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        self.dependencies_mut().into_get_mut(name)
+    }
+
+    /// Gets the dependencies.
+    pub fn dependencies(&self) -> Dependencies<'_> {
+        Dependencies { lines: &self.lines }
+    }
+
+    /// Gets the mutable dependencies.
+    pub fn dependencies_mut(&mut self) -> DependenciesMut<'_> {
+        DependenciesMut {
+            lines: Some(&mut self.lines),
+        }
+    }
+
+    /// Gets the local path that replaces the given module.
+    pub fn get_replace_path(&self, module: impl AsRef<str>) -> Option<&str> {
+        replace_path(&self.lines, module.as_ref())
+    }
+
+    /// Replaces the given module with a local path.
+    pub fn add_replace(&mut self, module: impl AsRef<str>, path: impl AsRef<str>) -> &mut Self {
+        let module = module.as_ref();
+        let path = path.as_ref().trim_end_matches('/');
+
+        if self.get_replace_path(module).is_some() {
+            return self;
+        }
+
+        let path = match path.starts_with("./") || path.starts_with("../") {
+            true => path.to_owned(),
+            false => format!("./{path}"),
+        };
+
+        if self
+            .lines
+            .last()
+            .is_some_and(|line| !line.trim().is_empty())
+        {
+            self.lines.push(String::new());
+        }
+
+        self.lines.push(format!("replace {module} => {path}"));
+        self
+    }
+
+    /// Builds the manifest with the given module replaced with a local path.
+    pub fn with_replace(mut self, module: impl AsRef<str>, path: impl AsRef<str>) -> Self {
+        self.add_replace(module, path);
+        self
+    }
+}
+
+impl Display for GoManifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for GoManifest {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            lines: s.lines().map(ToOwned::to_owned).collect(),
+            version: None,
+        })
+    }
+}
+
+/// Parses the major version from a `vN` path element.
+fn major_suffix(element: &str) -> Option<u64> {
+    let major = element.strip_prefix('v')?.parse().ok()?;
+
+    (major >= 2).then_some(major)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use semver::Version;
+
+    use super::GoManifest;
+
+    #[test]
+    fn test_package() {
+        let mut manifest = GoManifest::new_package("github.com/ploys/example");
+
+        assert_eq!(manifest.module_path(), Some("github.com/ploys/example"));
+        assert_eq!(manifest.name(), Some("example"));
+        assert_eq!(manifest.major_version_suffix(), None);
+        assert_eq!(manifest.version(), Version::new(0, 0, 0));
+
+        manifest.set_version(Version::new(0, 1, 0));
+
+        assert_eq!(manifest.version(), Version::new(0, 1, 0));
+        assert_eq!(manifest.to_string(), "module github.com/ploys/example\n");
+
+        let manifest = GoManifest::new_package("github.com/ploys/example/v2")
+            .with_replace("github.com/ploys/example-one", "packages/example-one");
+
+        assert_eq!(manifest.name(), Some("example"));
+        assert_eq!(manifest.major_version_suffix(), Some(2));
+        assert_eq!(
+            manifest.get_replace_path("github.com/ploys/example-one"),
+            Some("./packages/example-one")
+        );
+        assert_eq!(
+            manifest.to_string(),
+            indoc::indoc! {"
+                module github.com/ploys/example/v2
+
+                replace github.com/ploys/example-one => ./packages/example-one
+            "}
+        );
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let content = indoc::indoc! {r#"
+            module github.com/ploys/example
+
+            go 1.22
+
+            require github.com/ploys/example-one v0.1.0
+
+            require (
+                github.com/ploys/example-two v0.2.0 // indirect
+                github.com/ploys/example-three v1.0.0
+            )
+
+            replace github.com/ploys/example-two => ./example-two
+        "#};
+
+        let mut manifest = content.parse::<GoManifest>().unwrap();
+
+        assert_eq!(manifest.to_string(), content);
+        assert_eq!(manifest.dependencies().into_iter().count(), 3);
+
+        let one = manifest
+            .get_dependency("github.com/ploys/example-one")
+            .unwrap();
+
+        assert_eq!(one.version(), Some("v0.1.0"));
+        assert_eq!(one.path(), None);
+
+        let two = manifest
+            .get_dependency("github.com/ploys/example-two")
+            .unwrap();
+
+        assert_eq!(two.version(), Some("v0.2.0"));
+        assert_eq!(two.path(), Some("./example-two"));
+
+        let members = manifest.members().unwrap();
+
+        assert!(members.includes(Path::new("example-two")));
+        assert!(!members.includes(Path::new("example-three")));
+
+        manifest
+            .get_dependency_mut("github.com/ploys/example-two")
+            .unwrap()
+            .set_version(Version::new(0, 3, 0));
+
+        assert_eq!(
+            manifest
+                .get_dependency_mut("github.com/ploys/example-two")
+                .unwrap()
+                .version(),
+            Some(Version::new(0, 3, 0))
+        );
+        assert!(
+            manifest
+                .to_string()
+                .contains("    github.com/ploys/example-two v0.3.0 // indirect\n")
+        );
+    }
+}
@@ -6,7 +6,10 @@
 pub mod cargo;
 mod dependency;
 mod error;
+pub mod go;
 mod members;
+pub mod npm;
+pub mod python;
 
 use std::fmt::{self, Display};
 
@@ -15,15 +18,26 @@ use strum::{EnumIs, EnumTryAs};
 pub use self::cargo::CargoManifest;
 pub use self::dependency::{Dependencies, DependenciesMut, DependencyMut, DependencyRef};
 pub use self::error::Error;
+pub use self::go::GoManifest;
 pub use self::members::Members;
+pub use self::npm::NpmManifest;
+pub use self::python::PythonManifest;
 
 use super::PackageKind;
 
+use self::npm::DependenciesMut as NpmDependenciesMut;
+
 /// The package manifest.
 #[derive(Clone, Debug, PartialEq, Eq, EnumIs, EnumTryAs)]
 pub enum Manifest {
     /// A cargo package manifest.
     Cargo(CargoManifest),
+    /// An npm package manifest.
+    Npm(NpmManifest),
+    /// A python package manifest.
+    Python(PythonManifest),
+    /// A go module manifest.
+    Go(GoManifest),
 }
 
 impl Manifest {
@@ -32,10 +46,30 @@ impl Manifest {
         Self::Cargo(CargoManifest::new_package(name))
     }
 
+    /// Constructs a new npm manifest.
+    pub fn new_npm(name: impl Into<String>) -> Self {
+        Self::Npm(NpmManifest::new_package(name))
+    }
+
+    /// Constructs a new python manifest.
+    pub fn new_python(name: impl Into<String>) -> Self {
+        Self::Python(PythonManifest::new_package(name))
+    }
+
+    /// Constructs a new go manifest.
+    ///
+    /// The go module is identified by its full module path.
+    pub fn new_go(path: impl Into<String>) -> Self {
+        Self::Go(GoManifest::new_package(path))
+    }
+
     /// Gets the package kind.
     pub fn package_kind(&self) -> PackageKind {
         match self {
             Self::Cargo(_) => PackageKind::Cargo,
+            Self::Npm(_) => PackageKind::Npm,
+            Self::Python(_) => PackageKind::Python,
+            Self::Go(_) => PackageKind::Go,
         }
     }
 
@@ -43,6 +77,9 @@ impl Manifest {
     pub fn members(&self) -> Result<Members, Error> {
         match self {
             Self::Cargo(cargo) => Ok(cargo.members()?),
+            Self::Npm(npm) => Ok(npm.members()?),
+            Self::Python(python) => Ok(python.members()?),
+            Self::Go(go) => Ok(go.members()?),
         }
     }
 
@@ -50,6 +87,9 @@ impl Manifest {
     pub fn from_bytes(kind: PackageKind, bytes: &[u8]) -> Result<Self, Error> {
         match kind {
             PackageKind::Cargo => Ok(Self::Cargo(CargoManifest::from_bytes(bytes)?)),
+            PackageKind::Npm => Ok(Self::Npm(NpmManifest::from_bytes(bytes)?)),
+            PackageKind::Python => Ok(Self::Python(PythonManifest::from_bytes(bytes)?)),
+            PackageKind::Go => Ok(Self::Go(GoManifest::from_bytes(bytes)?)),
         }
     }
 }
@@ -59,6 +99,9 @@ impl Manifest {
     pub fn get_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
         match self {
             Self::Cargo(cargo) => cargo.get_dependency(name).map(DependencyRef::Cargo),
+            Self::Npm(npm) => npm.get_dependency(name).map(DependencyRef::Npm),
+            Self::Python(python) => python.get_dependency(name).map(DependencyRef::Python),
+            Self::Go(go) => go.get_dependency(name).map(DependencyRef::Go),
         }
     }
 
@@ -66,6 +109,9 @@ impl Manifest {
     pub fn get_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
         match self {
             Self::Cargo(cargo) => cargo.get_dependency_mut(name).map(DependencyMut::Cargo),
+            Self::Npm(npm) => npm.get_dependency_mut(name).map(DependencyMut::Npm),
+            Self::Python(python) => python.get_dependency_mut(name).map(DependencyMut::Python),
+            Self::Go(go) => go.get_dependency_mut(name).map(DependencyMut::Go),
         }
     }
 
@@ -73,6 +119,9 @@ impl Manifest {
     pub fn dependencies(&self) -> Dependencies<'_> {
         match self {
             Self::Cargo(cargo) => Dependencies::Cargo(cargo.dependencies()),
+            Self::Npm(npm) => Dependencies::Npm(npm.dependencies()),
+            Self::Python(python) => Dependencies::Python(python.dependencies()),
+            Self::Go(go) => Dependencies::Go(go.dependencies()),
         }
     }
 
@@ -80,6 +129,9 @@ impl Manifest {
     pub fn dependencies_mut(&mut self) -> DependenciesMut<'_> {
         match self {
             Self::Cargo(cargo) => DependenciesMut::Cargo(cargo.dependencies_mut()),
+            Self::Npm(npm) => DependenciesMut::Npm(npm.dependencies_mut()),
+            Self::Python(python) => DependenciesMut::Python(python.dependencies_mut()),
+            Self::Go(go) => DependenciesMut::Go(go.dependencies_mut()),
         }
     }
 }
@@ -89,6 +141,9 @@ impl Manifest {
     pub fn get_dev_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
         match self {
             Self::Cargo(cargo) => cargo.get_dev_dependency(name).map(DependencyRef::Cargo),
+            Self::Npm(npm) => npm.get_dev_dependency(name).map(DependencyRef::Npm),
+            Self::Python(python) => python.get_dev_dependency(name).map(DependencyRef::Python),
+            Self::Go(_) => None,
         }
     }
 
@@ -96,6 +151,11 @@ impl Manifest {
     pub fn get_dev_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
         match self {
             Self::Cargo(cargo) => cargo.get_dev_dependency_mut(name).map(DependencyMut::Cargo),
+            Self::Npm(npm) => npm.get_dev_dependency_mut(name).map(DependencyMut::Npm),
+            Self::Python(python) => python
+                .get_dev_dependency_mut(name)
+                .map(DependencyMut::Python),
+            Self::Go(_) => None,
         }
     }
 
@@ -103,6 +163,9 @@ impl Manifest {
     pub fn dev_dependencies(&self) -> Dependencies<'_> {
         match self {
             Self::Cargo(cargo) => Dependencies::Cargo(cargo.dev_dependencies()),
+            Self::Npm(npm) => Dependencies::Npm(npm.dev_dependencies()),
+            Self::Python(python) => Dependencies::Python(python.dev_dependencies()),
+            Self::Go(_) => Dependencies::Go(Default::default()),
         }
     }
 
@@ -110,6 +173,9 @@ impl Manifest {
     pub fn dev_dependencies_mut(&mut self) -> DependenciesMut<'_> {
         match self {
             Self::Cargo(cargo) => DependenciesMut::Cargo(cargo.dev_dependencies_mut()),
+            Self::Npm(npm) => DependenciesMut::Npm(npm.dev_dependencies_mut()),
+            Self::Python(python) => DependenciesMut::Python(python.dev_dependencies_mut()),
+            Self::Go(_) => DependenciesMut::Go(Default::default()),
         }
     }
 }
@@ -119,6 +185,9 @@ impl Manifest {
     pub fn get_build_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
         match self {
             Self::Cargo(cargo) => cargo.get_build_dependency(name).map(DependencyRef::Cargo),
+            Self::Npm(_) => None,
+            Self::Python(python) => python.get_build_dependency(name).map(DependencyRef::Python),
+            Self::Go(_) => None,
         }
     }
 
@@ -128,6 +197,11 @@ impl Manifest {
             Self::Cargo(cargo) => cargo
                 .get_build_dependency_mut(name)
                 .map(DependencyMut::Cargo),
+            Self::Npm(_) => None,
+            Self::Python(python) => python
+                .get_build_dependency_mut(name)
+                .map(DependencyMut::Python),
+            Self::Go(_) => None,
         }
     }
 
@@ -135,6 +209,9 @@ impl Manifest {
     pub fn build_dependencies(&self) -> Dependencies<'_> {
         match self {
             Self::Cargo(cargo) => Dependencies::Cargo(cargo.build_dependencies()),
+            Self::Npm(_) => Dependencies::Npm(Default::default()),
+            Self::Python(python) => Dependencies::Python(python.build_dependencies()),
+            Self::Go(_) => Dependencies::Go(Default::default()),
         }
     }
 
@@ -142,6 +219,9 @@ impl Manifest {
     pub fn build_dependencies_mut(&mut self) -> DependenciesMut<'_> {
         match self {
             Self::Cargo(cargo) => DependenciesMut::Cargo(cargo.build_dependencies_mut()),
+            Self::Npm(_) => DependenciesMut::Npm(NpmDependenciesMut::empty()),
+            Self::Python(python) => DependenciesMut::Python(python.build_dependencies_mut()),
+            Self::Go(_) => DependenciesMut::Go(Default::default()),
         }
     }
 }
@@ -150,6 +230,9 @@ impl Display for Manifest {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Cargo(cargo) => Display::fmt(cargo, f),
+            Self::Npm(npm) => Display::fmt(npm, f),
+            Self::Python(python) => Display::fmt(python, f),
+            Self::Go(go) => Display::fmt(go, f),
         }
     }
 }
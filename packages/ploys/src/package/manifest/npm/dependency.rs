@@ -0,0 +1,323 @@
+use std::fmt::{self, Debug};
+use std::path::{Path, PathBuf};
+
+use semver::Version;
+use serde_json::{Map, Value};
+
+/// An *npm* package dependency.
+pub struct Dependency {
+    name: String,
+    version: Option<Version>,
+    path: Option<PathBuf>,
+}
+
+impl Dependency {
+    /// Creates a new dependency.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: None,
+            path: None,
+        }
+    }
+
+    /// Gets the dependency name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the dependency version.
+    pub fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+
+    /// Sets the dependency version.
+    pub fn set_version(&mut self, version: impl Into<Version>) -> &mut Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Builds the dependency with the given version.
+    pub fn with_version(mut self, version: impl Into<Version>) -> Self {
+        self.set_version(version);
+        self
+    }
+
+    /// Gets the dependency path.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Sets the dependency path.
+    pub fn set_path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Builds the dependency with the given path.
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.set_path(path);
+        self
+    }
+}
+
+/// The npm package dependency.
+#[derive(Clone)]
+pub struct DependencyRef<'a> {
+    name: &'a str,
+    value: &'a Value,
+}
+
+impl<'a> DependencyRef<'a> {
+    /// Gets the dependency name.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Gets the dependency version requirement if it has been set.
+    pub fn version(&self) -> Option<&'a str> {
+        self.value.as_str()
+    }
+
+    /// Gets the dependency path if it has been set.
+    ///
+    /// This is the location of a `file:` or `link:` dependency.
+    pub fn path(&self) -> Option<&'a str> {
+        self.version().and_then(local_path)
+    }
+}
+
+impl Debug for DependencyRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DependencyRef")
+            .field("name", &self.name())
+            .field("version", &self.version())
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+impl<'a> From<(&'a String, &'a Value)> for DependencyRef<'a> {
+    fn from((name, value): (&'a String, &'a Value)) -> Self {
+        Self { name, value }
+    }
+}
+
+/// The npm package dependencies.
+#[derive(Clone, Default)]
+pub struct Dependencies<'a> {
+    pub(super) map: Option<&'a Map<String, Value>>,
+}
+
+impl<'a> Dependencies<'a> {
+    /// Gets the dependency with the given name.
+    pub fn get(&self, name: impl AsRef<str>) -> Option<DependencyRef<'a>> {
+        self.map?.get_key_value(name.as_ref()).map(Into::into)
+    }
+}
+
+impl<'a> IntoIterator for Dependencies<'a> {
+    type Item = DependencyRef<'a>;
+    type IntoIter = Box<dyn Iterator<Item = DependencyRef<'a>> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self.map {
+            Some(map) => Box::new(map.iter().map(Into::into)),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+impl Debug for Dependencies<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a> From<&'a Value> for Dependencies<'a> {
+    fn from(value: &'a Value) -> Self {
+        Self {
+            map: value.as_object(),
+        }
+    }
+}
+
+/// The mutable npm package dependency.
+pub struct DependencyMut<'a> {
+    name: &'a str,
+    value: &'a mut Value,
+}
+
+impl DependencyMut<'_> {
+    /// Gets the dependency name.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// Gets the dependency version if it has been set.
+    ///
+    /// This strips any leading prefix such as `^` or `~` from the version
+    /// requirement.
+    pub fn version(&self) -> Option<Version> {
+        let (_, version) = split_operator(self.value.as_str()?);
+
+        version.parse().ok()
+    }
+
+    /// Sets the dependency version.
+    ///
+    /// This preserves any existing prefix such as `^`, `~` or `workspace:^` and
+    /// leaves requirements without a single version such as `*`, `workspace:*`
+    /// or `file:` paths unchanged.
+    pub fn set_version(&mut self, version: impl Into<Version>) {
+        let version = version.into();
+
+        match self.value.as_str() {
+            Some(requirement) => {
+                let (operator, current) = split_operator(requirement);
+
+                if current.parse::<Version>().is_ok() {
+                    *self.value = Value::from(format!("{operator}{version}"));
+                }
+            }
+            None => *self.value = Value::from(version.to_string()),
+        }
+    }
+
+    /// Gets the dependency path if it has been set.
+    pub fn path(&self) -> Option<&str> {
+        self.value.as_str().and_then(local_path)
+    }
+}
+
+impl Debug for DependencyMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DependencyMut")
+            .field("name", &self.name())
+            .field("version", &self.version())
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+impl<'a> From<(&'a String, &'a mut Value)> for DependencyMut<'a> {
+    fn from((name, value): (&'a String, &'a mut Value)) -> Self {
+        Self { name, value }
+    }
+}
+
+/// The mutable npm package dependencies.
+pub struct DependenciesMut<'a> {
+    manifest: Option<&'a mut Map<String, Value>>,
+    key: &'static str,
+}
+
+impl<'a> DependenciesMut<'a> {
+    pub(super) fn new(manifest: &'a mut Map<String, Value>, key: &'static str) -> Self {
+        Self {
+            manifest: Some(manifest),
+            key,
+        }
+    }
+
+    /// Constructs an empty set of dependencies that cannot be modified.
+    pub(crate) fn empty() -> Self {
+        Self {
+            manifest: None,
+            key: "",
+        }
+    }
+}
+
+impl<'a> DependenciesMut<'a> {
+    /// Gets the mutable dependency with the given name.
+    pub fn get_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        self.manifest
+            .as_mut()?
+            .get_mut(self.key)?
+            .as_object_mut()?
+            .iter_mut()
+            .map(Into::<DependencyMut>::into)
+            .find(|dependency| dependency.name() == name.as_ref())
+    }
+
+    /// Gets the mutable dependency with the given name.
+    pub fn into_get_mut(self, name: impl AsRef<str>) -> Option<DependencyMut<'a>> {
+        self.into_iter()
+            .find(|dependency| dependency.name() == name.as_ref())
+    }
+
+    /// Inserts a dependency.
+    ///
+    /// A dependency with a path is inserted with the `file:` protocol and
+    /// otherwise uses a caret requirement on the given version.
+    pub fn insert(&mut self, dependency: impl Into<Dependency>) {
+        let dependency = dependency.into();
+        let Some(manifest) = self.manifest.as_mut() else {
+            return;
+        };
+
+        let requirement = match (dependency.path(), dependency.version()) {
+            (Some(path), _) => format!("file:{}", path.display()),
+            (None, Some(version)) => format!("^{version}"),
+            (None, None) => return,
+        };
+
+        let dependencies = manifest
+            .entry(self.key)
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if !dependencies.is_object() {
+            *dependencies = Value::Object(Map::new());
+        }
+
+        dependencies
+            .as_object_mut()
+            .expect("object")
+            .insert(dependency.name().to_owned(), Value::from(requirement));
+    }
+}
+
+impl<'a> IntoIterator for DependenciesMut<'a> {
+    type Item = DependencyMut<'a>;
+    type IntoIter = Box<dyn Iterator<Item = DependencyMut<'a>> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self
+            .manifest
+            .and_then(|manifest| manifest.get_mut(self.key))
+            .and_then(Value::as_object_mut)
+        {
+            Some(map) => Box::new(map.iter_mut().map(Into::into)),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+impl Debug for DependenciesMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let map = self
+            .manifest
+            .as_deref()
+            .and_then(|manifest| manifest.get(self.key))
+            .and_then(Value::as_object);
+
+        f.debug_list().entries(Dependencies { map }).finish()
+    }
+}
+
+/// Splits the prefix from the version requirement.
+fn split_operator(requirement: &str) -> (&str, &str) {
+    let index = requirement
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(requirement.len());
+
+    requirement.split_at(index)
+}
+
+/// Gets the path from a `file:` or `link:` version requirement.
+fn local_path(requirement: &str) -> Option<&str> {
+    requirement
+        .strip_prefix("file:")
+        .or_else(|| requirement.strip_prefix("link:"))
+}
@@ -0,0 +1,590 @@
+//! The `package.json` package manifest for JavaScript.
+
+mod dependency;
+
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use globset::{Glob, GlobSetBuilder};
+use semver::Version;
+use serde_json::{Map, Value};
+use url::Url;
+
+use crate::package::manifest::Members;
+use crate::repository::RepoAddr;
+
+pub use self::dependency::{
+    Dependencies, DependenciesMut, Dependency, DependencyMut, DependencyRef,
+};
+
+use super::Error;
+
+/// The npm package manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NpmManifest(Map<String, Value>);
+
+impl NpmManifest {
+    /// Constructs a new npm package manifest.
+    pub fn new_package(name: impl Into<String>) -> Self {
+        Self({
+            let mut map = Map::new();
+
+            map.insert("name".into(), Value::from(name.into()));
+            map.insert("version".into(), Value::from("0.0.0"));
+            map
+        })
+    }
+
+    /// Constructs a new npm workspace manifest.
+    pub fn new_workspace() -> Self {
+        Self({
+            let mut map = Map::new();
+
+            map.insert("private".into(), Value::from(true));
+            map.insert("workspaces".into(), Value::Array(Vec::new()));
+            map
+        })
+    }
+
+    /// Gets the package name.
+    pub fn name(&self) -> Option<&str> {
+        self.0.get("name").and_then(Value::as_str)
+    }
+
+    /// Gets the package description.
+    pub fn description(&self) -> Option<&str> {
+        self.0.get("description").and_then(Value::as_str)
+    }
+
+    /// Sets the package description.
+    pub fn set_description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.0
+            .insert("description".into(), Value::from(description.into()));
+        self
+    }
+
+    /// Gets the package version.
+    ///
+    /// This defaults to `0.0.0` if the `version` field has not been set as it
+    /// is only required for packages that are published to the registry.
+    pub fn version(&self) -> Version {
+        self.0
+            .get("version")
+            .and_then(Value::as_str)
+            .unwrap_or("0.0.0")
+            .parse()
+            .expect("version should be valid semver")
+    }
+
+    /// Sets the package version.
+    pub fn set_version(&mut self, version: impl Into<Version>) -> &mut Self {
+        self.0
+            .insert("version".into(), Value::from(version.into().to_string()));
+        self
+    }
+
+    /// Gets the package repository.
+    ///
+    /// This supports both the string and object forms of the [repository][1]
+    /// field.
+    ///
+    /// [1]: https://docs.npmjs.com/cli/configuring-npm/package-json#repository
+    pub fn repository(&self) -> Option<Url> {
+        let repository = self.0.get("repository")?;
+        let url = match repository.as_str() {
+            Some(url) => url,
+            None => repository.get("url")?.as_str()?,
+        };
+
+        url.trim_start_matches("git+")
+            .trim_end_matches(".git")
+            .parse()
+            .ok()
+    }
+
+    /// Sets the package repository.
+    pub fn set_repository(&mut self, repository: impl Into<RepoAddr>) -> &mut Self {
+        self.0.insert(
+            "repository".into(),
+            Value::from(repository.into().to_string()),
+        );
+        self
+    }
+
+    /// Gets the package authors.
+    ///
+    /// This includes the `author` followed by any `contributors` in either the
+    /// string or object form.
+    pub fn authors(&self) -> Option<impl IntoIterator<Item = &str>> {
+        let author = self.0.get("author");
+        let contributors = self.0.get("contributors").and_then(Value::as_array);
+
+        if author.is_none() && contributors.is_none() {
+            return None;
+        }
+
+        Some(
+            author
+                .into_iter()
+                .chain(contributors.into_iter().flatten())
+                .filter_map(|person| match person.as_str() {
+                    Some(person) => Some(person),
+                    None => person.get("name")?.as_str(),
+                }),
+        )
+    }
+
+    /// Adds a package author.
+    ///
+    /// This sets the `author` field if it is empty and otherwise adds the
+    /// author to the `contributors` field.
+    pub fn add_author(&mut self, author: impl Into<String>) -> &mut Self {
+        if !self.0.contains_key("author") {
+            self.0.insert("author".into(), Value::from(author.into()));
+
+            return self;
+        }
+
+        let contributors = self
+            .0
+            .entry("contributors")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        match contributors.as_array_mut() {
+            Some(contributors) => contributors.push(Value::from(author.into())),
+            None => *contributors = Value::Array(vec![Value::from(author.into())]),
+        }
+
+        self
+    }
+
+    /// Gets the workspaces.
+    ///
+    /// This supports both the array form and the object form with a `packages`
+    /// field.
+    pub fn workspaces(&self) -> impl Iterator<Item = &str> {
+        let workspaces = self.0.get("workspaces");
+        let workspaces = match workspaces.and_then(|value| value.get("packages")) {
+            Some(packages) => Some(packages),
+            None => workspaces,
+        };
+
+        workspaces
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+    }
+
+    /// Adds a new workspace.
+    pub fn add_workspace(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        let workspaces = self
+            .0
+            .entry("workspaces")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        let workspaces = match workspaces {
+            Value::Object(object) => object
+                .entry("packages")
+                .or_insert_with(|| Value::Array(Vec::new())),
+            workspaces => workspaces,
+        };
+
+        if !workspaces.is_array() {
+            *workspaces = Value::Array(Vec::new());
+        }
+
+        let workspaces = workspaces.as_array_mut().expect("array");
+
+        for workspace in workspaces.iter().filter_map(Value::as_str) {
+            if path.as_ref() == Path::new(workspace) {
+                return self;
+            }
+
+            if let Ok(glob) = Glob::new(workspace.trim_start_matches("./"))
+                && glob.compile_matcher().is_match(path.as_ref())
+            {
+                return self;
+            }
+        }
+
+        workspaces.push(Value::from(path.as_ref().to_string_lossy()));
+
+        self
+    }
+
+    /// Builds the workspace with the given workspace.
+    pub fn with_workspace(mut self, path: impl AsRef<Path>) -> Self {
+        self.add_workspace(path);
+        self
+    }
+
+    /// Gets the workspace members.
+    ///
+    /// This follows the [workspaces][1] documentation to build a list of
+    /// included members. Patterns starting with `!` are treated as excluded
+    /// members.
+    ///
+    /// [1]: https://docs.npmjs.com/cli/using-npm/workspaces
+    pub fn members(&self) -> Result<Members, Error> {
+        let mut includes = GlobSetBuilder::new();
+        let mut excludes = Vec::new();
+
+        for workspace in self.workspaces() {
+            match workspace.strip_prefix('!') {
+                Some(path) => excludes.push(PathBuf::from(path.trim_start_matches("./"))),
+                None => {
+                    includes.add(Glob::new(workspace.trim_start_matches("./"))?);
+                }
+            }
+        }
+
+        let dependencies = self
+            .dependencies()
+            .into_iter()
+            .chain(self.dev_dependencies())
+            .chain(self.peer_dependencies());
+
+        for dependency in dependencies {
+            if let Some(path) = dependency.path() {
+                includes.add(Glob::new(path.trim_start_matches("./"))?);
+            }
+        }
+
+        Ok(Members::new(includes.build()?, excludes))
+    }
+
+    /// Creates a manifest from the given bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        std::str::from_utf8(bytes)?.parse()
+    }
+}
+
+impl NpmManifest {
+    /// Adds a new dependency to the manifest.
+    pub fn add_dependency(&mut self, dependency: impl Into<Dependency>) -> &mut Self {
+        self.dependencies_mut().insert(dependency);
+        self
+    }
+
+    /// Builds the manifest with the given dependency.
+    pub fn with_dependency(mut self, dependency: impl Into<Dependency>) -> Self {
+        self.add_dependency(dependency);
+        self
+    }
+
+    /// Gets the dependency with the given name.
+    pub fn get_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
+        self.dependencies().get(name)
+    }
+
+    /// Gets the mutable dependency with the given name.
+    pub fn get_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        self.dependencies_mut().into_get_mut(name)
+    }
+
+    /// Gets the dependencies.
+    pub fn dependencies(&self) -> Dependencies<'_> {
+        self.0
+            .get("dependencies")
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Gets the mutable dependencies.
+    pub fn dependencies_mut(&mut self) -> DependenciesMut<'_> {
+        DependenciesMut::new(&mut self.0, "dependencies")
+    }
+}
+
+impl NpmManifest {
+    /// Adds a new dev dependency to the manifest.
+    pub fn add_dev_dependency(&mut self, dependency: impl Into<Dependency>) -> &mut Self {
+        self.dev_dependencies_mut().insert(dependency);
+        self
+    }
+
+    /// Builds the manifest with the given dev dependency.
+    pub fn with_dev_dependency(mut self, dependency: impl Into<Dependency>) -> Self {
+        self.add_dev_dependency(dependency);
+        self
+    }
+
+    /// Gets the dev dependency with the given name.
+    pub fn get_dev_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
+        self.dev_dependencies().get(name)
+    }
+
+    /// Gets the mutable dev dependency with the given name.
+    pub fn get_dev_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        self.dev_dependencies_mut().into_get_mut(name)
+    }
+
+    /// Gets the dev dependencies.
+    pub fn dev_dependencies(&self) -> Dependencies<'_> {
+        self.0
+            .get("devDependencies")
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Gets the mutable dev dependencies.
+    pub fn dev_dependencies_mut(&mut self) -> DependenciesMut<'_> {
+        DependenciesMut::new(&mut self.0, "devDependencies")
+    }
+}
+
+impl NpmManifest {
+    /// Adds a new peer dependency to the manifest.
+    pub fn add_peer_dependency(&mut self, dependency: impl Into<Dependency>) -> &mut Self {
+        self.peer_dependencies_mut().insert(dependency);
+        self
+    }
+
+    /// Builds the manifest with the given peer dependency.
+    pub fn with_peer_dependency(mut self, dependency: impl Into<Dependency>) -> Self {
+        self.add_peer_dependency(dependency);
+        self
+    }
+
+    /// Gets the peer dependency with the given name.
+    pub fn get_peer_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
+        self.peer_dependencies().get(name)
+    }
+
+    /// Gets the mutable peer dependency with the given name.
+    pub fn get_peer_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        self.peer_dependencies_mut().into_get_mut(name)
+    }
+
+    /// Gets the peer dependencies.
+    pub fn peer_dependencies(&self) -> Dependencies<'_> {
+        self.0
+            .get("peerDependencies")
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Gets the mutable peer dependencies.
+    pub fn peer_dependencies_mut(&mut self) -> DependenciesMut<'_> {
+        DependenciesMut::new(&mut self.0, "peerDependencies")
+    }
+}
+
+impl Default for NpmManifest {
+    fn default() -> Self {
+        Self::new_workspace()
+    }
+}
+
+impl Display for NpmManifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string_pretty(&self.0).map_err(|_| fmt::Error)?;
+
+        writeln!(f, "{json}")
+    }
+}
+
+impl FromStr for NpmManifest {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let manifest = Self(serde_json::from_str(s)?);
+
+        if let Some(version) = manifest.0.get("version") {
+            let is_valid = version
+                .as_str()
+                .is_some_and(|version| version.parse::<Version>().is_ok());
+
+            if !is_valid {
+                return Err(Error::Version(version.to_string()));
+            }
+        }
+
+        Ok(manifest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use semver::Version;
+
+    use super::{Dependency, NpmManifest};
+
+    #[test]
+    fn test_package() {
+        let mut manifest = NpmManifest::new_package("example");
+
+        assert_eq!(manifest.name(), Some("example"));
+        assert_eq!(manifest.description(), None);
+        assert_eq!(manifest.version(), Version::new(0, 0, 0));
+
+        manifest.set_description("An example package.");
+        manifest.set_version(Version::new(0, 1, 0));
+        manifest.add_author("Joe Bloggs");
+        manifest.add_author("Jane Bloggs");
+
+        assert_eq!(manifest.description(), Some("An example package."));
+        assert_eq!(manifest.version(), Version::new(0, 1, 0));
+        assert_eq!(
+            manifest.authors().unwrap().into_iter().collect::<Vec<_>>(),
+            ["Joe Bloggs", "Jane Bloggs"]
+        );
+
+        let expected = indoc::indoc! {r#"
+            {
+              "name": "example",
+              "version": "0.1.0",
+              "description": "An example package.",
+              "author": "Joe Bloggs",
+              "contributors": [
+                "Jane Bloggs"
+              ]
+            }
+        "#};
+
+        assert_eq!(manifest.to_string(), expected);
+        assert!(r#"{"name":"example","version":"1.0"}"#.parse::<NpmManifest>().is_err());
+        assert!(r#"{"name":"example","version":1}"#.parse::<NpmManifest>().is_err());
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let mut manifest = indoc::indoc! {r#"
+            {
+              "name": "example",
+              "version": "0.1.0",
+              "dependencies": {
+                "example-one": "^0.1.0",
+                "example-two": "file:../example-two",
+                "example-three": "workspace:*"
+              },
+              "devDependencies": {
+                "example-four": "~0.2.0"
+              },
+              "peerDependencies": {
+                "example-five": "*"
+              }
+            }
+        "#}
+        .parse::<NpmManifest>()
+        .unwrap();
+
+        assert_eq!(manifest.dependencies().into_iter().count(), 3);
+        assert_eq!(manifest.dev_dependencies().into_iter().count(), 1);
+        assert_eq!(manifest.peer_dependencies().into_iter().count(), 1);
+
+        let one = manifest.get_dependency("example-one").unwrap();
+
+        assert_eq!(one.name(), "example-one");
+        assert_eq!(one.version(), Some("^0.1.0"));
+        assert_eq!(one.path(), None);
+
+        let two = manifest.get_dependency("example-two").unwrap();
+
+        assert_eq!(two.path(), Some("../example-two"));
+
+        let mut one = manifest.get_dependency_mut("example-one").unwrap();
+
+        assert_eq!(one.version(), Some(Version::new(0, 1, 0)));
+
+        one.set_version(Version::new(0, 2, 0));
+
+        let mut three = manifest.get_dependency_mut("example-three").unwrap();
+
+        three.set_version(Version::new(0, 2, 0));
+
+        let mut four = manifest.get_dev_dependency_mut("example-four").unwrap();
+
+        four.set_version(Version::new(0, 3, 0));
+
+        let mut five = manifest.get_peer_dependency_mut("example-five").unwrap();
+
+        five.set_version(Version::new(0, 3, 0));
+
+        assert_eq!(
+            manifest.get_dependency("example-one").unwrap().version(),
+            Some("^0.2.0")
+        );
+        assert_eq!(
+            manifest.get_dependency("example-three").unwrap().version(),
+            Some("workspace:*")
+        );
+        assert_eq!(
+            manifest
+                .get_dev_dependency("example-four")
+                .unwrap()
+                .version(),
+            Some("~0.3.0")
+        );
+        assert_eq!(
+            manifest
+                .get_peer_dependency("example-five")
+                .unwrap()
+                .version(),
+            Some("*")
+        );
+
+        manifest.add_dependency(Dependency::new("example-six").with_version(Version::new(1, 0, 0)));
+        manifest.add_peer_dependency(Dependency::new("example-seven").with_path("../seven"));
+
+        assert_eq!(
+            manifest.get_dependency("example-six").unwrap().version(),
+            Some("^1.0.0")
+        );
+        assert_eq!(
+            manifest
+                .get_peer_dependency("example-seven")
+                .unwrap()
+                .path(),
+            Some("../seven")
+        );
+    }
+
+    #[test]
+    fn test_members() {
+        let mut manifest = NpmManifest::new_workspace();
+
+        manifest.add_workspace("packages/*");
+        manifest.add_workspace("packages/example");
+        manifest.add_workspace("examples/example");
+        manifest.add_workspace("!packages/excluded");
+
+        let members = manifest.members().unwrap();
+
+        assert!(members.includes(Path::new("packages/example")));
+        assert!(members.includes(Path::new("examples/example")));
+        assert!(!members.includes(Path::new("packages/excluded")));
+
+        let expected = indoc::indoc! {r#"
+            {
+              "private": true,
+              "workspaces": [
+                "packages/*",
+                "examples/example",
+                "!packages/excluded"
+              ]
+            }
+        "#};
+
+        assert_eq!(manifest.to_string(), expected);
+
+        let manifest = indoc::indoc! {r#"
+            {
+              "private": true,
+              "workspaces": {
+                "packages": ["packages/*"]
+              }
+            }
+        "#}
+        .parse::<NpmManifest>()
+        .unwrap();
+
+        let members = manifest.members().unwrap();
+
+        assert!(members.includes(Path::new("packages/example")));
+    }
+}
@@ -0,0 +1,622 @@
+use std::fmt::{self, Debug};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use either::Either;
+use semver::Version;
+use toml_edit::{Array, Item, KeyMut, TableLike, Value};
+
+/// A *Python* package dependency.
+pub struct Dependency {
+    name: String,
+    version: Option<Version>,
+    path: Option<PathBuf>,
+}
+
+impl Dependency {
+    /// Creates a new dependency.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: None,
+            path: None,
+        }
+    }
+
+    /// Gets the dependency name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the dependency version.
+    pub fn version(&self) -> Option<&Version> {
+        self.version.as_ref()
+    }
+
+    /// Sets the dependency version.
+    pub fn set_version(&mut self, version: impl Into<Version>) -> &mut Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Builds the dependency with the given version.
+    pub fn with_version(mut self, version: impl Into<Version>) -> Self {
+        self.set_version(version);
+        self
+    }
+
+    /// Gets the dependency path.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Sets the dependency path.
+    pub fn set_path(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Builds the dependency with the given path.
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.set_path(path);
+        self
+    }
+}
+
+impl Dependency {
+    /// Converts the dependency into a PEP 508 requirement string.
+    pub(super) fn to_requirement(&self) -> Option<String> {
+        match (self.path(), self.version()) {
+            (Some(path), _) => Some(format!("{} @ file:{}", self.name, path.display())),
+            (None, Some(version)) => Some(format!("{}>={}", self.name, format_version(version))),
+            (None, None) => None,
+        }
+    }
+
+    /// Converts the dependency into a Poetry dependency item.
+    pub(super) fn to_poetry_item(&self) -> Option<Item> {
+        match (self.path(), self.version()) {
+            (Some(path), _) => {
+                let mut table = toml_edit::InlineTable::new();
+
+                table.insert("path", Value::from(path.display().to_string()));
+
+                Some(Item::Value(Value::InlineTable(table)))
+            }
+            (None, Some(version)) => {
+                Some(toml_edit::value(format!("^{}", format_version(version))))
+            }
+            (None, None) => None,
+        }
+    }
+}
+
+/// The python package dependency.
+#[derive(Clone)]
+pub struct DependencyRef<'a> {
+    name: &'a str,
+    version: Option<&'a str>,
+    path: Option<&'a str>,
+}
+
+impl<'a> DependencyRef<'a> {
+    /// Gets the dependency name.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Gets the dependency version specifier if it has been set.
+    pub fn version(&self) -> Option<&'a str> {
+        self.version
+    }
+
+    /// Gets the dependency path if it has been set.
+    pub fn path(&self) -> Option<&'a str> {
+        self.path
+    }
+
+    /// Constructs the dependency from a PEP 508 requirement string.
+    pub(super) fn from_requirement(requirement: &'a str) -> Self {
+        let parsed = Requirement::parse(requirement);
+
+        Self {
+            name: &requirement[parsed.name],
+            version: parsed.version.map(|range| &requirement[range]),
+            path: parsed.url.and_then(|range| file_path(&requirement[range])),
+        }
+    }
+
+    /// Constructs the dependency from a Poetry dependency item.
+    fn from_poetry(name: &'a str, item: &'a Item) -> Self {
+        Self {
+            name,
+            version: match item.as_str() {
+                Some(version) => Some(version),
+                None => item.get("version").and_then(Item::as_str),
+            },
+            path: item.get("path").and_then(Item::as_str),
+        }
+    }
+}
+
+impl Debug for DependencyRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DependencyRef")
+            .field("name", &self.name())
+            .field("version", &self.version())
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+/// The python package dependencies.
+///
+/// These are either a PEP 508 requirements array or a Poetry dependencies
+/// table.
+#[derive(Clone, Default)]
+pub struct Dependencies<'a> {
+    pub(super) inner: Option<Either<&'a Array, &'a dyn TableLike>>,
+}
+
+impl<'a> Dependencies<'a> {
+    /// Gets the dependency with the given name.
+    ///
+    /// This compares names using the PEP 503 normalized form.
+    pub fn get(&self, name: impl AsRef<str>) -> Option<DependencyRef<'a>> {
+        self.clone()
+            .into_iter()
+            .find(|dependency| is_same_name(dependency.name(), name.as_ref()))
+    }
+}
+
+impl<'a> IntoIterator for Dependencies<'a> {
+    type Item = DependencyRef<'a>;
+    type IntoIter = Box<dyn Iterator<Item = DependencyRef<'a>> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self.inner {
+            Some(Either::Left(array)) => Box::new(
+                array
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(DependencyRef::from_requirement),
+            ),
+            Some(Either::Right(table)) => Box::new(
+                table
+                    .iter()
+                    .filter(|(name, _)| *name != "python")
+                    .map(|(name, item)| DependencyRef::from_poetry(name, item)),
+            ),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+impl Debug for Dependencies<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a> From<&'a Item> for Dependencies<'a> {
+    fn from(item: &'a Item) -> Self {
+        match item.as_array() {
+            Some(array) => Self {
+                inner: Some(Either::Left(array)),
+            },
+            None => Self {
+                inner: item.as_table_like().map(Either::Right),
+            },
+        }
+    }
+}
+
+/// The mutable python package dependency.
+pub struct DependencyMut<'a> {
+    inner: Either<&'a mut Value, (KeyMut<'a>, &'a mut Item)>,
+}
+
+impl DependencyMut<'_> {
+    /// Gets the dependency name.
+    pub fn name(&self) -> &str {
+        match &self.inner {
+            Either::Left(value) => {
+                let requirement = value.as_str().unwrap_or_default();
+
+                &requirement[Requirement::parse(requirement).name]
+            }
+            Either::Right((name, _)) => name.get(),
+        }
+    }
+
+    /// Gets the dependency version if it has been set.
+    ///
+    /// This only returns a version for specifiers with a single clause such as
+    /// `>=1.0.0` or `^1.0.0`.
+    pub fn version(&self) -> Option<Version> {
+        let specifier = self.specifier()?;
+        let (_, version) = split_operator(specifier);
+
+        parse_version(version)
+    }
+
+    /// Sets the dependency version.
+    ///
+    /// This preserves the existing operator and leaves dependencies without a
+    /// single version clause unchanged.
+    pub fn set_version(&mut self, version: impl Into<Version>) {
+        let version = version.into();
+
+        let Some(specifier) = self.specifier() else {
+            return;
+        };
+
+        let (operator, current) = split_operator(specifier);
+
+        if parse_version(current).is_none() {
+            return;
+        }
+
+        let specifier = format!("{operator}{}", format_version(&version));
+
+        match &mut self.inner {
+            Either::Left(value) => {
+                let requirement = value.as_str().unwrap_or_default();
+                let range = Requirement::parse(requirement).version.expect("version");
+                let mut requirement = requirement.to_owned();
+
+                requirement.replace_range(range, &specifier);
+
+                let decor = value.decor().clone();
+
+                **value = Value::from(requirement);
+                *value.decor_mut() = decor;
+            }
+            Either::Right((_, item)) => {
+                if let Some(table) = item.as_table_like_mut() {
+                    let item = table.entry("version").or_insert_with(Item::default);
+
+                    *item = Item::Value(Value::from(specifier));
+                } else if let Some(value) = item.as_value_mut() {
+                    *value = Value::from(specifier);
+                }
+            }
+        }
+    }
+
+    /// Gets the dependency path if it has been set.
+    pub fn path(&self) -> Option<&str> {
+        match &self.inner {
+            Either::Left(value) => {
+                let requirement = value.as_str()?;
+
+                file_path(&requirement[Requirement::parse(requirement).url?])
+            }
+            Either::Right((_, item)) => item.get("path").and_then(Item::as_str),
+        }
+    }
+
+    fn specifier(&self) -> Option<&str> {
+        match &self.inner {
+            Either::Left(value) => {
+                let requirement = value.as_str()?;
+
+                Some(&requirement[Requirement::parse(requirement).version?])
+            }
+            Either::Right((_, item)) => match item.as_str() {
+                Some(version) => Some(version),
+                None => item.get("version").and_then(Item::as_str),
+            },
+        }
+    }
+}
+
+impl Debug for DependencyMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DependencyMut")
+            .field("name", &self.name())
+            .field("version", &self.version())
+            .field("path", &self.path())
+            .finish()
+    }
+}
+
+/// The mutable python package dependencies.
+#[derive(Default)]
+pub struct DependenciesMut<'a> {
+    pub(super) inner: Option<Either<&'a mut Array, &'a mut dyn TableLike>>,
+}
+
+impl<'a> DependenciesMut<'a> {
+    /// Gets the mutable dependency with the given name.
+    pub fn get_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        let inner: Either<&mut Array, &mut dyn TableLike> = match &mut self.inner {
+            Some(Either::Left(array)) => Either::Left(array),
+            Some(Either::Right(table)) => Either::Right(&mut **table),
+            None => return None,
+        };
+
+        DependenciesMut { inner: Some(inner) }.into_get_mut(name)
+    }
+
+    /// Gets the mutable dependency with the given name.
+    pub fn into_get_mut(self, name: impl AsRef<str>) -> Option<DependencyMut<'a>> {
+        self.into_iter()
+            .find(|dependency| is_same_name(dependency.name(), name.as_ref()))
+    }
+}
+
+impl<'a> IntoIterator for DependenciesMut<'a> {
+    type Item = DependencyMut<'a>;
+    type IntoIter = Box<dyn Iterator<Item = DependencyMut<'a>> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self.inner {
+            Some(Either::Left(array)) => Box::new(
+                array
+                    .iter_mut()
+                    .filter(|value| value.is_str())
+                    .map(|value| DependencyMut {
+                        inner: Either::Left(value),
+                    }),
+            ),
+            Some(Either::Right(table)) => Box::new(
+                table
+                    .iter_mut()
+                    .filter(|(name, _)| name.get() != "python")
+                    .map(|(name, item)| DependencyMut {
+                        inner: Either::Right((name, item)),
+                    }),
+            ),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+}
+
+impl Debug for DependenciesMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let inner = match &self.inner {
+            Some(Either::Left(array)) => Some(Either::Left(&**array)),
+            Some(Either::Right(table)) => Some(Either::Right(&**table)),
+            None => None,
+        };
+
+        f.debug_list().entries(Dependencies { inner }).finish()
+    }
+}
+
+/// The byte ranges of a PEP 508 requirement string.
+struct Requirement {
+    name: Range<usize>,
+    version: Option<Range<usize>>,
+    url: Option<Range<usize>>,
+}
+
+impl Requirement {
+    /// Parses the name, version specifier and URL of the requirement.
+    ///
+    /// This is a lenient parser that skips over extras and ignores environment
+    /// markers.
+    fn parse(requirement: &str) -> Self {
+        let end = requirement.find(';').unwrap_or(requirement.len());
+        let skip = |from: usize| end - requirement[from..end].trim_start().len();
+
+        let start = skip(0);
+        let name = start
+            ..requirement[start..end]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+                .map_or(end, |index| start + index);
+
+        let mut from = skip(name.end);
+
+        if requirement[from..end].starts_with('[') {
+            from = match requirement[from..end].find(']') {
+                Some(index) => skip(from + index + 1),
+                None => end,
+            };
+        }
+
+        let mut to = from + requirement[from..end].trim_end().len();
+
+        if requirement[from..to].starts_with('@') {
+            let from = skip(from + 1);
+
+            return Self {
+                name,
+                version: None,
+                url: (from < to).then_some(from..to),
+            };
+        }
+
+        if requirement[from..to].starts_with('(') && requirement[from..to].ends_with(')') {
+            from = skip(from + 1);
+            to = from + requirement[from..to - 1].trim_end().len();
+        }
+
+        Self {
+            name,
+            version: (from < to).then_some(from..to),
+            url: None,
+        }
+    }
+}
+
+/// Checks whether the two names are equal after PEP 503 normalization.
+pub(super) fn is_same_name(a: &str, b: &str) -> bool {
+    fn normalize(name: &str) -> impl Iterator<Item = char> + '_ {
+        name.chars().map(|c| match c {
+            '-' | '_' | '.' => '-',
+            c => c.to_ascii_lowercase(),
+        })
+    }
+
+    normalize(a).eq(normalize(b))
+}
+
+/// Splits the operator from the version specifier.
+fn split_operator(specifier: &str) -> (&str, &str) {
+    let index = specifier
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(specifier.len());
+
+    specifier.split_at(index)
+}
+
+/// Parses a PEP 440 version into a semantic version.
+///
+/// Versions that omit the minor or patch components are padded with zeros.
+/// Pre-releases map to the `alpha`, `beta` and `rc` identifiers and development
+/// releases to `dev`. Post-releases have no semver equivalent so these are kept
+/// as `post` build metadata along with any local version label. Versions with
+/// more than three release components or a non-zero epoch are not supported.
+pub(super) fn parse_version(version: &str) -> Option<Version> {
+    if version.contains(',') {
+        return None;
+    }
+
+    let version = version.trim().to_ascii_lowercase();
+    let version = version.strip_prefix('v').unwrap_or(&version);
+
+    let (version, local) = match version.split_once('+') {
+        Some((version, local)) => (version, Some(local)),
+        None => (version, None),
+    };
+
+    let version = match version.split_once('!') {
+        Some((epoch, version)) if epoch.parse::<u64>().ok()? == 0 => version,
+        Some(_) => return None,
+        None => version,
+    };
+
+    let index = version
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(version.len());
+    let (release, mut rest) = version.split_at(index);
+
+    let release = release
+        .trim_end_matches('.')
+        .split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    let [major, minor, patch] = match release[..] {
+        [major] => [major, 0, 0],
+        [major, minor] => [major, minor, 0],
+        [major, minor, patch] => [major, minor, patch],
+        _ => return None,
+    };
+
+    let mut pre = Vec::new();
+    let mut build = Vec::new();
+
+    while !rest.is_empty() {
+        let segment = rest.trim_start_matches(['.', '-', '_']);
+        let index = segment
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(segment.len());
+        let (label, segment) = segment.split_at(index);
+        let segment = match label.is_empty() {
+            true => segment,
+            false => segment.trim_start_matches(['.', '-', '_']),
+        };
+        let index = segment
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(segment.len());
+        let (number, segment) = segment.split_at(index);
+
+        if label.is_empty() && number.is_empty() {
+            return None;
+        }
+
+        let number = match number {
+            "" => 0,
+            number => number.parse::<u64>().ok()?,
+        };
+
+        match label {
+            "a" | "alpha" => pre.push(format!("alpha.{number}")),
+            "b" | "beta" => pre.push(format!("beta.{number}")),
+            "c" | "rc" | "pre" | "preview" => pre.push(format!("rc.{number}")),
+            "dev" if build.is_empty() => pre.push(format!("dev.{number}")),
+            "dev" => build.push(format!("dev.{number}")),
+            "" | "post" | "rev" | "r" => build.push(format!("post.{number}")),
+            _ => return None,
+        }
+
+        rest = segment;
+    }
+
+    if let Some(local) = local {
+        build.push(local.replace(['_', '-'], "."));
+    }
+
+    let mut version = format!("{major}.{minor}.{patch}");
+
+    if !pre.is_empty() {
+        version = format!("{version}-{}", pre.join("."));
+    }
+
+    if !build.is_empty() {
+        version = format!("{version}+{}", build.join("."));
+    }
+
+    version.parse().ok()
+}
+
+/// Formats a semantic version as a PEP 440 version.
+///
+/// This reverses the mapping of [`parse_version`] and otherwise falls back to
+/// the semantic version for pre-release identifiers it does not recognise.
+pub(super) fn format_version(version: &Version) -> String {
+    let mut output = format!("{}.{}.{}", version.major, version.minor, version.patch);
+    let mut local = Vec::new();
+
+    let mut identifiers = version
+        .pre
+        .split('.')
+        .filter(|id| !id.is_empty())
+        .peekable();
+
+    while let Some(label) = identifiers.next() {
+        let number = identifiers
+            .next_if(|id| id.bytes().all(|b| b.is_ascii_digit()))
+            .unwrap_or("0");
+
+        match label {
+            "alpha" => output.push_str(&format!("a{number}")),
+            "beta" => output.push_str(&format!("b{number}")),
+            "rc" => output.push_str(&format!("rc{number}")),
+            "dev" => output.push_str(&format!(".dev{number}")),
+            _ => return version.to_string(),
+        }
+    }
+
+    let mut identifiers = version
+        .build
+        .split('.')
+        .filter(|id| !id.is_empty())
+        .peekable();
+
+    while let Some(label) = identifiers.next() {
+        let number = identifiers.next_if(|id| id.bytes().all(|b| b.is_ascii_digit()));
+
+        match (label, number) {
+            ("post", Some(number)) => output.push_str(&format!(".post{number}")),
+            ("dev", Some(number)) => output.push_str(&format!(".dev{number}")),
+            (label, number) => local.extend([label].into_iter().chain(number)),
+        }
+    }
+
+    if !local.is_empty() {
+        output.push('+');
+        output.push_str(&local.join("."));
+    }
+
+    output
+}
+
+/// Gets the path from a `file:` URL.
+fn file_path(url: &str) -> Option<&str> {
+    url.strip_prefix("file://")
+        .or_else(|| url.strip_prefix("file:"))
+}
@@ -0,0 +1,933 @@
+//! The `pyproject.toml` package manifest for Python.
+//!
+//! This supports the standard [PEP 621] `[project]` table used by tools such as
+//! Hatch and falls back to the `[tool.poetry]` table for Poetry projects.
+//!
+//! [PEP 621]: https://peps.python.org/pep-0621/
+
+mod dependency;
+
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use either::Either;
+use globset::{Glob, GlobSetBuilder};
+use semver::Version;
+use toml_edit::{Array, DocumentMut, Item, Table, TableLike, Value, value};
+use url::Url;
+
+use crate::package::manifest::Members;
+use crate::repository::RepoAddr;
+
+pub use self::dependency::{
+    Dependencies, DependenciesMut, Dependency, DependencyMut, DependencyRef,
+};
+
+use super::Error;
+
+/// The python package manifest.
+#[derive(Clone, Debug)]
+pub struct PythonManifest(DocumentMut);
+
+impl PythonManifest {
+    /// Constructs a new python package manifest.
+    pub fn new_package(name: impl Into<String>) -> Self {
+        Self({
+            let mut document = DocumentMut::new();
+
+            document.insert(
+                "project",
+                Item::Table({
+                    let mut table = Table::new();
+
+                    table.insert("name", value(name.into()));
+                    table.insert("version", value("0.0.0"));
+                    table
+                }),
+            );
+            document
+        })
+    }
+
+    /// Constructs a new python workspace manifest.
+    ///
+    /// This uses the `[tool.uv.workspace]` table to define the members.
+    pub fn new_workspace() -> Self {
+        Self({
+            let mut document = DocumentMut::new();
+            let mut tool = Table::new();
+            let mut uv = Table::new();
+            let mut workspace = Table::new();
+
+            workspace.insert("members", value(Array::new()));
+            uv.insert("workspace", Item::Table(workspace));
+            uv.set_implicit(true);
+            tool.insert("uv", Item::Table(uv));
+            tool.set_implicit(true);
+            document.insert("tool", Item::Table(tool));
+            document
+        })
+    }
+
+    /// Checks whether this is a Poetry manifest.
+    ///
+    /// This is only the case when the `[tool.poetry]` table is present without
+    /// the standard `[project]` table.
+    pub fn is_poetry(&self) -> bool {
+        self.0.get("project").is_none() && self.poetry().is_some()
+    }
+
+    /// Gets the package name.
+    pub fn name(&self) -> Option<&str> {
+        self.package()?.get("name")?.as_str()
+    }
+
+    /// Gets the package description.
+    pub fn description(&self) -> Option<&str> {
+        self.package()?.get("description")?.as_str()
+    }
+
+    /// Sets the package description.
+    pub fn set_description(&mut self, description: impl Into<String>) -> &mut Self {
+        if let Some(package) = self.package_mut() {
+            let item = package.entry("description").or_insert_with(Item::default);
+
+            *item = Item::Value(Value::from(description.into()));
+        }
+
+        self
+    }
+
+    /// Gets the package version.
+    ///
+    /// This defaults to `0.0.0` if the `version` field has not been set or is
+    /// declared as dynamic. PEP 440 versions are mapped to semantic versions
+    /// and are validated when the manifest is parsed.
+    pub fn version(&self) -> Version {
+        match self.package().and_then(|package| package.get("version")) {
+            Some(version) => version
+                .as_str()
+                .and_then(dependency::parse_version)
+                .expect("version should be valid"),
+            None => Version::new(0, 0, 0),
+        }
+    }
+
+    /// Sets the package version.
+    pub fn set_version(&mut self, version: impl Into<Version>) -> &mut Self {
+        if let Some(package) = self.package_mut() {
+            let item = package.entry("version").or_insert_with(Item::default);
+
+            *item = Item::Value(Value::from(dependency::format_version(&version.into())));
+        }
+
+        self
+    }
+
+    /// Gets the package repository.
+    ///
+    /// This reads the `Repository` or `Source` entries in `[project.urls]` or
+    /// the `repository` field in `[tool.poetry]`.
+    pub fn repository(&self) -> Option<Url> {
+        let package = self.package()?;
+        let url = match package.get("urls").and_then(Item::as_table_like) {
+            Some(urls) => urls
+                .iter()
+                .find(|(key, _)| matches!(&*key.to_lowercase(), "repository" | "source"))
+                .and_then(|(_, url)| url.as_str()),
+            None => package.get("repository").and_then(Item::as_str),
+        };
+
+        url?.parse().ok()
+    }
+
+    /// Sets the package repository.
+    pub fn set_repository(&mut self, repository: impl Into<RepoAddr>) -> &mut Self {
+        let is_poetry = self.is_poetry();
+
+        if let Some(package) = self.package_mut() {
+            let repository = repository.into().to_string();
+
+            if is_poetry {
+                package.insert("repository", value(repository));
+            } else {
+                let urls = package
+                    .entry("urls")
+                    .or_insert_with(|| Item::Table(Table::new()));
+
+                if let Some(urls) = urls.as_table_like_mut() {
+                    urls.insert("Repository", value(repository));
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Gets the package authors.
+    ///
+    /// This includes the PEP 621 author names or the Poetry author strings.
+    pub fn authors(&self) -> Option<impl IntoIterator<Item = &str>> {
+        Some(
+            self.package()?
+                .get("authors")?
+                .as_array()?
+                .iter()
+                .filter_map(|author| match author.as_str() {
+                    Some(author) => Some(author),
+                    None => author.as_inline_table()?.get("name")?.as_str(),
+                }),
+        )
+    }
+
+    /// Adds a package author.
+    pub fn add_author(&mut self, author: impl Into<String>) -> &mut Self {
+        let is_poetry = self.is_poetry();
+
+        if let Some(package) = self.package_mut() {
+            let authors = package
+                .entry("authors")
+                .or_insert_with(|| value(Array::new()));
+
+            if !authors.is_array() {
+                *authors = value(Array::new());
+            }
+
+            let authors = authors.as_array_mut().expect("array");
+
+            if is_poetry {
+                authors.push(author.into());
+            } else {
+                let mut table = toml_edit::InlineTable::new();
+
+                table.insert("name", Value::from(author.into()));
+                authors.push(table);
+            }
+        }
+
+        self
+    }
+
+    /// Gets the workspace members.
+    ///
+    /// This follows the `[tool.uv.workspace]` members and exclude fields as
+    /// well as any path dependencies.
+    pub fn members(&self) -> Result<Members, Error> {
+        let mut includes = GlobSetBuilder::new();
+        let mut excludes = Vec::new();
+
+        if let Some(workspace) = self.uv_workspace() {
+            for member in array_strs(workspace.get("members")) {
+                includes.add(Glob::new(member.trim_start_matches("./"))?);
+            }
+
+            for path in array_strs(workspace.get("exclude")) {
+                excludes.push(PathBuf::from(path));
+            }
+        }
+
+        let dependencies = self
+            .dependencies()
+            .into_iter()
+            .chain(self.dev_dependencies())
+            .chain(
+                self.optional_dependency_groups()
+                    .flat_map(|group| self.optional_dependencies(group)),
+            );
+
+        for dependency in dependencies {
+            if let Some(path) = dependency.path() {
+                includes.add(Glob::new(path.trim_start_matches("./"))?);
+            }
+        }
+
+        Ok(Members::new(includes.build()?, excludes))
+    }
+
+    /// Adds a new workspace member.
+    pub fn add_workspace_member(&mut self, path: impl AsRef<Path>) -> &mut Self {
+        let tool = self
+            .0
+            .entry("tool")
+            .or_insert_with(|| Item::Table(implicit_table()));
+        let uv = tool
+            .as_table_like_mut()
+            .expect("table")
+            .entry("uv")
+            .or_insert_with(|| Item::Table(implicit_table()));
+        let workspace = uv
+            .as_table_like_mut()
+            .expect("table")
+            .entry("workspace")
+            .or_insert_with(|| Item::Table(Table::new()));
+        let members = workspace
+            .as_table_like_mut()
+            .expect("table")
+            .entry("members")
+            .or_insert_with(|| value(Array::new()));
+
+        if let Some(members) = members.as_array_mut() {
+            let exists = members.iter().filter_map(Value::as_str).any(|member| {
+                path.as_ref() == Path::new(member)
+                    || Glob::new(member.trim_start_matches("./"))
+                        .is_ok_and(|glob| glob.compile_matcher().is_match(path.as_ref()))
+            });
+
+            if !exists {
+                members.push(path.as_ref().to_string_lossy().to_string());
+            }
+        }
+
+        self
+    }
+
+    /// Builds the workspace with the given member.
+    pub fn with_workspace_member(mut self, path: impl AsRef<Path>) -> Self {
+        self.add_workspace_member(path);
+        self
+    }
+
+    /// Creates a manifest from the given bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        std::str::from_utf8(bytes)?.parse()
+    }
+}
+
+impl PythonManifest {
+    /// Adds a new dependency to the manifest.
+    pub fn add_dependency(&mut self, dependency: impl Into<Dependency>) -> &mut Self {
+        let dependency = dependency.into();
+
+        match self.is_poetry() {
+            true => insert_poetry(self.poetry_mut(), "dependencies", &dependency),
+            false => insert_requirement(self.project_mut(), "dependencies", &dependency),
+        }
+
+        self
+    }
+
+    /// Builds the manifest with the given dependency.
+    pub fn with_dependency(mut self, dependency: impl Into<Dependency>) -> Self {
+        self.add_dependency(dependency);
+        self
+    }
+
+    /// Gets the dependency with the given name.
+    pub fn get_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
+        self.dependencies().get(name)
+    }
+
+    /// Gets the mutable dependency with the given name.
+    pub fn get_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        self.dependencies_mut().into_get_mut(name)
+    }
+
+    /// Gets the dependencies.
+    ///
+    /// This is the `[project].dependencies` array or the Poetry dependencies
+    /// table.
+    pub fn dependencies(&self) -> Dependencies<'_> {
+        self.package()
+            .and_then(|package| package.get("dependencies"))
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Gets the mutable dependencies.
+    pub fn dependencies_mut(&mut self) -> DependenciesMut<'_> {
+        dependencies_mut(
+            self.package_mut()
+                .and_then(|package| package.get_mut("dependencies")),
+        )
+    }
+}
+
+impl PythonManifest {
+    /// Adds a new dev dependency to the manifest.
+    pub fn add_dev_dependency(&mut self, dependency: impl Into<Dependency>) -> &mut Self {
+        let dependency = dependency.into();
+
+        match self.is_poetry() {
+            true => {
+                let group = table_entry(table_entry(self.poetry_mut(), "group"), "dev");
+
+                insert_poetry(group, "dependencies", &dependency);
+            }
+            false => {
+                let groups = self
+                    .0
+                    .entry("dependency-groups")
+                    .or_insert_with(|| Item::Table(Table::new()))
+                    .as_table_like_mut();
+
+                insert_requirement(groups, "dev", &dependency);
+            }
+        }
+
+        self
+    }
+
+    /// Builds the manifest with the given dev dependency.
+    pub fn with_dev_dependency(mut self, dependency: impl Into<Dependency>) -> Self {
+        self.add_dev_dependency(dependency);
+        self
+    }
+
+    /// Gets the dev dependency with the given name.
+    pub fn get_dev_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
+        self.dev_dependencies().get(name)
+    }
+
+    /// Gets the mutable dev dependency with the given name.
+    pub fn get_dev_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        self.dev_dependencies_mut().into_get_mut(name)
+    }
+
+    /// Gets the dev dependencies.
+    ///
+    /// This is the `dev` group in the [PEP 735] `[dependency-groups]` table or
+    /// the Poetry `dev` group.
+    ///
+    /// [PEP 735]: https://peps.python.org/pep-0735/
+    pub fn dev_dependencies(&self) -> Dependencies<'_> {
+        let item = match self.is_poetry() {
+            true => self.poetry().and_then(|poetry| {
+                match poetry.get("group").and_then(|group| group.get("dev")) {
+                    Some(dev) => dev.get("dependencies"),
+                    None => poetry.get("dev-dependencies"),
+                }
+            }),
+            false => self
+                .0
+                .get("dependency-groups")
+                .and_then(|groups| groups.get("dev")),
+        };
+
+        item.map(Into::into).unwrap_or_default()
+    }
+
+    /// Gets the mutable dev dependencies.
+    pub fn dev_dependencies_mut(&mut self) -> DependenciesMut<'_> {
+        let item = match self.is_poetry() {
+            true => self
+                .poetry_mut()
+                .and_then(|poetry| match poetry.contains_key("group") {
+                    true => poetry
+                        .get_mut("group")?
+                        .get_mut("dev")?
+                        .get_mut("dependencies"),
+                    false => poetry.get_mut("dev-dependencies"),
+                }),
+            false => self
+                .0
+                .get_mut("dependency-groups")
+                .and_then(|groups| groups.get_mut("dev")),
+        };
+
+        dependencies_mut(item)
+    }
+}
+
+impl PythonManifest {
+    /// Gets the build dependency with the given name.
+    pub fn get_build_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
+        self.build_dependencies().get(name)
+    }
+
+    /// Gets the mutable build dependency with the given name.
+    pub fn get_build_dependency_mut(&mut self, name: impl AsRef<str>) -> Option<DependencyMut<'_>> {
+        self.build_dependencies_mut().into_get_mut(name)
+    }
+
+    /// Gets the build dependencies.
+    ///
+    /// This is the `[build-system].requires` array.
+    pub fn build_dependencies(&self) -> Dependencies<'_> {
+        self.0
+            .get("build-system")
+            .and_then(|build| build.get("requires"))
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Gets the mutable build dependencies.
+    pub fn build_dependencies_mut(&mut self) -> DependenciesMut<'_> {
+        dependencies_mut(
+            self.0
+                .get_mut("build-system")
+                .and_then(|build| build.get_mut("requires")),
+        )
+    }
+}
+
+impl PythonManifest {
+    /// Gets the optional dependency group names.
+    ///
+    /// This is the `[project.optional-dependencies]` table or the Poetry
+    /// dependency groups.
+    pub fn optional_dependency_groups(&self) -> impl Iterator<Item = &str> {
+        let groups = match self.is_poetry() {
+            true => self.poetry().and_then(|poetry| poetry.get("group")),
+            false => self
+                .project()
+                .and_then(|project| project.get("optional-dependencies")),
+        };
+
+        groups
+            .and_then(Item::as_table_like)
+            .into_iter()
+            .flat_map(|groups| groups.iter().map(|(name, _)| name))
+    }
+
+    /// Adds a new optional dependency to the given group.
+    pub fn add_optional_dependency(
+        &mut self,
+        group: impl AsRef<str>,
+        dependency: impl Into<Dependency>,
+    ) -> &mut Self {
+        let dependency = dependency.into();
+
+        match self.is_poetry() {
+            true => {
+                let group = table_entry(table_entry(self.poetry_mut(), "group"), group.as_ref());
+
+                insert_poetry(group, "dependencies", &dependency);
+            }
+            false => {
+                let groups = table_entry(self.project_mut(), "optional-dependencies");
+
+                insert_requirement(groups, group.as_ref(), &dependency);
+            }
+        }
+
+        self
+    }
+
+    /// Builds the manifest with the given optional dependency.
+    pub fn with_optional_dependency(
+        mut self,
+        group: impl AsRef<str>,
+        dependency: impl Into<Dependency>,
+    ) -> Self {
+        self.add_optional_dependency(group, dependency);
+        self
+    }
+
+    /// Gets the optional dependencies in the given group.
+    pub fn optional_dependencies(&self, group: impl AsRef<str>) -> Dependencies<'_> {
+        let item = match self.is_poetry() {
+            true => self.poetry().and_then(|poetry| {
+                poetry
+                    .get("group")?
+                    .get(group.as_ref())?
+                    .get("dependencies")
+            }),
+            false => self
+                .project()
+                .and_then(|project| project.get("optional-dependencies")?.get(group.as_ref())),
+        };
+
+        item.map(Into::into).unwrap_or_default()
+    }
+
+    /// Gets the mutable optional dependencies in the given group.
+    pub fn optional_dependencies_mut(&mut self, group: impl AsRef<str>) -> DependenciesMut<'_> {
+        let item = match self.is_poetry() {
+            true => self.poetry_mut().and_then(|poetry| {
+                poetry
+                    .get_mut("group")?
+                    .get_mut(group.as_ref())?
+                    .get_mut("dependencies")
+            }),
+            false => self.project_mut().and_then(|project| {
+                project
+                    .get_mut("optional-dependencies")?
+                    .get_mut(group.as_ref())
+            }),
+        };
+
+        dependencies_mut(item)
+    }
+}
+
+impl PythonManifest {
+    fn project(&self) -> Option<&dyn TableLike> {
+        self.0.get("project")?.as_table_like()
+    }
+
+    fn project_mut(&mut self) -> Option<&mut dyn TableLike> {
+        self.0.get_mut("project")?.as_table_like_mut()
+    }
+
+    fn poetry(&self) -> Option<&dyn TableLike> {
+        self.0.get("tool")?.get("poetry")?.as_table_like()
+    }
+
+    fn poetry_mut(&mut self) -> Option<&mut dyn TableLike> {
+        self.0
+            .get_mut("tool")?
+            .get_mut("poetry")?
+            .as_table_like_mut()
+    }
+
+    fn package(&self) -> Option<&dyn TableLike> {
+        match self.is_poetry() {
+            true => self.poetry(),
+            false => self.project(),
+        }
+    }
+
+    fn package_mut(&mut self) -> Option<&mut dyn TableLike> {
+        match self.is_poetry() {
+            true => self.poetry_mut(),
+            false => self.project_mut(),
+        }
+    }
+
+    fn uv_workspace(&self) -> Option<&dyn TableLike> {
+        self.0
+            .get("tool")?
+            .get("uv")?
+            .get("workspace")?
+            .as_table_like()
+    }
+}
+
+impl Default for PythonManifest {
+    fn default() -> Self {
+        Self::new_workspace()
+    }
+}
+
+impl Display for PythonManifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl PartialEq for PythonManifest {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Eq for PythonManifest {}
+
+impl FromStr for PythonManifest {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let manifest = Self(s.parse()?);
+
+        if let Some(version) = manifest
+            .package()
+            .and_then(|package| package.get("version"))
+        {
+            let is_valid = version
+                .as_str()
+                .and_then(dependency::parse_version)
+                .is_some();
+
+            if !is_valid {
+                return Err(Error::Version(version.to_string().trim().to_owned()));
+            }
+        }
+
+        Ok(manifest)
+    }
+}
+
+/// Creates an implicit table that is only rendered through its children.
+fn implicit_table() -> Table {
+    let mut table = Table::new();
+
+    table.set_implicit(true);
+    table
+}
+
+/// Gets or inserts the table with the given key.
+fn table_entry<'a>(
+    table: Option<&'a mut dyn TableLike>,
+    key: &str,
+) -> Option<&'a mut dyn TableLike> {
+    let item = table?
+        .entry(key)
+        .or_insert_with(|| Item::Table(implicit_table()));
+
+    if !item.is_table_like() {
+        *item = Item::Table(implicit_table());
+    }
+
+    item.as_table_like_mut()
+}
+
+/// Inserts the dependency as a PEP 508 requirement string.
+fn insert_requirement(table: Option<&mut dyn TableLike>, key: &str, dependency: &Dependency) {
+    let (Some(table), Some(requirement)) = (table, dependency.to_requirement()) else {
+        return;
+    };
+
+    let item = table.entry(key).or_insert_with(|| value(Array::new()));
+
+    if !item.is_array() {
+        *item = value(Array::new());
+    }
+
+    let array = item.as_array_mut().expect("array");
+
+    array.retain(|value| {
+        value
+            .as_str()
+            .map(DependencyRef::from_requirement)
+            .is_none_or(|existing| !dependency::is_same_name(existing.name(), dependency.name()))
+    });
+    array.push(requirement);
+}
+
+/// Inserts the dependency into a Poetry dependencies table.
+fn insert_poetry(table: Option<&mut dyn TableLike>, key: &str, dependency: &Dependency) {
+    let (Some(table), Some(item)) = (table_entry(table, key), dependency.to_poetry_item()) else {
+        return;
+    };
+
+    table.insert(dependency.name(), item);
+}
+
+/// Creates mutable dependencies from the given item.
+fn dependencies_mut(item: Option<&mut Item>) -> DependenciesMut<'_> {
+    match item {
+        Some(item) if item.is_array() => DependenciesMut {
+            inner: item.as_array_mut().map(Either::Left),
+        },
+        Some(item) => DependenciesMut {
+            inner: item.as_table_like_mut().map(Either::Right),
+        },
+        None => DependenciesMut::default(),
+    }
+}
+
+/// Gets the string values in an array item.
+fn array_strs(item: Option<&Item>) -> impl Iterator<Item = &str> {
+    item.and_then(Item::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use semver::Version;
+
+    use super::{Dependency, PythonManifest};
+
+    #[test]
+    fn test_project_manifest() {
+        let mut manifest = indoc::indoc! {r#"
+            [project]
+            name = "example"
+            version = "0.1"
+            dependencies = [
+                "example-one>=0.1.0",
+                "Example_Two[extra] (==0.2.0) ; python_version >= '3.8'",
+                "example-three @ file:///packages/example-three",
+                "requests",
+            ]
+
+            [project.optional-dependencies]
+            cli = ["example-four~=0.3.0"]
+
+            [build-system]
+            requires = ["hatchling>=1.0"]
+            build-backend = "hatchling.build"
+        "#}
+        .parse::<PythonManifest>()
+        .unwrap();
+
+        assert!(!manifest.is_poetry());
+        assert_eq!(manifest.name(), Some("example"));
+        assert_eq!(manifest.version(), Version::new(0, 1, 0));
+        assert_eq!(manifest.dependencies().into_iter().count(), 4);
+        assert_eq!(manifest.build_dependencies().into_iter().count(), 1);
+        assert_eq!(
+            manifest.optional_dependency_groups().collect::<Vec<_>>(),
+            ["cli"]
+        );
+
+        let two = manifest.get_dependency("example-two").unwrap();
+
+        assert_eq!(two.name(), "Example_Two");
+        assert_eq!(two.version(), Some("==0.2.0"));
+
+        let three = manifest.get_dependency("example-three").unwrap();
+
+        assert_eq!(three.version(), None);
+        assert_eq!(three.path(), Some("/packages/example-three"));
+
+        manifest.set_version(Version::new(0, 2, 0));
+        manifest
+            .get_dependency_mut("example-one")
+            .unwrap()
+            .set_version(Version::new(0, 2, 0));
+        manifest
+            .get_dependency_mut("example-two")
+            .unwrap()
+            .set_version(Version::new(0, 3, 0));
+        manifest
+            .optional_dependencies_mut("cli")
+            .get_mut("example-four")
+            .unwrap()
+            .set_version(Version::new(0, 4, 0));
+        manifest.add_dev_dependency(Dependency::new("pytest").with_version(Version::new(8, 0, 0)));
+
+        let expected = indoc::indoc! {r#"
+            [project]
+            name = "example"
+            version = "0.2.0"
+            dependencies = [
+                "example-one>=0.2.0",
+                "Example_Two[extra] (==0.3.0) ; python_version >= '3.8'",
+                "example-three @ file:///packages/example-three",
+                "requests",
+            ]
+
+            [project.optional-dependencies]
+            cli = ["example-four~=0.4.0"]
+
+            [build-system]
+            requires = ["hatchling>=1.0"]
+            build-backend = "hatchling.build"
+
+            [dependency-groups]
+            dev = ["pytest>=8.0.0"]
+        "#};
+
+        assert_eq!(manifest.to_string(), expected);
+    }
+
+    #[test]
+    fn test_poetry_manifest() {
+        let mut manifest = indoc::indoc! {r#"
+            [tool.poetry]
+            name = "example"
+            version = "0.1.0"
+            authors = ["Joe Bloggs <joe.bloggs@example.com>"]
+
+            [tool.poetry.dependencies]
+            python = "^3.8"
+            example-one = "^0.1.0"
+            example-two = { version = "~0.2.0", path = "../example-two" }
+
+            [tool.poetry.group.dev.dependencies]
+            pytest = "^8.0.0"
+        "#}
+        .parse::<PythonManifest>()
+        .unwrap();
+
+        assert!(manifest.is_poetry());
+        assert_eq!(manifest.name(), Some("example"));
+        assert_eq!(manifest.version(), Version::new(0, 1, 0));
+        assert_eq!(manifest.dependencies().into_iter().count(), 2);
+        assert_eq!(manifest.dev_dependencies().into_iter().count(), 1);
+        assert_eq!(
+            manifest.authors().unwrap().into_iter().collect::<Vec<_>>(),
+            ["Joe Bloggs <joe.bloggs@example.com>"]
+        );
+
+        let two = manifest.get_dependency("example-two").unwrap();
+
+        assert_eq!(two.version(), Some("~0.2.0"));
+        assert_eq!(two.path(), Some("../example-two"));
+
+        manifest.set_version(Version::new(0, 2, 0));
+        manifest
+            .get_dependency_mut("example-one")
+            .unwrap()
+            .set_version(Version::new(0, 2, 0));
+        manifest
+            .get_dependency_mut("example-two")
+            .unwrap()
+            .set_version(Version::new(0, 3, 0));
+
+        let expected = indoc::indoc! {r#"
+            [tool.poetry]
+            name = "example"
+            version = "0.2.0"
+            authors = ["Joe Bloggs <joe.bloggs@example.com>"]
+
+            [tool.poetry.dependencies]
+            python = "^3.8"
+            example-one = "^0.2.0"
+            example-two = { version = "~0.3.0", path = "../example-two" }
+
+            [tool.poetry.group.dev.dependencies]
+            pytest = "^8.0.0"
+        "#};
+
+        assert_eq!(manifest.to_string(), expected);
+    }
+
+    #[test]
+    fn test_members() {
+        let mut manifest = PythonManifest::new_workspace();
+
+        manifest.add_workspace_member("packages/*");
+        manifest.add_workspace_member("packages/example");
+        manifest.add_workspace_member("examples/example");
+
+        let members = manifest.members().unwrap();
+
+        assert!(members.includes(Path::new("packages/example")));
+        assert!(members.includes(Path::new("examples/example")));
+
+        let expected = indoc::indoc! {r#"
+            [tool.uv.workspace]
+            members = ["packages/*", "examples/example"]
+        "#};
+
+        assert_eq!(manifest.to_string(), expected);
+    }
+
+    #[test]
+    fn test_pep440_versions() {
+        let cases = [
+            ("1.0.0rc1", "1.0.0-rc.1", "1.0.0rc1"),
+            ("1.0a2", "1.0.0-alpha.2", "1.0.0a2"),
+            ("1.0.post1", "1.0.0+post.1", "1.0.0.post1"),
+            ("2024.1.0.dev3", "2024.1.0-dev.3", "2024.1.0.dev3"),
+            (
+                "1.0.0b1.post2.dev3",
+                "1.0.0-beta.1+post.2.dev.3",
+                "1.0.0b1.post2.dev3",
+            ),
+            ("0!1.2+ubuntu-1", "1.2.0+ubuntu.1", "1.2.0+ubuntu.1"),
+        ];
+
+        for (pep440, semver, formatted) in cases {
+            let mut manifest = format!("[project]\nname = \"example\"\nversion = \"{pep440}\"\n")
+                .parse::<PythonManifest>()
+                .unwrap();
+
+            let version = manifest.version();
+
+            assert_eq!(version, semver.parse::<Version>().unwrap(), "{pep440}");
+
+            manifest.set_version(version);
+
+            assert!(manifest.to_string().contains(formatted), "{pep440}");
+        }
+
+        assert!(
+            "[project]\nname = \"example\"\nversion = \"1!2.0\"\n"
+                .parse::<PythonManifest>()
+                .is_err()
+        );
+        assert!(
+            "[project]\nname = \"example\"\nversion = \"1.2.3.4\"\n"
+                .parse::<PythonManifest>()
+                .is_err()
+        );
+    }
+}
@@ -16,10 +16,11 @@ use bytes::Bytes;
 use either::Either;
 use relative_path::{RelativePath, RelativePathBuf};
 use semver::Version;
+use strum::IntoEnumIterator;
 use url::Url;
 
-use crate::changelog::Changelog;
-use crate::project::Project;
+use crate::changelog::{Change, Changelog, ChangelogFormat, Changeset, ChangesetFile};
+use crate::project::{Config, Project};
 use crate::repository::adapters::subdirectory::Subdirectory;
 use crate::repository::types::staging::Staging;
 use crate::repository::{Remote, RepoAddr, Repository, Stage};
@@ -29,13 +30,15 @@ pub use self::error::Error;
 pub use self::kind::PackageKind;
 pub use self::lockfile::Lockfile;
 pub use self::manifest::Manifest;
-use self::manifest::{Dependencies, DependenciesMut, DependencyMut, DependencyRef};
+use self::manifest::cargo::WorkspacePackage;
+use self::manifest::{CargoManifest, Dependencies, DependenciesMut, DependencyMut, DependencyRef};
 
 /// A project package.
 #[derive(Clone)]
 pub struct Package<T = Staging> {
     pub(crate) repository: Subdirectory<T>,
     manifest: Manifest,
+    workspace: Option<CargoManifest>,
     primary: bool,
 }
 
@@ -45,6 +48,39 @@ impl Package {
         Self {
             repository: Subdirectory::new_root(Staging::new()),
             manifest: Manifest::new_cargo(name),
+            workspace: None,
+            primary: false,
+        }
+    }
+
+    /// Constructs a new npm package.
+    pub fn new_npm(name: impl Into<String>) -> Self {
+        Self {
+            repository: Subdirectory::new_root(Staging::new()),
+            manifest: Manifest::new_npm(name),
+            workspace: None,
+            primary: false,
+        }
+    }
+
+    /// Constructs a new python package.
+    pub fn new_python(name: impl Into<String>) -> Self {
+        Self {
+            repository: Subdirectory::new_root(Staging::new()),
+            manifest: Manifest::new_python(name),
+            workspace: None,
+            primary: false,
+        }
+    }
+
+    /// Constructs a new go package.
+    ///
+    /// The go package is identified by its full module path.
+    pub fn new_go(path: impl Into<String>) -> Self {
+        Self {
+            repository: Subdirectory::new_root(Staging::new()),
+            manifest: Manifest::new_go(path),
+            workspace: None,
             primary: false,
         }
     }
@@ -55,13 +91,26 @@ impl<T> Package<T> {
     pub fn name(&self) -> &str {
         match self.manifest() {
             Manifest::Cargo(cargo) => cargo.package().expect("package").name(),
+            Manifest::Npm(npm) => npm.name().expect("name"),
+            Manifest::Python(python) => python.name().expect("name"),
+            Manifest::Go(go) => go.name().expect("name"),
         }
     }
 
     /// Gets the package description.
     pub fn description(&self) -> Option<&str> {
         match self.manifest() {
-            Manifest::Cargo(cargo) => cargo.package().expect("package").description(),
+            Manifest::Cargo(cargo) => {
+                let package = cargo.package().expect("package");
+
+                match package.is_inherited("description") {
+                    true => self.workspace_package()?.description(),
+                    false => package.description(),
+                }
+            }
+            Manifest::Npm(npm) => npm.description(),
+            Manifest::Python(python) => python.description(),
+            Manifest::Go(_) => None,
         }
     }
 
@@ -74,6 +123,13 @@ impl<T> Package<T> {
                     .expect("package")
                     .set_description(description);
             }
+            Manifest::Npm(npm) => {
+                npm.set_description(description);
+            }
+            Manifest::Python(python) => {
+                python.set_description(description);
+            }
+            Manifest::Go(_) => {}
         }
 
         self
@@ -88,15 +144,52 @@ impl<T> Package<T> {
     /// Gets the package version.
     pub fn version(&self) -> Version {
         match self.manifest() {
-            Manifest::Cargo(cargo) => cargo.package().expect("package").version(),
+            Manifest::Cargo(cargo) => {
+                let package = cargo.package().expect("package");
+
+                match package.is_inherited("version") {
+                    true => self
+                        .workspace_package()
+                        .and_then(|package| package.version())
+                        .unwrap_or(Version::new(0, 0, 0)),
+                    false => package.version(),
+                }
+            }
+            Manifest::Npm(npm) => npm.version(),
+            Manifest::Python(python) => python.version(),
+            Manifest::Go(go) => go.version(),
         }
     }
 
     /// Sets the package version.
     pub fn set_version(&mut self, version: impl Into<Version>) -> &mut Self {
-        match self.manifest_mut() {
-            Manifest::Cargo(cargo) => cargo.package_mut().expect("package").set_version(version),
-        };
+        match &mut self.manifest {
+            Manifest::Cargo(cargo) => {
+                let inherited = cargo.package().expect("package").is_inherited("version");
+
+                match (inherited, self.workspace.as_mut()) {
+                    (true, workspace) => {
+                        let workspace = workspace.unwrap_or(cargo);
+
+                        if let Some(mut package) = workspace.workspace_mut().package_mut() {
+                            package.set_version(version);
+                        }
+                    }
+                    (false, _) => {
+                        cargo.package_mut().expect("package").set_version(version);
+                    }
+                }
+            }
+            Manifest::Npm(npm) => {
+                npm.set_version(version);
+            }
+            Manifest::Python(python) => {
+                python.set_version(version);
+            }
+            Manifest::Go(go) => {
+                go.set_version(version);
+            }
+        }
 
         self
     }
@@ -120,7 +213,17 @@ impl<T> Package<T> {
     /// Gets the package repository.
     pub fn repository(&self) -> Option<Url> {
         match self.manifest() {
-            Manifest::Cargo(cargo) => cargo.package().expect("package").repository(),
+            Manifest::Cargo(cargo) => {
+                let package = cargo.package().expect("package");
+
+                match package.is_inherited("repository") {
+                    true => self.workspace_package()?.repository(),
+                    false => package.repository(),
+                }
+            }
+            Manifest::Npm(npm) => npm.repository(),
+            Manifest::Python(python) => python.repository(),
+            Manifest::Go(_) => None,
         }
     }
 
@@ -133,6 +236,13 @@ impl<T> Package<T> {
                     .expect("package")
                     .set_repository(repository);
             }
+            Manifest::Npm(npm) => {
+                npm.set_repository(repository);
+            }
+            Manifest::Python(python) => {
+                python.set_repository(repository);
+            }
+            Manifest::Go(_) => {}
         }
 
         self
@@ -147,7 +257,26 @@ impl<T> Package<T> {
     /// Gets the package authors.
     pub fn authors(&self) -> Option<impl IntoIterator<Item = &str>> {
         match self.manifest() {
-            Manifest::Cargo(cargo) => cargo.package().expect("package").authors(),
+            Manifest::Cargo(cargo) => {
+                let package = cargo.package().expect("package");
+
+                match package.is_inherited("authors") {
+                    true => self
+                        .workspace_package()?
+                        .authors()
+                        .map(|authors| Either::Left(Either::Left(authors.into_iter()))),
+                    false => package
+                        .authors()
+                        .map(|authors| Either::Left(Either::Right(authors.into_iter()))),
+                }
+            }
+            Manifest::Npm(npm) => npm
+                .authors()
+                .map(|authors| Either::Right(Either::Left(authors.into_iter()))),
+            Manifest::Python(python) => python
+                .authors()
+                .map(|authors| Either::Right(Either::Right(authors.into_iter()))),
+            Manifest::Go(_) => None,
         }
     }
 
@@ -157,6 +286,13 @@ impl<T> Package<T> {
             Manifest::Cargo(cargo) => {
                 cargo.package_mut().expect("package").add_author(author);
             }
+            Manifest::Npm(npm) => {
+                npm.add_author(author);
+            }
+            Manifest::Python(python) => {
+                python.add_author(author);
+            }
+            Manifest::Go(_) => {}
         }
 
         self
@@ -190,14 +326,65 @@ impl<T> Package<T> {
     pub fn is_primary(&self) -> bool {
         self.primary
     }
+
+    /// Gets the release tag prefix.
+    ///
+    /// Go modules are always tagged under the module directory as required by
+    /// the go toolchain, regardless of whether this is the primary package.
+    pub fn tag_prefix(&self) -> String {
+        match self.kind() {
+            PackageKind::Go if self.path().as_str().is_empty() => String::from("v"),
+            PackageKind::Go => format!("{}/v", self.path()),
+            _ if self.is_primary() => String::new(),
+            _ => format!("{}-", self.name()),
+        }
+    }
+
+    /// Gets the release tag name for the given version.
+    pub fn tag_name(&self, version: impl Borrow<Version>) -> String {
+        format!("{}{}", self.tag_prefix(), version.borrow())
+    }
+
+    /// Sets the package version to the latest of the given release tags.
+    pub(crate) fn set_tagged_version<'a>(&mut self, tags: impl IntoIterator<Item = &'a str>) {
+        let prefix = self.tag_prefix();
+        let version = tags
+            .into_iter()
+            .filter_map(|tag| tag.strip_prefix(&prefix)?.parse::<Version>().ok())
+            .max();
+
+        if let Some(version) = version {
+            self.set_version(version);
+        }
+    }
 }
 
 impl<T> Package<T> {
+    /// Gets the workspace package table that a cargo package inherits from.
+    ///
+    /// This is the root manifest for workspace members and the package manifest
+    /// itself for the root package.
+    fn workspace_package(&self) -> Option<WorkspacePackage<'_>> {
+        self.workspace
+            .as_ref()
+            .or_else(|| self.manifest.try_as_cargo_ref())?
+            .workspace()?
+            .package()
+    }
+
     /// Gets the package manifest.
     pub fn manifest(&self) -> &Manifest {
         &self.manifest
     }
 
+    /// Gets the cargo workspace manifest that the package inherits from.
+    ///
+    /// This is only set for cargo workspace members and includes any changes to
+    /// inherited fields such as the version.
+    pub fn workspace_manifest(&self) -> Option<&CargoManifest> {
+        self.workspace.as_ref()
+    }
+
     /// Gets the mutable package manifest.
     ///
     /// Note that replacing the manifest with another kind is a logic error and
@@ -213,8 +400,58 @@ where
     T: Repository,
 {
     /// Gets the package changelog.
+    ///
+    /// This reads the first changelog file that exists in any of the supported
+    /// formats, such as `CHANGELOG.md`, `CHANGELOG.adoc` or `CHANGES.rst`.
     pub fn changelog(&self) -> Option<Changelog> {
-        self.get_file_as("CHANGELOG.md").ok().flatten()
+        let (path, format) = self.changelog_file()?;
+        let bytes = self.get_file(path).ok()??;
+        let text = std::str::from_utf8(&bytes).ok()?;
+
+        Some(Changelog::from_format(text, format))
+    }
+
+    /// Gets the package changelog file name and format.
+    ///
+    /// This is `None` when the package does not have a changelog file.
+    pub fn changelog_file(&self) -> Option<(&'static str, ChangelogFormat)> {
+        ChangelogFormat::iter().find_map(|format| {
+            format
+                .file_names()
+                .iter()
+                .find(|name| matches!(self.get_file(name), Ok(Some(_))))
+                .map(|name| (*name, format))
+        })
+    }
+
+    /// Gets the changeset files that include the package.
+    ///
+    /// These are the markdown files in the `.changeset` directory at the root
+    /// of the repository, excluding the `README.md` file. Files that cannot be
+    /// read or parsed are ignored.
+    pub fn changeset_files(&self) -> Vec<(RelativePathBuf, ChangesetFile)> {
+        let repository = self.repository.inner();
+        let Ok(index) = repository.get_index() else {
+            return Vec::new();
+        };
+
+        let paths = index
+            .filter(|path| path.parent() == Some(RelativePath::new(".changeset")))
+            .filter(|path| path.extension() == Some("md") && path.file_name() != Some("README.md"))
+            .map(|path| path.to_relative_path_buf())
+            .collect::<Vec<_>>();
+
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let bytes = repository.get_file(&path).ok()??;
+                let file = ChangesetFile::from_bytes(&bytes).ok()?;
+
+                file.get_package(self.name())?;
+
+                Some((path, file))
+            })
+            .collect()
     }
 }
 
@@ -285,7 +522,7 @@ where
 impl<T> Package<T> {
     /// Gets the dependency with the given name.
     pub fn get_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
-        self.manifest().get_dependency(name)
+        self.dependencies().get(name)
     }
 
     /// Gets the mutable dependency with the given name.
@@ -295,7 +532,14 @@ impl<T> Package<T> {
 
     /// Gets the dependencies.
     pub fn dependencies(&self) -> Dependencies<'_> {
-        self.manifest().dependencies()
+        match (self.manifest(), &self.workspace) {
+            (Manifest::Cargo(cargo), Some(workspace)) => Dependencies::Cargo(
+                cargo
+                    .dependencies()
+                    .with_workspace(workspace.workspace_dependencies()),
+            ),
+            (manifest, _) => manifest.dependencies(),
+        }
     }
 
     /// Gets the mutable dependencies.
@@ -307,7 +551,7 @@ impl<T> Package<T> {
 impl<T> Package<T> {
     /// Gets the dev dependency with the given name.
     pub fn get_dev_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
-        self.manifest().get_dev_dependency(name)
+        self.dev_dependencies().get(name)
     }
 
     /// Gets the mutable dev dependency with the given name.
@@ -317,7 +561,14 @@ impl<T> Package<T> {
 
     /// Gets the dev dependencies.
     pub fn dev_dependencies(&self) -> Dependencies<'_> {
-        self.manifest().dev_dependencies()
+        match (self.manifest(), &self.workspace) {
+            (Manifest::Cargo(cargo), Some(workspace)) => Dependencies::Cargo(
+                cargo
+                    .dev_dependencies()
+                    .with_workspace(workspace.workspace_dependencies()),
+            ),
+            (manifest, _) => manifest.dev_dependencies(),
+        }
     }
 
     /// Gets the mutable dev dependencies.
@@ -329,7 +580,7 @@ impl<T> Package<T> {
 impl<T> Package<T> {
     /// Gets the build dependency with the given name.
     pub fn get_build_dependency(&self, name: impl AsRef<str>) -> Option<DependencyRef<'_>> {
-        self.manifest().get_build_dependency(name)
+        self.build_dependencies().get(name)
     }
 
     /// Gets the mutable build dependency with the given name.
@@ -339,7 +590,14 @@ impl<T> Package<T> {
 
     /// Gets the build dependencies.
     pub fn build_dependencies(&self) -> Dependencies<'_> {
-        self.manifest().build_dependencies()
+        match (self.manifest(), &self.workspace) {
+            (Manifest::Cargo(cargo), Some(workspace)) => Dependencies::Cargo(
+                cargo
+                    .build_dependencies()
+                    .with_workspace(workspace.workspace_dependencies()),
+            ),
+            (manifest, _) => manifest.build_dependencies(),
+        }
     }
 
     /// Gets the mutable build dependencies.
@@ -368,6 +626,7 @@ where
         Package {
             repository: self.repository.detached(),
             manifest: self.manifest,
+            workspace: self.workspace,
             primary: self.primary,
         }
     }
@@ -393,6 +652,7 @@ where
         Package {
             repository: self.repository.detached(),
             manifest: self.manifest,
+            workspace: self.workspace,
             primary: self.primary,
         }
     }
@@ -410,15 +670,116 @@ where
     ///
     /// It does not yet support parallel release or hotfix branches and expects
     /// all development to be on the default branch in the repository settings.
+    ///
+    /// Changes are grouped into changesets using the label mapping from the
+    /// project configuration, or the default mapping if it cannot be loaded.
     pub fn build_release_notes(
         &self,
         version: impl Borrow<Version>,
     ) -> Result<crate::changelog::Release, T::Error> {
-        self.repository.inner().get_changelog_release(
+        let labels = self
+            .repository
+            .inner()
+            .get_file("Ploys.toml")
+            .ok()
+            .flatten()
+            .and_then(|bytes| Config::from_bytes(&bytes).ok())
+            .map(|config| config.changelog_labels())
+            .unwrap_or_default();
+
+        let mut release = self.repository.inner().get_changelog_release(
             self.name(),
             version.borrow(),
-            self.is_primary(),
-        )
+            &self.tag_prefix(),
+            &labels,
+        )?;
+
+        let tag = self.tag_name(version.borrow());
+
+        if !self.repository.inner().get_tags()?.contains(&tag) {
+            for (_, file) in self.changeset_files() {
+                let change = Change::new(file.summary());
+
+                match release.get_changeset_mut("Changed") {
+                    Some(changeset) => {
+                        changeset.add_change(change);
+                    }
+                    None => {
+                        release.add_changeset(Changeset::changed().with_change(change));
+                        release.sort_changesets();
+                    }
+                }
+            }
+        }
+
+        Ok(release)
+    }
+
+    /// Infers the version bump from the changes since the latest release.
+    ///
+    /// This uses the highest bump level from the changeset files that include
+    /// the package. Otherwise, see [`Bump::infer`] for how the Conventional
+    /// Commits messages of the unreleased changes determine the bump.
+    pub fn infer_bump(&self) -> Result<Bump, T::Error> {
+        let bump = self
+            .changeset_files()
+            .iter()
+            .filter_map(|(_, file)| file.get_package(self.name()))
+            .min_by_key(|bump| match bump {
+                Bump::Major => 0,
+                Bump::Minor => 1,
+                _ => 2,
+            });
+
+        if let Some(bump) = bump {
+            return Ok(bump);
+        }
+
+        let messages = self
+            .repository
+            .inner()
+            .get_unreleased_changes(self.name(), &self.tag_prefix())?;
+
+        Ok(Bump::infer(messages, &self.version()))
+    }
+
+    /// Gets the tagged version that precedes the given version.
+    ///
+    /// Stable versions are preceded by the previous stable version so that the
+    /// prereleases in between are skipped.
+    pub fn previous_version(
+        &self,
+        version: impl Borrow<Version>,
+    ) -> Result<Option<Version>, T::Error> {
+        let version = version.borrow();
+        let prefix = self.tag_prefix();
+
+        Ok(self
+            .repository
+            .inner()
+            .get_tags()?
+            .iter()
+            .filter_map(|tag| tag.strip_prefix(&prefix)?.parse::<Version>().ok())
+            .filter(|previous| previous < version)
+            .filter(|previous| !version.pre.is_empty() || previous.pre.is_empty())
+            .max())
+    }
+
+    /// Loads the package version from the release tags.
+    ///
+    /// This sets the version of packages such as Go modules that are only
+    /// versioned by tags to the latest tagged release and has no effect on
+    /// packages that declare a version in the manifest.
+    pub fn load_tagged_version(&mut self) -> Result<&mut Self, T::Error> {
+        if self.kind() != PackageKind::Go {
+            return Ok(self);
+        }
+
+        let tags = self.repository.inner().get_tags()?;
+
+        self.set_tagged_version(tags.iter().map(String::as_str));
+
+        Ok(self)
     }
 }
 
@@ -427,11 +788,16 @@ where
     T: Repository,
 {
     /// Constructs a package from a manifest.
-    pub(super) fn from_manifest(
-        project: &Project<T>,
+    ///
+    /// The workspace manifest is the root manifest that the package was found
+    /// from and is used to resolve inherited cargo package fields.
+    pub(super) fn from_manifest<'a>(
+        project: &'a Project<T>,
         path: impl Into<RelativePathBuf>,
         manifest: Manifest,
-    ) -> Option<Package<&T>> {
+        workspace: &Manifest,
+    ) -> Option<Package<&'a T>> {
+        let path = path.into();
         let kind = manifest.package_kind();
         let primary = match kind {
             PackageKind::Cargo => {
@@ -439,13 +805,28 @@ where
 
                 pkg.name() == project.name()
             }
+            PackageKind::Npm => manifest.try_as_npm_ref()?.name()? == project.name(),
+            PackageKind::Python => manifest.try_as_python_ref()?.name()? == project.name(),
+            PackageKind::Go => manifest.try_as_go_ref()?.name()? == project.name(),
         };
 
-        Some(Package {
-            repository: Subdirectory::new_unvalidated(&project.repository, path.into()),
+        let workspace = match path.as_str().is_empty() {
+            true => None,
+            false => workspace.try_as_cargo_ref().cloned(),
+        };
+
+        let mut package = Package {
+            repository: Subdirectory::new_unvalidated(&project.repository, path),
             manifest: manifest.clone(),
+            workspace,
             primary,
-        })
+        };
+
+        if kind == PackageKind::Go {
+            package.set_tagged_version(project.tags.iter().map(String::as_str));
+        }
+
+        Some(package)
     }
 }
 
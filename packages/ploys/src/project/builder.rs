@@ -57,7 +57,7 @@ impl Builder {
 
         self.commit_config(&repo, &branch, config)?;
 
-        Ok(Project::open(repo)?)
+        Ok(Project::open(repo)?.with_tags()?)
     }
 }
 
@@ -79,9 +79,10 @@ impl Builder {
     /// single file per commit, and the git commits endpoint only signs when
     /// using an App installation access token.
     fn init_repo(&self, credentials: &Credentials) -> Result<(GitHub, String), ClientError> {
+        let api_url = self.client.github().api_url();
         let url = match self.repo.owner() == credentials.user() {
-            true => String::from("https://api.github.com/user/repos"),
-            false => format!("https://api.github.com/orgs/{}/repos", self.repo.owner()),
+            true => format!("{api_url}/user/repos"),
+            false => format!("{api_url}/orgs/{}/repos", self.repo.owner()),
         };
 
         self.client
@@ -139,7 +140,7 @@ impl Builder {
         branch: &str,
         config: Config,
     ) -> Result<(), ClientError> {
-        let files = vec![("Ploys.toml".into(), config.to_string())];
+        let files = vec![("Ploys.toml".into(), Some(config.to_string()))];
 
         repo.commit_branch(branch, "Add project configuration", files)
             .map_err(ProjError::Repository)?;
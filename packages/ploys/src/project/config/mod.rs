@@ -9,8 +9,12 @@ mod project;
 use std::fmt::{self, Display};
 use std::str::FromStr;
 
-use toml_edit::{DocumentMut, Item, Table, value};
+use relative_path::RelativePathBuf;
+use strum::IntoEnumIterator;
+use toml_edit::{DocumentMut, Item, Table, TableLike, value};
 
+use crate::changelog::{ChangelogGrouping, LabelMapping};
+use crate::project::{TemplateKind, Templates};
 use crate::repository::RepoAddr;
 
 pub use self::error::Error;
@@ -94,6 +98,157 @@ impl Config {
         self
     }
 
+    /// Gets the changelog label mapping.
+    ///
+    /// This is read from the `[changelog.labels]` table which maps each change
+    /// label to a changeset label, falling back to the default mapping when the
+    /// table does not exist.
+    pub fn changelog_labels(&self) -> LabelMapping {
+        let Some(table) = self
+            .0
+            .get("changelog")
+            .and_then(|changelog| changelog.get("labels"))
+            .and_then(Item::as_table_like)
+        else {
+            return LabelMapping::default();
+        };
+
+        table
+            .iter()
+            .filter_map(|(label, item)| Some((label, item.as_str()?)))
+            .fold(LabelMapping::new(), |mapping, (label, changeset)| {
+                mapping.with_label(label, changeset)
+            })
+    }
+
+    /// Sets the changelog label mapping.
+    pub fn set_changelog_labels(&mut self, mapping: LabelMapping) -> &mut Self {
+        let changelog = self.changelog_table_mut();
+
+        let mut labels = Table::new();
+
+        for (label, changeset) in mapping.labels() {
+            labels.insert(label, value(changeset));
+        }
+
+        changelog.insert("labels", Item::Table(labels));
+
+        self
+    }
+
+    /// Builds the config with the given changelog label mapping.
+    pub fn with_changelog_labels(mut self, mapping: LabelMapping) -> Self {
+        self.set_changelog_labels(mapping);
+        self
+    }
+
+    /// Gets the project changelog path.
+    ///
+    /// This is read from the `[changelog.project]` table and defaults to the
+    /// `CHANGELOG.md` file at the root of the repository. The project changelog
+    /// is disabled when the table does not exist.
+    pub fn project_changelog_path(&self) -> Option<RelativePathBuf> {
+        let table = self.project_changelog_table()?;
+        let path = table
+            .get("path")
+            .and_then(Item::as_str)
+            .unwrap_or("CHANGELOG.md");
+
+        Some(RelativePathBuf::from(path))
+    }
+
+    /// Gets the project changelog grouping.
+    ///
+    /// This is read from the `group` field of the `[changelog.project]` table
+    /// and defaults to grouping by date.
+    pub fn project_changelog_grouping(&self) -> ChangelogGrouping {
+        self.project_changelog_table()
+            .and_then(|table| table.get("group"))
+            .and_then(Item::as_str)
+            .and_then(|group| group.parse().ok())
+            .unwrap_or_default()
+    }
+
+    /// Sets the project changelog path and grouping.
+    pub fn set_project_changelog(
+        &mut self,
+        path: impl Into<RelativePathBuf>,
+        grouping: ChangelogGrouping,
+    ) -> &mut Self {
+        let changelog = self.changelog_table_mut();
+        let mut table = Table::new();
+
+        table.insert("path", value(path.into().as_str()));
+        table.insert("group", value(grouping.to_string()));
+
+        changelog.insert("project", Item::Table(table));
+
+        self
+    }
+
+    /// Builds the config with the given project changelog path and grouping.
+    pub fn with_project_changelog(
+        mut self,
+        path: impl Into<RelativePathBuf>,
+        grouping: ChangelogGrouping,
+    ) -> Self {
+        self.set_project_changelog(path, grouping);
+        self
+    }
+
+    /// Gets the release templates.
+    ///
+    /// This is read from the `[release.templates]` table which maps each
+    /// template name, such as `pull-request-title`, to the template source.
+    pub fn release_templates(&self) -> Templates {
+        let Some(table) = self
+            .0
+            .get("release")
+            .and_then(|release| release.get("templates"))
+            .and_then(Item::as_table_like)
+        else {
+            return Templates::new();
+        };
+
+        TemplateKind::iter()
+            .filter_map(|kind| Some((kind, table.get(kind.name())?.as_str()?)))
+            .fold(Templates::new(), |templates, (kind, template)| {
+                templates.with_template(kind, template)
+            })
+    }
+
+    /// Sets the release templates.
+    pub fn set_release_templates(&mut self, templates: Templates) -> &mut Self {
+        let release = self
+            .0
+            .entry("release")
+            .or_insert_with(|| {
+                let mut table = Table::new();
+
+                table.set_implicit(true);
+
+                Item::Table(table)
+            })
+            .as_table_like_mut()
+            .expect("release table");
+
+        let mut table = Table::new();
+
+        for (kind, template) in templates.templates() {
+            table.insert(kind.name(), value(template));
+        }
+
+        release.insert("templates", Item::Table(table));
+
+        self
+    }
+
+    /// Builds the config with the given release templates.
+    pub fn with_release_templates(mut self, templates: Templates) -> Self {
+        self.set_release_templates(templates);
+        self
+    }
+
     /// The project section.
     pub fn project(&self) -> Project<'_> {
         Project::from_table(
@@ -118,6 +273,29 @@ impl Config {
 }
 
 impl Config {
+    /// Gets the project changelog table.
+    fn project_changelog_table(&self) -> Option<&dyn TableLike> {
+        self.0
+            .get("changelog")
+            .and_then(|changelog| changelog.get("project"))
+            .and_then(Item::as_table_like)
+    }
+
+    /// Gets the mutable changelog table, inserting it if it does not exist.
+    fn changelog_table_mut(&mut self) -> &mut dyn TableLike {
+        self.0
+            .entry("changelog")
+            .or_insert_with(|| {
+                let mut table = Table::new();
+
+                table.set_implicit(true);
+
+                Item::Table(table)
+            })
+            .as_table_like_mut()
+            .expect("changelog table")
+    }
+
     /// Constructs config from the given bytes.
     pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
         std::str::from_utf8(bytes)?.parse()
@@ -158,6 +336,8 @@ impl FromStr for Config {
 
 #[cfg(test)]
 mod tests {
+    use crate::changelog::{ChangelogGrouping, LabelMapping};
+    use crate::project::{TemplateKind, Templates};
     use crate::repository::RepoAddr;
 
     use super::Config;
@@ -184,4 +364,118 @@ mod tests {
 
         assert_eq!(config.to_string(), expected);
     }
+
+    #[test]
+    fn test_changelog_labels() {
+        let config = Config::new("example");
+
+        assert_eq!(config.changelog_labels(), LabelMapping::default());
+
+        let config = indoc::indoc! {r#"
+            [project]
+            name = "example"
+
+            [changelog.labels]
+            "type: feature" = "Added"
+            "type: bug" = "Fixed"
+            "type: docs" = "Documentation"
+        "#}
+        .parse::<Config>()
+        .unwrap();
+
+        let labels = config.changelog_labels();
+
+        assert_eq!(labels.get_changeset_label(["type: bug"]), "Fixed");
+        assert_eq!(labels.get_changeset_label(["type: fix"]), "Changed");
+        assert_eq!(labels.labels().count(), 3);
+
+        let config = Config::new("example")
+            .with_changelog_labels(LabelMapping::new().with_label("type: bug", "Fixed"));
+
+        let expected = indoc::indoc! {r#"
+            [project]
+            name = "example"
+
+            [changelog.labels]
+            "type: bug" = "Fixed"
+        "#};
+
+        assert_eq!(config.to_string(), expected);
+    }
+
+    #[test]
+    fn test_release_templates() {
+        let config = indoc::indoc! {r#"
+            [project]
+            name = "example"
+
+            [release.templates]
+            branch = "release/{{ package }}-{{ version }}"
+            pull-request-title = "Release {{ package }} {{ version }}"
+        "#}
+        .parse::<Config>()
+        .unwrap();
+
+        let templates = config.release_templates();
+
+        assert_eq!(
+            templates.get_template(TemplateKind::Branch),
+            Some("release/{{ package }}-{{ version }}")
+        );
+        assert_eq!(templates.get_template(TemplateKind::ReleaseBody), None);
+        assert_eq!(templates.templates().count(), 2);
+
+        let config = Config::new("example").with_release_templates(
+            Templates::new().with_template(TemplateKind::ReleaseName, "{{ version }}"),
+        );
+
+        let expected = indoc::indoc! {r#"
+            [project]
+            name = "example"
+
+            [release.templates]
+            release-name = "{{ version }}"
+        "#};
+
+        assert_eq!(config.to_string(), expected);
+        assert_eq!(Config::new("example").release_templates(), Templates::new());
+    }
+
+    #[test]
+    fn test_project_changelog() {
+        let config = Config::new("example");
+
+        assert_eq!(config.project_changelog_path(), None);
+        assert_eq!(config.project_changelog_grouping(), ChangelogGrouping::Date);
+
+        let config = indoc::indoc! {r#"
+            [project]
+            name = "example"
+
+            [changelog.project]
+            group = "release-train"
+        "#}
+        .parse::<Config>()
+        .unwrap();
+
+        assert_eq!(config.project_changelog_path().unwrap(), "CHANGELOG.md");
+        assert_eq!(
+            config.project_changelog_grouping(),
+            ChangelogGrouping::ReleaseTrain
+        );
+
+        let config = Config::new("example")
+            .with_project_changelog("docs/CHANGELOG.adoc", ChangelogGrouping::Date);
+
+        let expected = indoc::indoc! {r#"
+            [project]
+            name = "example"
+
+            [changelog.project]
+            path = "docs/CHANGELOG.adoc"
+            group = "date"
+        "#};
+
+        assert_eq!(config.to_string(), expected);
+    }
 }
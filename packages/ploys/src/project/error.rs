@@ -12,6 +12,10 @@ pub enum Error<T = crate::repository::types::staging::Error> {
     Repository(T),
     /// The package error.
     Package(crate::package::Error<T>),
+    /// The dependency graph error.
+    Graph(super::graph::Error),
+    /// The release template error.
+    Template(super::TemplateError),
     /// A UTF-8 error.
     Utf8(std::str::Utf8Error),
 }
@@ -26,6 +30,8 @@ where
             Self::Config(err) => Display::fmt(err, f),
             Self::Repository(err) => Display::fmt(err, f),
             Self::Package(err) => Display::fmt(err, f),
+            Self::Graph(err) => Display::fmt(err, f),
+            Self::Template(err) => Display::fmt(err, f),
             Self::Utf8(err) => Display::fmt(err, f),
         }
     }
@@ -41,6 +47,8 @@ where
             Self::Config(err) => Some(err),
             Self::Repository(err) => Some(err),
             Self::Package(err) => Some(err),
+            Self::Graph(err) => Some(err),
+            Self::Template(err) => Some(err),
             Self::Utf8(err) => Some(err),
         }
     }
@@ -70,6 +78,18 @@ impl<T> From<crate::package::Error<T>> for Error<T> {
     }
 }
 
+impl<T> From<super::graph::Error> for Error<T> {
+    fn from(err: super::graph::Error) -> Self {
+        Self::Graph(err)
+    }
+}
+
+impl<T> From<super::TemplateError> for Error<T> {
+    fn from(err: super::TemplateError) -> Self {
+        Self::Template(err)
+    }
+}
+
 impl<T> From<crate::package::BumpError> for Error<T> {
     fn from(err: crate::package::BumpError) -> Self {
         Self::Package(err.into())
@@ -0,0 +1,303 @@
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{self, Display};
+
+use crate::package::{Manifest, Package};
+
+/// The package dependency graph.
+///
+/// This describes the dependencies between packages in the same project and
+/// ignores any dependencies on packages from elsewhere. Dependencies on local
+/// packages are found by name, or by module path for Go modules.
+///
+/// Development dependencies are left out as they are only needed to test the
+/// package and may form cycles without affecting the release order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DependencyGraph {
+    packages: Vec<String>,
+    dependencies: Vec<BTreeSet<usize>>,
+}
+
+impl DependencyGraph {
+    /// Constructs a new dependency graph from the given packages.
+    pub(super) fn new<T>(packages: impl IntoIterator<Item = Package<T>>) -> Self {
+        let packages = packages.into_iter().collect::<Vec<_>>();
+        let mut lookup = HashMap::new();
+
+        for (index, package) in packages.iter().enumerate() {
+            lookup.insert(package.name(), index);
+
+            if let Manifest::Go(go) = package.manifest()
+                && let Some(path) = go.module_path()
+            {
+                lookup.insert(path, index);
+            }
+        }
+
+        let dependencies = packages
+            .iter()
+            .enumerate()
+            .map(|(index, package)| {
+                package
+                    .dependencies()
+                    .into_iter()
+                    .chain(package.build_dependencies())
+                    .filter_map(|dependency| lookup.get(dependency.name()).copied())
+                    .filter(|dependency| *dependency != index)
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            packages: packages
+                .iter()
+                .map(|package| package.name().to_owned())
+                .collect(),
+            dependencies,
+        }
+    }
+
+    /// Gets an iterator over the package names.
+    pub fn packages(&self) -> impl Iterator<Item = &str> {
+        self.packages.iter().map(String::as_str)
+    }
+
+    /// Gets an iterator over the local dependencies of the given package.
+    pub fn dependencies(&self, package: impl AsRef<str>) -> impl Iterator<Item = &str> {
+        self.index(package.as_ref())
+            .into_iter()
+            .flat_map(|index| &self.dependencies[index])
+            .map(|index| self.packages[*index].as_str())
+    }
+
+    /// Gets an iterator over the local dependents of the given package.
+    pub fn dependents(&self, package: impl AsRef<str>) -> impl Iterator<Item = &str> {
+        let index = self.index(package.as_ref());
+
+        self.dependencies
+            .iter()
+            .enumerate()
+            .filter(move |(_, dependencies)| {
+                index.is_some_and(|index| dependencies.contains(&index))
+            })
+            .map(|(dependent, _)| self.packages[dependent].as_str())
+    }
+
+    /// Gets the packages in topological order.
+    ///
+    /// Each package is preceded by its dependencies which makes this suitable
+    /// as a release order. Packages without a dependency relationship keep the
+    /// order in which they were found.
+    pub fn topological_order(&self) -> Result<Vec<&str>, Error> {
+        let mut states = vec![State::Unvisited; self.packages.len()];
+        let mut order = Vec::with_capacity(self.packages.len());
+        let mut path = Vec::new();
+
+        for index in 0..self.packages.len() {
+            self.visit(index, &mut states, &mut path, &mut order)?;
+        }
+
+        Ok(order
+            .into_iter()
+            .map(|index| self.packages[index].as_str())
+            .collect())
+    }
+
+    /// Visits the package and its dependencies in depth-first order.
+    fn visit(
+        &self,
+        index: usize,
+        states: &mut [State],
+        path: &mut Vec<usize>,
+        order: &mut Vec<usize>,
+    ) -> Result<(), Error> {
+        match states[index] {
+            State::Visited => return Ok(()),
+            State::Visiting => {
+                let start = path.iter().position(|entry| *entry == index).unwrap_or(0);
+                let cycle = path[start..]
+                    .iter()
+                    .chain([&index])
+                    .map(|index| self.packages[*index].clone())
+                    .collect();
+
+                return Err(Error::Cycle(cycle));
+            }
+            State::Unvisited => {}
+        }
+
+        states[index] = State::Visiting;
+        path.push(index);
+
+        for dependency in &self.dependencies[index] {
+            self.visit(*dependency, states, path, order)?;
+        }
+
+        path.pop();
+        states[index] = State::Visited;
+        order.push(index);
+
+        Ok(())
+    }
+
+    /// Gets the index of the given package.
+    fn index(&self, package: &str) -> Option<usize> {
+        self.packages.iter().position(|name| name == package)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    Unvisited,
+    Visiting,
+    Visited,
+}
+
+/// The dependency graph error.
+#[derive(Debug)]
+pub enum Error {
+    /// A dependency cycle between the given packages.
+    Cycle(Vec<String>),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cycle(packages) => {
+                let cycle = packages
+                    .iter()
+                    .map(|package| format!("`{package}`"))
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+
+                write!(f, "Dependency cycle: {cycle}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use semver::Version;
+
+    use crate::package::Package;
+    use crate::package::manifest::cargo::Dependency;
+    use crate::project::Project;
+
+    use super::Error;
+
+    /// Creates a Cargo package with the given local dependencies.
+    pub(crate) fn package(name: &str, version: Version, dependencies: &[&str]) -> Package {
+        let mut package = Package::new_cargo(name).with_version(version);
+        let manifest = package.manifest_mut().try_as_cargo_mut().unwrap();
+
+        for dependency in dependencies {
+            manifest.add_dependency(
+                Dependency::new(*dependency)
+                    .with_version(Version::new(0, 1, 0))
+                    .with_path(format!("../{dependency}")),
+            );
+        }
+
+        package
+    }
+
+    #[test]
+    fn test_dependency_graph() {
+        let project = Project::new("example")
+            .with_package(package(
+                "example-one",
+                Version::new(0, 1, 0),
+                &["example-two", "serde"],
+            ))
+            .unwrap()
+            .with_package(package("example-two", Version::new(0, 1, 0), &[]))
+            .unwrap()
+            .with_package(package(
+                "example-three",
+                Version::new(0, 1, 0),
+                &["example-one", "example-two"],
+            ))
+            .unwrap();
+
+        let graph = project.dependency_graph();
+
+        assert_eq!(
+            graph.dependencies("example-one").collect::<Vec<_>>(),
+            ["example-two"]
+        );
+        assert_eq!(
+            graph.dependents("example-two").collect::<Vec<_>>(),
+            ["example-one", "example-three"]
+        );
+        assert_eq!(graph.dependents("example-three").count(), 0);
+        assert_eq!(graph.dependencies("serde").count(), 0);
+
+        let order = graph.topological_order().unwrap();
+        let position = |name| order.iter().position(|entry| *entry == name).unwrap();
+
+        assert_eq!(order.len(), 3);
+        assert!(position("example-two") < position("example-one"));
+        assert!(position("example-one") < position("example-three"));
+    }
+
+    #[test]
+    fn test_dependency_graph_cycle() {
+        let project = Project::new("example")
+            .with_package(package(
+                "example-one",
+                Version::new(0, 1, 0),
+                &["example-two"],
+            ))
+            .unwrap()
+            .with_package(package(
+                "example-two",
+                Version::new(0, 1, 0),
+                &["example-one"],
+            ))
+            .unwrap();
+
+        let Err(Error::Cycle(cycle)) = project.dependency_graph().topological_order() else {
+            panic!("expected cycle");
+        };
+
+        assert_eq!(cycle.len(), 3);
+        assert_eq!(cycle.first(), cycle.last());
+        assert!(
+            Error::Cycle(cycle)
+                .to_string()
+                .starts_with("Dependency cycle: `example-")
+        );
+    }
+
+    #[test]
+    fn test_dependency_graph_dev_cycle() {
+        let mut one = package("example-one", Version::new(0, 1, 0), &[]);
+        let mut two = package("example-two", Version::new(0, 1, 0), &["example-one"]);
+
+        for (package, dependency) in [(&mut one, "example-two"), (&mut two, "example-one")] {
+            package
+                .manifest_mut()
+                .try_as_cargo_mut()
+                .unwrap()
+                .add_dev_dependency(
+                    Dependency::new(dependency).with_path(format!("../{dependency}")),
+                );
+        }
+
+        let project = Project::new("example")
+            .with_package(one)
+            .unwrap()
+            .with_package(two)
+            .unwrap();
+
+        let graph = project.dependency_graph();
+
+        assert_eq!(graph.dependencies("example-one").count(), 0);
+        assert_eq!(
+            graph.topological_order().unwrap(),
+            ["example-one", "example-two"]
+        );
+    }
+}
@@ -2,12 +2,13 @@
 //!
 //! This module includes utilities for inspecting and managing projects located
 //! in one of several supported formats including a local Git repository and a
-//! remote GitHub repository.
+//! remote GitHub, GitLab or Gitea repository.
 
 pub mod config;
 
 mod builder;
 mod error;
+mod graph;
 mod packages;
 mod release;
 
@@ -17,8 +18,9 @@ use bytes::Bytes;
 use either::Either;
 use relative_path::{RelativePath, RelativePathBuf};
 
+use crate::changelog::{Changelog, ChangelogFormat, LabelMapping};
 use crate::package::lockfile::CargoLockfile;
-use crate::package::manifest::CargoManifest;
+use crate::package::manifest::{CargoManifest, GoManifest, NpmManifest, PythonManifest};
 use crate::package::{BumpOrVersion, Package, PackageKind};
 use crate::repository::types::staging::Staging;
 use crate::repository::{Remote, RepoAddr, Repository, Stage};
@@ -26,8 +28,12 @@ use crate::repository::{Remote, RepoAddr, Repository, Stage};
 pub use self::builder::Builder;
 pub use self::config::Config;
 pub use self::error::Error;
+pub use self::graph::{DependencyGraph, Error as GraphError};
 pub use self::packages::Packages;
-pub use self::release::{ReleaseBuilder, ReleaseRequest, ReleaseRequestBuilder};
+pub use self::release::{
+    FileChange, ReleaseBuilder, ReleasePlan, ReleaseRequest, ReleaseRequestBuilder, TemplateError,
+    TemplateKind, Templates,
+};
 
 /// A project from one of several supported repositories.
 ///
@@ -43,6 +49,7 @@ pub use self::release::{ReleaseBuilder, ReleaseRequest, ReleaseRequestBuilder};
 pub struct Project<T = Staging> {
     pub(crate) repository: T,
     config: Config,
+    pub(crate) tags: Vec<String>,
 }
 
 impl Project {
@@ -55,6 +62,7 @@ impl Project {
                 .with_file("Ploys.toml", config.to_string().into_bytes())
                 .expect("infallible"),
             config,
+            tags: Vec::new(),
         }
     }
 }
@@ -73,6 +81,7 @@ where
         Ok(Self {
             config: Config::from_bytes(&config)?,
             repository,
+            tags: Vec::new(),
         })
     }
 }
@@ -137,6 +146,11 @@ impl<T> Project<T> {
         self.set_authors(authors);
         self
     }
+
+    /// Gets the changelog label mapping.
+    pub fn changelog_labels(&self) -> LabelMapping {
+        self.config.changelog_labels()
+    }
 }
 
 impl<T> Project<T>
@@ -262,6 +276,54 @@ where
                 self.add_file("Cargo.toml", manifest.to_string().into_bytes())?;
                 self.add_file("Cargo.lock", lockfile.to_string().into_bytes())?;
             }
+            PackageKind::Npm => {
+                let mut manifest = self
+                    .get_file_as::<NpmManifest>("package.json")
+                    .map_err(|err| {
+                        err.map_right(crate::package::Error::Manifest)
+                            .map_right(Error::Package)
+                            .into_inner()
+                    })?
+                    .unwrap_or_default();
+
+                manifest.add_workspace("packages/*");
+                manifest.add_workspace(base_path.join(package.path()).as_str());
+
+                self.add_file("package.json", manifest.to_string().into_bytes())?;
+            }
+            PackageKind::Python => {
+                let mut manifest = self
+                    .get_file_as::<PythonManifest>("pyproject.toml")
+                    .map_err(|err| {
+                        err.map_right(crate::package::Error::Manifest)
+                            .map_right(Error::Package)
+                            .into_inner()
+                    })?
+                    .unwrap_or_default();
+
+                manifest.add_workspace_member("packages/*");
+                manifest.add_workspace_member(base_path.join(package.path()).as_str());
+
+                self.add_file("pyproject.toml", manifest.to_string().into_bytes())?;
+            }
+            PackageKind::Go => {
+                let mut manifest = self
+                    .get_file_as::<GoManifest>("go.mod")
+                    .map_err(|err| {
+                        err.map_right(crate::package::Error::Manifest)
+                            .map_right(Error::Package)
+                            .into_inner()
+                    })?
+                    .unwrap_or_else(|| GoManifest::new_package(self.name()));
+                let module = package.manifest().try_as_go_ref().expect("go");
+
+                manifest.add_replace(
+                    module.module_path().expect("module"),
+                    base_path.join(package.path()).as_str(),
+                );
+
+                self.add_file("go.mod", manifest.to_string().into_bytes())?;
+            }
         }
 
         Ok(self)
@@ -289,6 +351,25 @@ where
     pub fn packages(&self) -> Packages<'_, T> {
         Packages::new(self)
     }
+
+    /// Gets the dependency graph of the project packages.
+    pub fn dependency_graph(&self) -> DependencyGraph {
+        DependencyGraph::new(self.packages())
+    }
+
+    /// Gets the project changelog.
+    ///
+    /// This aggregates the releases of every package with each change prefixed
+    /// by the package name. It is only available when the project changelog is
+    /// configured in the `[changelog.project]` table and the file exists.
+    pub fn changelog(&self) -> Option<Changelog> {
+        let path = self.config.project_changelog_path()?;
+        let format = ChangelogFormat::from_path(&path).unwrap_or(ChangelogFormat::Markdown);
+        let bytes = self.repository.get_file(&path).ok()??;
+        let text = std::str::from_utf8(&bytes).ok()?;
+
+        Some(Changelog::from_format(text, format))
+    }
 }
 
 impl<T> Project<T>
@@ -316,24 +397,48 @@ where
     }
 }
 
+impl<T> Project<T>
+where
+    T: Remote,
+{
+    /// Loads the release tags from the remote.
+    ///
+    /// This sets the version of packages such as Go modules that are only
+    /// versioned by tags to the latest tagged release. The tags are only
+    /// requested when the project includes such a package.
+    pub fn load_tags(&mut self) -> Result<&mut Self, Error<T::Error>> {
+        if self
+            .packages()
+            .any(|package| package.kind() == PackageKind::Go)
+        {
+            self.tags = self.repository.get_tags().map_err(Error::Repository)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Builds the project with the release tags loaded from the remote.
+    pub fn with_tags(mut self) -> Result<Self, Error<T::Error>> {
+        self.load_tags()?;
+
+        Ok(self)
+    }
+}
+
 impl<T> Project<T>
 where
     T: Remote + Clone,
 {
     /// Constructs a new package release request builder.
+    ///
+    /// Additional packages may be released in the same request with
+    /// [`ReleaseRequestBuilder::with_package`].
     pub fn create_package_release_request(
         &self,
         package: impl AsRef<str>,
         version: impl Into<BumpOrVersion>,
     ) -> Result<ReleaseRequestBuilder<'_, T>, Error<T::Error>> {
-        let package = self
-            .get_package(package.as_ref())
-            .ok_or_else(|| {
-                Error::Package(crate::package::Error::NotFound(
-                    package.as_ref().to_string(),
-                ))
-            })?
-            .detached();
+        let package = self.get_release_package(package.as_ref())?;
 
         Ok(ReleaseRequestBuilder::new(self, package, version.into()))
     }
@@ -343,17 +448,22 @@ where
         &self,
         package: impl AsRef<str>,
     ) -> Result<ReleaseBuilder<'_, T>, Error<T::Error>> {
-        let package = self
-            .get_package(package.as_ref())
-            .ok_or_else(|| {
-                Error::Package(crate::package::Error::NotFound(
-                    package.as_ref().to_string(),
-                ))
-            })?
-            .detached();
+        let package = self.get_release_package(package.as_ref())?;
 
         Ok(ReleaseBuilder::new(self, package))
     }
+
+    /// Gets the detached package to release with the tagged version loaded.
+    fn get_release_package(&self, name: &str) -> Result<Package<T>, Error<T::Error>> {
+        let mut package = self
+            .get_package(name)
+            .ok_or_else(|| Error::Package(crate::package::Error::NotFound(name.to_string())))?
+            .detached();
+
+        package.load_tagged_version().map_err(Error::Repository)?;
+
+        Ok(package)
+    }
 }
 
 #[cfg(feature = "fs")]
@@ -423,6 +533,7 @@ mod fs {
                     .with_file("Ploys.toml", self.config.to_string())?
                     .committed(())?,
                 config: self.config,
+                tags: Vec::new(),
             })
         }
     }
@@ -445,7 +556,7 @@ mod git {
         where
             P: Into<PathBuf>,
         {
-            Self::open(Git::open(path).map_err(Error::Repository)?)
+            Self::open(Git::open(path).map_err(Error::Repository)?)?.with_tags()
         }
 
         /// Opens a project from a [`Git`] repository and revision.
@@ -454,7 +565,7 @@ mod git {
             P: Into<PathBuf>,
             V: Into<Revision>,
         {
-            Self::open(Git::open(path)?.with_revision(revision))
+            Self::open(Git::open(path)?.with_revision(revision))?.with_tags()
         }
     }
 
@@ -462,7 +573,7 @@ mod git {
         type Error = Error<GitError>;
 
         fn try_from(repository: Git) -> Result<Self, Self::Error> {
-            Self::open(repository)
+            Self::open(repository)?.with_tags()
         }
     }
 }
@@ -490,10 +601,12 @@ mod fs_git {
             self,
             revision: impl Into<Revision>,
         ) -> Result<Project<Git>, Error<GitError>> {
-            Ok(Project {
+            Project {
                 repository: Git::open(self.repository.path())?.with_revision(revision),
                 config: self.config,
-            })
+                tags: Vec::new(),
+            }
+            .with_tags()
         }
 
         /// Initializes a new [`Git`] repository.
@@ -506,6 +619,7 @@ mod fs_git {
             Ok(Project {
                 repository: Git::init(self.repository.path())?,
                 config: self.config,
+                tags: Vec::new(),
             })
         }
     }
@@ -519,6 +633,72 @@ mod fs_git {
     }
 }
 
+mod gitlab {
+    use crate::repository::types::gitlab::{Error as GitLabError, GitLab};
+    use crate::repository::{Open, RepoAddr};
+
+    use super::{Error, Project};
+
+    /// The [`GitLab`] repository constructors.
+    impl Project<GitLab> {
+        /// Opens a project from a [`GitLab`] repository.
+        ///
+        /// The access token is required for private repositories.
+        pub fn gitlab<R>(repo: R, token: Option<&str>) -> Result<Self, Error<GitLabError>>
+        where
+            R: TryInto<RepoAddr, Error: Into<GitLabError>>,
+        {
+            let repository = GitLab::open(repo).map_err(Error::Repository)?;
+
+            match token {
+                Some(token) => Self::open(repository.with_token(token))?.with_tags(),
+                None => Self::open(repository)?.with_tags(),
+            }
+        }
+    }
+
+    impl TryFrom<GitLab> for Project<GitLab> {
+        type Error = Error<GitLabError>;
+
+        fn try_from(repository: GitLab) -> Result<Self, Self::Error> {
+            Self::open(repository)?.with_tags()
+        }
+    }
+}
+
+mod gitea {
+    use crate::repository::types::gitea::{Error as GiteaError, Gitea};
+    use crate::repository::{Open, RepoAddr};
+
+    use super::{Error, Project};
+
+    /// The [`Gitea`] repository constructors.
+    impl Project<Gitea> {
+        /// Opens a project from a [`Gitea`] or Forgejo repository.
+        ///
+        /// The access token is required for private repositories.
+        pub fn gitea<R>(repo: R, token: Option<&str>) -> Result<Self, Error<GiteaError>>
+        where
+            R: TryInto<RepoAddr, Error: Into<GiteaError>>,
+        {
+            let repository = Gitea::open(repo).map_err(Error::Repository)?;
+
+            match token {
+                Some(token) => Self::open(repository.with_token(token))?.with_tags(),
+                None => Self::open(repository)?.with_tags(),
+            }
+        }
+    }
+
+    impl TryFrom<Gitea> for Project<Gitea> {
+        type Error = Error<GiteaError>;
+
+        fn try_from(repository: Gitea) -> Result<Self, Self::Error> {
+            Self::open(repository)?.with_tags()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -526,9 +706,9 @@ mod tests {
     use semver::Version;
 
     use crate::changelog::Changelog;
-    use crate::package::Package;
     use crate::package::lockfile::CargoLockfile;
-    use crate::package::manifest::CargoManifest;
+    use crate::package::manifest::{CargoManifest, GoManifest, NpmManifest, PythonManifest};
+    use crate::package::{Bump, Package, PackageKind};
     use crate::repository::types::staging::Staging;
     use crate::repository::{RepoAddr, Stage};
 
@@ -607,6 +787,222 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_npm_packages() {
+        let mut project = Project::new("example");
+
+        let package_a = Package::new_npm("example-one");
+        let package_b = Package::new_npm("example-two").with_version(Version::new(0, 1, 0));
+
+        project.add_package(package_a).unwrap();
+        project.add_package(package_b).unwrap();
+        project
+            .add_package(Package::new_cargo("example-three"))
+            .unwrap();
+
+        let package_a = project.get_package("example-one").unwrap();
+        let package_b = project.get_package("example-two").unwrap();
+
+        assert_eq!(package_a.kind(), PackageKind::Npm);
+        assert_eq!(package_a.version(), Version::new(0, 0, 0));
+        assert_eq!(
+            package_a.manifest_path(),
+            "packages/example-one/package.json"
+        );
+        assert_eq!(package_b.kind(), PackageKind::Npm);
+        assert_eq!(package_b.version(), Version::new(0, 1, 0));
+        assert_eq!(project.packages().count(), 3);
+
+        let manifest = project
+            .get_file_as::<NpmManifest>("package.json")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(manifest.name(), None);
+        assert_eq!(manifest.workspaces().collect::<Vec<_>>(), ["packages/*"]);
+    }
+
+    #[test]
+    fn test_python_packages() {
+        let mut project = Project::new("example");
+
+        project
+            .add_package(Package::new_python("example-one"))
+            .unwrap();
+        project
+            .add_package(Package::new_python("example-two").with_version(Version::new(0, 1, 0)))
+            .unwrap();
+
+        let package_a = project.get_package("example-one").unwrap();
+        let package_b = project.get_package("example-two").unwrap();
+
+        assert_eq!(package_a.kind(), PackageKind::Python);
+        assert_eq!(
+            package_a.manifest_path(),
+            "packages/example-one/pyproject.toml"
+        );
+        assert_eq!(package_b.version(), Version::new(0, 1, 0));
+        assert_eq!(project.packages().count(), 2);
+
+        let manifest = project
+            .get_file_as::<PythonManifest>("pyproject.toml")
+            .unwrap()
+            .unwrap();
+
+        assert!(
+            manifest
+                .members()
+                .unwrap()
+                .includes(Path::new("packages/example-one"))
+        );
+    }
+
+    #[test]
+    fn test_go_packages() {
+        let mut project = Project::new("example");
+
+        project
+            .add_package(Package::new_go("github.com/ploys/example-one"))
+            .unwrap();
+
+        let root = project.get_package("example").unwrap();
+        let package = project.get_package("example-one").unwrap();
+
+        assert_eq!(package.kind(), PackageKind::Go);
+        assert_eq!(package.manifest_path(), "packages/example-one/go.mod");
+        assert_eq!(package.version(), Version::new(0, 0, 0));
+        assert_eq!(package.tag_prefix(), "packages/example-one/v");
+        assert_eq!(
+            package.tag_name(Version::new(0, 1, 0)),
+            "packages/example-one/v0.1.0"
+        );
+        assert!(root.is_primary());
+        assert_eq!(root.tag_prefix(), "v");
+        assert_eq!(project.packages().count(), 2);
+
+        let manifest = project
+            .get_file_as::<GoManifest>("go.mod")
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            manifest.get_replace_path("github.com/ploys/example-one"),
+            Some("./packages/example-one")
+        );
+    }
+
+    #[test]
+    fn test_cargo_workspace_inheritance() {
+        let repository = Staging::new()
+            .with_file("Ploys.toml", "[project]\nname = \"example\"")
+            .unwrap()
+            .with_file(
+                "Cargo.toml",
+                indoc::indoc! {r#"
+                    [workspace]
+                    members = ["packages/*"]
+
+                    [workspace.package]
+                    version = "0.1.0"
+                    repository = "https://github.com/ploys/example"
+
+                    [workspace.dependencies]
+                    example-one = { version = "0.1.0", path = "packages/example-one" }
+                "#},
+            )
+            .unwrap()
+            .with_file(
+                "packages/example-one/Cargo.toml",
+                indoc::indoc! {r#"
+                    [package]
+                    name = "example-one"
+                    version.workspace = true
+                    repository.workspace = true
+                "#},
+            )
+            .unwrap()
+            .with_file(
+                "packages/example-two/Cargo.toml",
+                indoc::indoc! {r#"
+                    [package]
+                    name = "example-two"
+                    version = "0.2.0"
+
+                    [dependencies]
+                    example-one.workspace = true
+                "#},
+            )
+            .unwrap();
+
+        let project = Project::open(repository).unwrap();
+        let mut package_a = project.get_package("example-one").unwrap();
+        let package_b = project.get_package("example-two").unwrap();
+
+        assert_eq!(package_a.version(), Version::new(0, 1, 0));
+        assert_eq!(
+            package_a.repository().unwrap().as_str(),
+            "https://github.com/ploys/example"
+        );
+        assert_eq!(package_b.version(), Version::new(0, 2, 0));
+        assert_eq!(
+            package_b.get_dependency("example-one").unwrap().version(),
+            Some("0.1.0")
+        );
+
+        let manifest = package_a.manifest().to_string();
+
+        package_a.set_version(Version::new(0, 3, 0));
+
+        assert_eq!(package_a.version(), Version::new(0, 3, 0));
+        assert_eq!(package_a.manifest().to_string(), manifest);
+        assert!(
+            package_a
+                .workspace_manifest()
+                .unwrap()
+                .to_string()
+                .contains("version = \"0.3.0\"")
+        );
+    }
+
+    #[test]
+    fn test_changeset_files() {
+        let mut project = Project::new("example");
+
+        project
+            .add_package(Package::new_cargo("example-one"))
+            .unwrap();
+        project
+            .add_package(Package::new_cargo("example-two"))
+            .unwrap();
+        project
+            .add_file(".changeset/README.md", "# Changesets")
+            .unwrap()
+            .add_file(
+                ".changeset/new-feature.md",
+                "---\n\"example-one\": minor\n---\n\nAdd a new feature.\n",
+            )
+            .unwrap()
+            .add_file(
+                ".changeset/bug-fix.md",
+                "---\n\"example-one\": patch\n\"example-two\": patch\n---\n\nFix a bug.\n",
+            )
+            .unwrap();
+
+        let package_a = project.get_package("example-one").unwrap();
+        let package_b = project.get_package("example-two").unwrap();
+
+        let mut files = package_a.changeset_files();
+
+        files.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].0, ".changeset/bug-fix.md");
+        assert_eq!(files[0].1.summary(), "Fix a bug.");
+        assert_eq!(files[1].0, ".changeset/new-feature.md");
+        assert_eq!(files[1].1.get_package("example-one"), Some(Bump::Minor));
+        assert_eq!(package_b.changeset_files().len(), 1);
+    }
+
     #[test]
     fn test_project_staging_repository() {
         let repository = Staging::new()
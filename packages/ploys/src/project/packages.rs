@@ -138,7 +138,9 @@ where
                 continue;
             };
 
-            let Some(package) = Package::from_manifest(self.project, parent, manifest) else {
+            let Some(package) =
+                Package::from_manifest(self.project, parent, manifest, &self.manifest)
+            else {
                 continue;
             };
 
@@ -1,10 +1,18 @@
+mod plan;
 mod request;
+mod template;
 
+use semver::Version;
+use strum::IntoEnumIterator;
 use tracing::{info, info_span};
 
-use crate::repository::Remote;
+use crate::repository::{Remote, Repository};
 
+pub use self::plan::{FileChange, ReleasePlan};
 pub use self::request::{ReleaseRequest, ReleaseRequestBuilder};
+pub use self::template::{Error as TemplateError, TemplateKind, Templates};
+
+use self::template::{PackageContext, TemplateContext};
 
 use super::{Package, Project};
 
@@ -36,12 +44,39 @@ impl Release {
 pub struct ReleaseBuilder<'a, T> {
     project: &'a Project<T>,
     package: Package<T>,
+    version: Option<Version>,
+    consolidate_prereleases: bool,
 }
 
 impl<'a, T> ReleaseBuilder<'a, T> {
     /// Constructs a new release builder.
     pub(crate) fn new(project: &'a Project<T>, package: Package<T>) -> Self {
-        Self { project, package }
+        Self {
+            project,
+            package,
+            version: None,
+            consolidate_prereleases: false,
+        }
+    }
+}
+
+impl<T> ReleaseBuilder<'_, T> {
+    /// Sets the release version.
+    ///
+    /// This defaults to the package version but must be set for packages such
+    /// as Go modules where the new version does not exist until it is tagged.
+    pub fn version(mut self, version: impl Into<Version>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Consolidate the prerelease notes.
+    ///
+    /// This includes the notes of the prereleases that precede a stable
+    /// version in the release body when they remain in the changelog.
+    pub fn consolidate_prereleases(mut self, enable: bool) -> Self {
+        self.consolidate_prereleases = enable;
+        self
     }
 }
 
@@ -50,10 +85,22 @@ where
     T: Remote,
 {
     /// Finishes the release.
-    pub fn finish(self) -> Result<Release, T::Error> {
-        let sha = self.project.repository.sha()?;
+    ///
+    /// The release name and body are rendered from the `release-name` and
+    /// `release-body` templates when they exist. This returns the project
+    /// error rather than the repository error so that template errors can be
+    /// reported.
+    pub fn finish(self) -> Result<Release, crate::project::Error<T::Error>> {
+        let sha = self
+            .project
+            .repository
+            .sha()
+            .map_err(crate::project::Error::Repository)?;
 
-        let version = self.package.version();
+        let version = match self.version {
+            Some(ref version) => version.clone(),
+            None => self.package.version(),
+        };
 
         let span = info_span!("release", package = self.package.name(), %version);
         let _enter = span.enter();
@@ -63,36 +110,59 @@ where
         let prerelease = !version.pre.is_empty();
         let latest = self.package.is_primary() && !prerelease;
 
-        let tag = match self.package.is_primary() {
-            true => version.to_string(),
-            false => format!("{}-{version}", self.package.name()),
-        };
-
-        let name = match self.package.is_primary() {
-            true => version.to_string(),
-            false => format!("{} {version}", self.package.name()),
-        };
+        let tag = self.package.tag_name(&version);
 
         let changelog = self.package.changelog();
         let release = changelog
             .as_ref()
             .and_then(|changelog| changelog.get_release(version.to_string()));
 
-        let release = match release {
+        let mut release = match release {
             Some(release) => release.to_owned(),
-            None => self.package.build_release_notes(&version)?,
+            None => self
+                .package
+                .build_release_notes(&version)
+                .map_err(crate::project::Error::Repository)?,
         };
 
-        let body = format!("{release:#}")
-            .lines()
-            .skip(2)
-            .collect::<Vec<_>>()
-            .join("\n");
+        if self.consolidate_prereleases
+            && version.pre.is_empty()
+            && let Some(changelog) = &changelog
+        {
+            for prerelease in changelog.get_prereleases(version.to_string()) {
+                release.merge(prerelease.to_owned());
+            }
+        }
+
+        let templates = get_templates(self.project);
+        let context = TemplateContext::new(
+            get_package_context(self.project, &self.package, &version, &release)
+                .map_err(crate::project::Error::Repository)?,
+            [],
+        );
+
+        let name = match templates.render(TemplateKind::ReleaseName, &context)? {
+            Some(name) => name,
+            None => match self.package.is_primary() {
+                true => version.to_string(),
+                false => format!("{} {version}", self.package.name()),
+            },
+        };
+
+        let body = match templates.render(TemplateKind::ReleaseBody, &context)? {
+            Some(body) => body,
+            None => format!("{release:#}")
+                .lines()
+                .skip(2)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        };
 
         let id = self
             .project
             .repository
-            .create_release(&tag, &sha, &name, &body, prerelease, latest)?;
+            .create_release(&tag, &sha, &name, &body, prerelease, latest)
+            .map_err(crate::project::Error::Repository)?;
 
         info!(id, "Created release");
 
@@ -103,3 +173,60 @@ where
         })
     }
 }
+
+/// Gets the release templates.
+///
+/// Templates declared in the `Ploys.toml` file take precedence over template
+/// files in the `.ploys/templates` directory.
+fn get_templates<T>(project: &Project<T>) -> Templates
+where
+    T: Repository,
+{
+    let mut templates = project.config.release_templates();
+
+    for kind in TemplateKind::iter() {
+        if templates.get_template(kind).is_some() {
+            continue;
+        }
+
+        let template = project
+            .repository
+            .get_file(kind.file_path())
+            .ok()
+            .flatten()
+            .and_then(|bytes| String::from_utf8(bytes.to_vec()).ok());
+
+        if let Some(template) = template {
+            templates.add_template(kind, template);
+        }
+    }
+
+    templates
+}
+
+/// Gets the release template context for the given package.
+fn get_package_context<T>(
+    project: &Project<T>,
+    package: &Package<T>,
+    version: &Version,
+    release: &crate::changelog::Release,
+) -> Result<PackageContext, T::Error>
+where
+    T: Remote,
+{
+    let previous_version = package.previous_version(version)?;
+    let compare_url = previous_version.as_ref().and_then(|previous_version| {
+        project.repository.get_compare_url(
+            &package.tag_name(previous_version),
+            &package.tag_name(version),
+        )
+    });
+
+    Ok(PackageContext::new(
+        package.name(),
+        version,
+        previous_version,
+        release.changesets().cloned(),
+        compare_url,
+    ))
+}
@@ -0,0 +1,168 @@
+use relative_path::{RelativePath, RelativePathBuf};
+use semver::Version;
+use similar::TextDiff;
+
+use crate::changelog::Release;
+
+/// The release plan.
+///
+/// This describes the release request that would be created without making any
+/// changes to the remote repository.
+#[derive(Clone, Debug)]
+pub struct ReleasePlan {
+    pub(super) title: String,
+    pub(super) branch: String,
+    pub(super) body: String,
+    pub(super) notes: Release,
+    pub(super) version: Version,
+    pub(super) packages: Vec<(String, Version)>,
+    pub(super) files: Vec<FileChange>,
+}
+
+impl ReleasePlan {
+    /// Gets the release request title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Gets the release request branch name.
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    /// Gets the release request body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Gets the release notes.
+    pub fn notes(&self) -> &Release {
+        &self.notes
+    }
+
+    /// Gets the resolved version of the first package.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Gets an iterator over the released packages and their versions.
+    pub fn packages(&self) -> impl Iterator<Item = (&str, &Version)> {
+        self.packages
+            .iter()
+            .map(|(name, version)| (name.as_str(), version))
+    }
+
+    /// Gets an iterator over the file changes.
+    pub fn files(&self) -> impl Iterator<Item = &FileChange> {
+        self.files.iter()
+    }
+}
+
+/// A planned file change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileChange {
+    path: RelativePathBuf,
+    original: Option<String>,
+    content: Option<String>,
+}
+
+impl FileChange {
+    /// Constructs a new file change.
+    pub(super) fn new(
+        path: impl Into<RelativePathBuf>,
+        original: Option<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            original,
+            content: Some(content.into()),
+        }
+    }
+
+    /// Constructs a new file removal.
+    pub(super) fn removed(path: impl Into<RelativePathBuf>, original: Option<String>) -> Self {
+        Self {
+            path: path.into(),
+            original,
+            content: None,
+        }
+    }
+
+    /// Gets the file path.
+    pub fn path(&self) -> &RelativePath {
+        &self.path
+    }
+
+    /// Gets the original file contents if the file exists.
+    pub fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+
+    /// Gets the updated file contents or `None` if the file is removed.
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    /// Gets the unified diff between the original and updated file contents.
+    pub fn diff(&self) -> String {
+        let original = match self.original {
+            Some(_) => format!("a/{}", self.path),
+            None => String::from("/dev/null"),
+        };
+        let content = match self.content {
+            Some(_) => format!("b/{}", self.path),
+            None => String::from("/dev/null"),
+        };
+
+        TextDiff::from_lines(
+            self.original().unwrap_or_default(),
+            self.content().unwrap_or_default(),
+        )
+        .unified_diff()
+        .header(&original, &content)
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileChange;
+
+    #[test]
+    fn test_file_change_diff() {
+        let change = FileChange::new(
+            "Cargo.toml",
+            Some("[package]\nname = \"example\"\nversion = \"0.1.0\"\n".into()),
+            "[package]\nname = \"example\"\nversion = \"0.2.0\"\n",
+        );
+
+        let expected = indoc::indoc! {r#"
+            --- a/Cargo.toml
+            +++ b/Cargo.toml
+            @@ -1,3 +1,3 @@
+             [package]
+             name = "example"
+            -version = "0.1.0"
+            +version = "0.2.0"
+        "#};
+
+        assert_eq!(change.diff(), expected);
+
+        let change = FileChange::new("CHANGELOG.md", None, "# Changelog\n");
+
+        assert!(
+            change
+                .diff()
+                .starts_with("--- /dev/null\n+++ b/CHANGELOG.md\n")
+        );
+
+        let change = FileChange::removed(".changeset/example.md", Some("Example.\n".into()));
+
+        assert_eq!(change.content(), None);
+        assert_eq!(
+            change.diff(),
+            "--- a/.changeset/example.md\n+++ /dev/null\n@@ -1 +0,0 @@\n-Example.\n"
+        );
+    }
+}
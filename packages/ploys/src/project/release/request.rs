@@ -1,12 +1,25 @@
-use relative_path::RelativePathBuf;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashMap};
+
+use relative_path::{RelativePath, RelativePathBuf};
 use semver::Version;
-use tracing::{info, info_span};
+use tracing::{info, info_span, warn};
+
+use crate::changelog::{Change, Changelog, ChangelogFormat, Changeset, Release};
+use crate::package::{Bump, BumpOrVersion, Lockfile, Manifest, Package, PackageKind};
+use crate::project::{DependencyGraph, Project};
+use crate::repository::Repository;
 
-use crate::changelog::Release;
-use crate::package::{BumpOrVersion, Lockfile, Package};
-use crate::project::Project;
+use super::{
+    FileChange, ReleasePlan, Remote, TemplateContext, TemplateKind, get_package_context,
+    get_templates,
+};
 
-use super::Remote;
+/// The start of the hidden release request package list.
+const MARKER_START: &str = "<!-- ploys-release:";
+
+/// The end of the hidden release request package list.
+const MARKER_END: &str = "-->";
 
 /// The release request.
 pub struct ReleaseRequest {
@@ -14,6 +27,7 @@ pub struct ReleaseRequest {
     title: String,
     notes: Release,
     version: Version,
+    packages: Vec<(String, Version)>,
 }
 
 impl ReleaseRequest {
@@ -36,6 +50,34 @@ impl ReleaseRequest {
     pub fn version(&self) -> &Version {
         &self.version
     }
+
+    /// Gets an iterator over the released packages and their versions.
+    ///
+    /// This includes the released package followed by any dependent packages
+    /// that were bumped along with it.
+    pub fn packages(&self) -> impl Iterator<Item = (&str, &Version)> {
+        self.packages
+            .iter()
+            .map(|(name, version)| (name.as_str(), version))
+    }
+
+    /// Parses the released packages from the body of a release request.
+    ///
+    /// The body of each release request ends with a hidden list of packages
+    /// and versions so that every package can be released once the request
+    /// has been merged.
+    pub fn parse_packages(body: &str) -> Option<Vec<(String, Version)>> {
+        let (_, list) = body.rsplit_once(MARKER_START)?;
+        let (list, _) = list.split_once(MARKER_END)?;
+
+        list.split(',')
+            .map(|entry| {
+                let (name, version) = entry.trim().rsplit_once('@')?;
+
+                Some((name.to_owned(), version.parse().ok()?))
+            })
+            .collect()
+    }
 }
 
 /// The release request builder.
@@ -44,8 +86,7 @@ impl ReleaseRequest {
 /// repository.
 pub struct ReleaseRequestBuilder<'a, T> {
     project: &'a Project<T>,
-    package: Package<T>,
-    version: BumpOrVersion,
+    packages: Vec<(Package<T>, BumpOrVersion)>,
     options: Options,
 }
 
@@ -58,13 +99,45 @@ impl<'a, T> ReleaseRequestBuilder<'a, T> {
     ) -> Self {
         Self {
             project,
-            package,
-            version,
+            packages: vec![(package, version)],
             options: Options::default(),
         }
     }
 }
 
+impl<T> ReleaseRequestBuilder<'_, T>
+where
+    T: Remote + Clone,
+{
+    /// Adds another package to the release request.
+    ///
+    /// The packages are released together with a single branch, commit and
+    /// release request. Adding a package that is already included replaces
+    /// its version.
+    pub fn with_package(
+        mut self,
+        package: impl AsRef<str>,
+        version: impl Into<BumpOrVersion>,
+    ) -> Result<Self, crate::project::Error<T::Error>> {
+        let version = version.into();
+        let existing = self
+            .packages
+            .iter_mut()
+            .find(|(existing, _)| existing.name() == package.as_ref());
+
+        match existing {
+            Some((_, existing)) => *existing = version,
+            None => {
+                let package = self.project.get_release_package(package.as_ref())?;
+
+                self.packages.push((package, version));
+            }
+        }
+
+        Ok(self)
+    }
+}
+
 impl<T> ReleaseRequestBuilder<'_, T> {
     /// Update the package manifest.
     pub fn update_package_manifest(mut self, enable: bool) -> Self {
@@ -78,6 +151,27 @@ impl<T> ReleaseRequestBuilder<'_, T> {
         self
     }
 
+    /// Bump the dependent packages.
+    ///
+    /// This releases every package that depends on the package, directly or
+    /// transitively, in the same release request. Each dependent receives a
+    /// patch bump, or a minor bump when an updated dependency is not semver
+    /// compatible with its previous version.
+    pub fn bump_dependent_packages(mut self, enable: bool) -> Self {
+        self.options.bump_dependent_packages = enable;
+        self
+    }
+
+    /// Consolidate the prerelease changelog sections.
+    ///
+    /// This merges the prereleases that precede a stable version, such as the
+    /// `1.0.0-rc.1` and `1.0.0-rc.2` releases before `1.0.0`, into the stable
+    /// release and removes their sections from the changelog.
+    pub fn consolidate_prereleases(mut self, enable: bool) -> Self {
+        self.options.consolidate_prereleases = enable;
+        self
+    }
+
     /// Update the workspace lockfile.
     pub fn update_lockfile(mut self, enable: bool) -> Self {
         self.options.update_lockfile = enable;
@@ -89,151 +183,457 @@ impl<T> ReleaseRequestBuilder<'_, T> {
         self.options.update_changelog = enable;
         self
     }
+
+    /// Update the project changelog.
+    ///
+    /// This adds the package releases to the project changelog when it is
+    /// configured in the `[changelog.project]` table.
+    pub fn update_project_changelog(mut self, enable: bool) -> Self {
+        self.options.update_project_changelog = enable;
+        self
+    }
 }
 
 impl<T> ReleaseRequestBuilder<'_, T>
 where
     T: Remote,
 {
-    /// Finishes the release request.
-    pub fn finish(mut self) -> Result<ReleaseRequest, crate::project::Error<T::Error>> {
-        let mut files = Vec::new();
+    /// Plans the release request.
+    ///
+    /// This resolves the versions, file changes and release notes without
+    /// making any changes to the remote repository.
+    pub fn plan(self) -> Result<ReleasePlan, crate::project::Error<T::Error>> {
+        let mut released = Vec::with_capacity(self.packages.len());
 
-        let version = match self.version {
-            BumpOrVersion::Bump(bump) => {
-                self.package.bump_version(bump)?;
-                self.package.version()
-            }
-            BumpOrVersion::Version(version) => {
-                self.package.set_version(version.clone());
-                version
+        for (mut package, version) in self.packages {
+            let previous = package.version();
+            let version = match version {
+                BumpOrVersion::Bump(bump) => {
+                    package.bump_version(bump)?;
+                    package.version()
+                }
+                BumpOrVersion::Version(version) => {
+                    package.set_version(version.clone());
+                    version
+                }
+                BumpOrVersion::Auto => {
+                    let bump = package
+                        .infer_bump()
+                        .map_err(crate::project::Error::Repository)?;
+
+                    info!(package = package.name(), %bump, "Inferred version bump");
+
+                    package.bump_version(bump)?;
+                    package.version()
+                }
+            };
+
+            if let Manifest::Go(go) = package.manifest()
+                && version.major >= 2
+                && go.major_version_suffix() != Some(version.major)
+            {
+                warn!(
+                    module = go.module_path(),
+                    "Module path must end in `/v{}` to release a new major version", version.major
+                );
             }
-        };
 
-        let span = info_span!("release_request", package = self.package.name(), %version);
+            released.push((package, previous, version));
+        }
+
+        let labels = released
+            .iter()
+            .map(|(package, _, version)| format!("{}@{version}", package.name()))
+            .collect::<Vec<_>>();
+
+        let span = info_span!("release_plan", packages = labels.join(", "));
         let _enter = span.enter();
 
-        info!("Creating release request");
+        info!("Planning release request");
+
+        // Go modules are versioned by tags alone so there are no manifests or
+        // lockfiles to update and no dependents to bump.
+        let graph = self.project.dependency_graph();
+        let packages = self.project.packages().collect::<Vec<_>>();
+        let dependents = match self.options.bump_dependent_packages {
+            true => bump_dependents(
+                &graph,
+                &packages,
+                &released
+                    .iter()
+                    .filter(|(package, _, _)| package.kind() != PackageKind::Go)
+                    .map(|(package, previous, version)| (package.name(), previous, version))
+                    .collect::<Vec<_>>(),
+            )?,
+            false => Vec::new(),
+        };
+
+        let releases = released
+            .iter()
+            .map(|(package, _, version)| (package.name(), package.path(), package.kind(), version))
+            .chain(dependents.iter().map(|(package, version)| {
+                (package.name(), package.path(), package.kind(), version)
+            }))
+            .collect::<Vec<_>>();
+
+        let mut manifests = Manifests::new(self.project);
 
         if self.options.update_package_manifest {
-            files.push((
-                self.package.manifest_path().to_owned(),
-                self.package.manifest().to_string(),
-            ));
+            for (_, path, kind, version) in &releases {
+                manifests.set_package_version(path, *kind, version);
+            }
         }
 
         if self.options.update_dependent_package_manifests {
-            for mut package in self.project.packages() {
-                if package.name() == self.package.name() {
+            for (name, _, kind, version) in &releases {
+                if *kind == PackageKind::Go {
                     continue;
                 }
 
-                let mut changed = false;
+                let dependents = packages.iter().filter(|package| {
+                    graph
+                        .dependents(name)
+                        .any(|dependent| dependent == package.name())
+                });
 
-                if let Some(mut dependency) = package.get_dependency_mut(self.package.name()) {
-                    dependency.set_version(version.clone());
-                    changed = true;
+                for package in dependents {
+                    manifests.set_dependency_version(package.path(), package.kind(), name, version);
                 }
 
-                if let Some(mut dependency) = package.get_dev_dependency_mut(self.package.name()) {
-                    dependency.set_version(version.clone());
-                    changed = true;
+                if *kind == PackageKind::Cargo {
+                    manifests.set_workspace_dependency_version(name, version);
                 }
+            }
+        }
+
+        let mut files = manifests.into_files();
+
+        if self.options.update_lockfile {
+            let mut kinds = Vec::new();
+
+            for (_, _, kind, _) in &releases {
+                if !kinds.contains(kind) {
+                    kinds.push(*kind);
+                }
+            }
+
+            for kind in kinds {
+                for path in kind.lockfile_names() {
+                    let lockfile = self
+                        .project
+                        .repository
+                        .get_file(path)
+                        .ok()
+                        .flatten()
+                        .and_then(|bytes| Lockfile::from_bytes(path, &bytes).ok());
+
+                    if let Some(mut lockfile) = lockfile {
+                        for (name, _, _, version) in
+                            releases.iter().filter(|release| release.2 == kind)
+                        {
+                            lockfile.set_package_version(name, (*version).clone());
+                        }
+
+                        files.push((RelativePathBuf::from(*path), lockfile.to_string()));
+                    }
+                }
+            }
+        }
+
+        let mut notes = Vec::with_capacity(released.len());
+        let mut package_releases = Vec::with_capacity(releases.len());
+
+        for (package, _, version) in &released {
+            let mut release = package
+                .build_release_notes(version)
+                .map_err(crate::project::Error::Repository)?;
+            let mut changelog = package.changelog().unwrap_or_default();
+
+            if self.options.consolidate_prereleases {
+                consolidate_prereleases(&mut changelog, &mut release, version);
+            }
+
+            if self.options.update_changelog {
+                changelog.release_unreleased(release.clone(), package.tag_name(version));
+                files.push(changelog_file(package, &changelog));
+            }
 
-                if let Some(mut dependency) = package.get_build_dependency_mut(self.package.name())
-                {
-                    dependency.set_version(version.clone());
-                    changed = true;
+            package_releases.push((package.name().to_owned(), release.clone()));
+            notes.push(release);
+        }
+
+        if self.options.update_changelog {
+            for (package, version) in &dependents {
+                let mut release = package
+                    .build_release_notes(version)
+                    .map_err(crate::project::Error::Repository)?;
+
+                for (name, _, _, version) in &releases {
+                    if graph
+                        .dependencies(package.name())
+                        .any(|dependency| dependency == *name)
+                    {
+                        add_dependency_change(&mut release, name, version);
+                    }
                 }
 
-                if changed {
-                    files.push((
-                        package.manifest_path().to_owned(),
-                        package.manifest().to_string(),
-                    ));
+                let mut changelog = package.changelog().unwrap_or_default();
+
+                if self.options.consolidate_prereleases {
+                    consolidate_prereleases(&mut changelog, &mut release, version);
                 }
+
+                changelog.release_unreleased(release.clone(), package.tag_name(version));
+                files.push(changelog_file(package, &changelog));
+                package_releases.push((package.name().to_owned(), release));
             }
         }
 
-        if self.options.update_lockfile
-            && let Some(path) = self.package.kind().lockfile_name()
+        if self.options.update_project_changelog
+            && let Some(path) = self.project.config.project_changelog_path()
         {
-            let lockfile = self
-                .project
-                .repository
-                .get_file(path)
-                .ok()
-                .flatten()
-                .and_then(|bytes| Lockfile::from_bytes(self.package.kind(), &bytes).ok());
+            if files.iter().any(|(file, _)| *file == path) {
+                warn!(%path, "Project changelog conflicts with a package changelog");
+            } else {
+                let format = ChangelogFormat::from_path(&path).unwrap_or(ChangelogFormat::Markdown);
+                let mut changelog = self
+                    .project
+                    .changelog()
+                    .unwrap_or_else(Changelog::new_project);
 
-            if let Some(mut lockfile) = lockfile {
-                lockfile.set_package_version(self.package.name(), version.clone());
-                files.push((RelativePathBuf::from(path), lockfile.to_string()));
+                changelog.add_package_releases(
+                    package_releases,
+                    self.project.config.project_changelog_grouping(),
+                );
+
+                files.push((path, changelog.to_format(format)));
             }
         }
 
-        let mut release = self
-            .package
-            .build_release_notes(&version)
-            .map_err(crate::project::Error::Repository)?;
+        // Changeset files are consumed by removing the released packages and
+        // deleting the files that no longer include any packages.
+        let mut changeset_files = BTreeMap::new();
 
-        if self.options.update_changelog {
-            let path = self.package.path().join("CHANGELOG.md");
+        for (path, file) in released
+            .iter()
+            .flat_map(|(package, _, _)| package.changeset_files())
+            .chain(
+                dependents
+                    .iter()
+                    .flat_map(|(package, _)| package.changeset_files()),
+            )
+        {
+            changeset_files.entry(path).or_insert(file);
+        }
 
-            let mut changelog = self.package.changelog().unwrap_or_default();
+        let mut removed = Vec::new();
 
-            changelog.add_release(release.clone());
-            files.push((path, changelog.to_string()));
+        for (path, mut file) in changeset_files {
+            for (name, _, _, _) in &releases {
+                file.remove_package(name);
+            }
+
+            match file.packages().next() {
+                Some(_) => files.push((path, file.to_string())),
+                None => removed.push(path),
+            }
         }
 
-        release.set_description(format!(
-            "Releasing package `{}` version `{version}`.",
-            self.package.name()
-        ));
+        let mut contexts = Vec::with_capacity(released.len());
 
-        if let Some(url) = release.url() {
-            release.add_reference(version.to_string(), url.to_string());
+        for ((package, _, version), release) in released.iter().zip(&notes) {
+            contexts.push(
+                get_package_context(self.project, package, version, release)
+                    .map_err(crate::project::Error::Repository)?,
+            );
         }
 
-        let body = release.to_string();
-        let title = match self.package.is_primary() {
-            true => format!("Release `{version}`"),
-            false => format!("Release `{}@{version}`", self.package.name()),
+        let (title, branch, body) = match &*released {
+            [(package, _, version)] => {
+                let release = &mut notes[0];
+                let mut description = format!(
+                    "Releasing package `{}` version `{version}`.",
+                    package.name()
+                );
+
+                if !dependents.is_empty() {
+                    description
+                        .push_str("\n\nThis also releases the following dependent packages:\n");
+
+                    for (package, version) in &dependents {
+                        description
+                            .push_str(&format!("\n- `{}` version `{version}`", package.name()));
+                    }
+                }
+
+                release.set_description(description);
+
+                if let Some(url) = release.url() {
+                    release.add_reference(version.to_string(), url.to_string());
+                }
+
+                let body = release.to_string();
+                let title = match package.is_primary() {
+                    true => format!("Release `{version}`"),
+                    false => format!("Release `{}@{version}`", package.name()),
+                };
+                let branch = match package.is_primary() {
+                    true => format!("release/{version}"),
+                    false => format!("release/{}-{version}", package.name()),
+                };
+
+                (title, branch, body)
+            }
+            _ => {
+                let mut body = String::from("Releasing the following packages:\n");
+
+                for (name, _, _, version) in &releases {
+                    body.push_str(&format!("\n- `{name}` version `{version}`"));
+                }
+
+                for (release, label) in notes.iter().zip(&labels) {
+                    let mut release = release.clone().with_version(label);
+
+                    if let Some(url) = release.url() {
+                        release.add_reference(label, url.to_string());
+                    }
+
+                    body.push_str(&format!("\n\n{release}"));
+                }
+
+                let title = format!(
+                    "Release {}",
+                    labels
+                        .iter()
+                        .map(|label| format!("`{label}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let branch = format!(
+                    "release/{}",
+                    released
+                        .iter()
+                        .map(|(package, _, version)| format!("{}-{version}", package.name()))
+                        .collect::<Vec<_>>()
+                        .join("+")
+                );
+
+                (title, branch, body)
+            }
+        };
+
+        let templates = get_templates(self.project);
+        let mut contexts = contexts.into_iter();
+        let context = contexts
+            .next()
+            .map(|package| TemplateContext::new(package, contexts));
+        let render = |kind| match &context {
+            Some(context) => templates.render(kind, context),
+            None => Ok(None),
         };
-        let branch = match self.package.is_primary() {
-            true => format!("release/{version}"),
-            false => format!("release/{}-{version}", self.package.name()),
+
+        let title = render(TemplateKind::PullRequestTitle)?.unwrap_or(title);
+        let branch = render(TemplateKind::Branch)?.unwrap_or(branch);
+        let mut body = render(TemplateKind::PullRequestBody)?.unwrap_or(body);
+
+        body.push_str(&format!(
+            "\n\n{MARKER_START} {} {MARKER_END}",
+            releases
+                .iter()
+                .map(|(name, _, _, version)| format!("{name}@{version}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+
+        let original = |path: &RelativePath| {
+            self.project
+                .repository
+                .get_file(path)
+                .ok()
+                .flatten()
+                .and_then(|bytes| String::from_utf8(bytes.to_vec()).ok())
         };
 
-        let default_branch = self
-            .project
+        let files = files
+            .into_iter()
+            .map(|(path, content)| {
+                let original = original(&path);
+
+                FileChange::new(path, original, content)
+            })
+            .chain(removed.into_iter().map(|path| {
+                let original = original(&path);
+
+                FileChange::removed(path, original)
+            }))
+            .collect();
+
+        let packages = releases
+            .iter()
+            .map(|(name, _, _, version)| ((*name).to_owned(), (*version).clone()))
+            .collect();
+        let version = released.swap_remove(0).2;
+
+        Ok(ReleasePlan {
+            title,
+            branch,
+            body,
+            notes: notes.swap_remove(0),
+            version,
+            packages,
+            files,
+        })
+    }
+
+    /// Finishes the release request.
+    pub fn finish(self) -> Result<ReleaseRequest, crate::project::Error<T::Error>> {
+        let project = self.project;
+        let plan = self.plan()?;
+
+        let span = info_span!("release_request", branch = plan.branch);
+        let _enter = span.enter();
+
+        info!("Creating release request");
+
+        let default_branch = project
             .repository
             .get_default_branch()
             .map_err(crate::project::Error::Repository)?;
 
-        self.project
+        project
             .repository
-            .create_branch(&branch)
+            .create_branch(&plan.branch)
             .map_err(crate::project::Error::Repository)?;
 
-        self.project
+        let files = plan
+            .files
+            .iter()
+            .map(|file| {
+                (
+                    file.path().to_owned(),
+                    file.content().map(ToOwned::to_owned),
+                )
+            })
+            .collect();
+
+        project
             .repository
-            .commit_branch(&branch, &title, files)
+            .commit_branch(&plan.branch, &plan.title, files)
             .map_err(crate::project::Error::Repository)?;
 
-        let id = self
-            .project
+        let id = project
             .repository
-            .create_pull_request(&branch, &default_branch, &title, &body)
+            .create_pull_request(&plan.branch, &default_branch, &plan.title, &plan.body)
             .map_err(crate::project::Error::Repository)?;
 
         info!(id, "Created release request");
 
         Ok(ReleaseRequest {
             id,
-            title,
-            notes: release,
-            version,
+            title: plan.title,
+            notes: plan.notes,
+            version: plan.version,
+            packages: plan.packages,
         })
     }
 }
@@ -242,8 +642,11 @@ where
 struct Options {
     update_package_manifest: bool,
     update_dependent_package_manifests: bool,
+    bump_dependent_packages: bool,
+    consolidate_prereleases: bool,
     update_lockfile: bool,
     update_changelog: bool,
+    update_project_changelog: bool,
 }
 
 impl Default for Options {
@@ -251,8 +654,393 @@ impl Default for Options {
         Self {
             update_package_manifest: true,
             update_dependent_package_manifests: true,
+            bump_dependent_packages: false,
+            consolidate_prereleases: false,
             update_lockfile: true,
             update_changelog: true,
+            update_project_changelog: true,
+        }
+    }
+}
+
+/// Renders the changelog file for the given package.
+///
+/// This keeps the name and format of an existing changelog file and creates a
+/// `CHANGELOG.md` file otherwise.
+fn changelog_file<T>(package: &Package<T>, changelog: &Changelog) -> (RelativePathBuf, String)
+where
+    T: Repository,
+{
+    let (name, format) = package
+        .changelog_file()
+        .unwrap_or(("CHANGELOG.md", ChangelogFormat::Markdown));
+
+    (package.path().join(name), changelog.to_format(format))
+}
+
+/// Consolidates the prerelease sections into the stable release.
+///
+/// The prereleases are merged from newest to oldest and removed from the
+/// changelog. This has no effect when releasing a prerelease version.
+fn consolidate_prereleases(changelog: &mut Changelog, release: &mut Release, version: &Version) {
+    if !version.pre.is_empty() {
+        return;
+    }
+
+    let prereleases = changelog
+        .get_prereleases(version.to_string())
+        .map(|prerelease| prerelease.version().to_owned())
+        .collect::<Vec<_>>();
+
+    for prerelease in prereleases {
+        if let Some(prerelease) = changelog.remove_release(prerelease) {
+            release.merge(prerelease);
+        }
+    }
+}
+
+/// The staged package manifests.
+///
+/// The manifests are loaded from the project as they are first changed so that
+/// packages sharing a manifest, such as a cargo workspace and its root package,
+/// are written once with every change applied.
+struct Manifests<'a, T> {
+    project: &'a Project<T>,
+    manifests: BTreeMap<RelativePathBuf, (String, Manifest)>,
+}
+
+impl<'a, T> Manifests<'a, T>
+where
+    T: Repository,
+{
+    /// Constructs a new set of staged manifests.
+    fn new(project: &'a Project<T>) -> Self {
+        Self {
+            project,
+            manifests: BTreeMap::new(),
+        }
+    }
+
+    /// Gets the mutable manifest at the given path.
+    fn get_mut(&mut self, path: RelativePathBuf, kind: PackageKind) -> Option<&mut Manifest> {
+        match self.manifests.entry(path) {
+            Entry::Occupied(entry) => Some(&mut entry.into_mut().1),
+            Entry::Vacant(entry) => {
+                let bytes = self.project.repository.get_file(entry.key()).ok()??;
+                let manifest = Manifest::from_bytes(kind, &bytes).ok()?;
+
+                Some(&mut entry.insert((manifest.to_string(), manifest)).1)
+            }
+        }
+    }
+
+    /// Sets the version of the package at the given path.
+    ///
+    /// Cargo packages that inherit the version from the workspace have the
+    /// version set in the root manifest instead.
+    fn set_package_version(&mut self, path: &RelativePath, kind: PackageKind, version: &Version) {
+        let Some(manifest) = self.get_mut(path.join(kind.file_name()), kind) else {
+            return;
+        };
+
+        match manifest {
+            Manifest::Cargo(cargo) => {
+                let inherited = cargo
+                    .package()
+                    .is_some_and(|package| package.is_inherited("version"));
+
+                match inherited {
+                    true => {
+                        if let Some(Manifest::Cargo(root)) =
+                            self.get_mut("Cargo.toml".into(), PackageKind::Cargo)
+                            && let Some(mut package) = root.workspace_mut().package_mut()
+                        {
+                            package.set_version(version.clone());
+                        }
+                    }
+                    false => {
+                        if let Some(mut package) = cargo.package_mut() {
+                            package.set_version(version.clone());
+                        }
+                    }
+                }
+            }
+            Manifest::Npm(npm) => {
+                npm.set_version(version.clone());
+            }
+            Manifest::Python(python) => {
+                python.set_version(version.clone());
+            }
+            Manifest::Go(_) => {}
+        }
+    }
+
+    /// Sets the version of a dependency of the package at the given path.
+    fn set_dependency_version(
+        &mut self,
+        path: &RelativePath,
+        kind: PackageKind,
+        name: &str,
+        version: &Version,
+    ) {
+        let Some(manifest) = self.get_mut(path.join(kind.file_name()), kind) else {
+            return;
+        };
+
+        if let Some(mut dependency) = manifest.get_dependency_mut(name) {
+            dependency.set_version(version.clone());
+        }
+
+        if let Some(mut dependency) = manifest.get_dev_dependency_mut(name) {
+            dependency.set_version(version.clone());
         }
+
+        if let Some(mut dependency) = manifest.get_build_dependency_mut(name) {
+            dependency.set_version(version.clone());
+        }
+    }
+
+    /// Sets the version of a dependency in the cargo workspace manifest.
+    fn set_workspace_dependency_version(&mut self, name: &str, version: &Version) {
+        if let Some(Manifest::Cargo(root)) = self.get_mut("Cargo.toml".into(), PackageKind::Cargo)
+            && let Some(mut dependencies) = root.workspace_mut().dependencies_mut()
+            && let Some(mut dependency) = dependencies.get_mut(name)
+        {
+            dependency.set_version(version.clone());
+        }
+    }
+
+    /// Gets the changed manifest files.
+    ///
+    /// Inherited dependencies are left unchanged so the manifests are compared
+    /// against the originals to determine whether anything was updated.
+    fn into_files(self) -> Vec<(RelativePathBuf, String)> {
+        self.manifests
+            .into_iter()
+            .filter_map(|(path, (original, manifest))| {
+                let manifest = manifest.to_string();
+
+                (manifest != original).then_some((path, manifest))
+            })
+            .collect()
+    }
+}
+
+/// The bumped dependent packages and their new versions.
+type Dependents<'a, T> = Vec<(Package<&'a T>, Version)>;
+
+/// Bumps the packages that depend on the released packages.
+///
+/// This visits the packages in dependency order so that each dependent is
+/// bumped once, with a minor bump if any of the updated dependencies are not
+/// semver compatible with the previous version or a patch bump otherwise.
+fn bump_dependents<'a, T>(
+    graph: &DependencyGraph,
+    packages: &[Package<&'a T>],
+    released: &[(&str, &Version, &Version)],
+) -> Result<Dependents<'a, T>, crate::project::Error<T::Error>>
+where
+    T: Repository,
+{
+    let mut breaking = released
+        .iter()
+        .map(|(name, previous, version)| ((*name).to_owned(), is_breaking(previous, version)))
+        .collect::<HashMap<_, _>>();
+    let mut dependents = Vec::new();
+
+    for dependent in graph.topological_order()? {
+        if released.iter().any(|(name, _, _)| *name == dependent) {
+            continue;
+        }
+
+        let Some(breaking_dependency) = graph
+            .dependencies(dependent)
+            .filter_map(|dependency| breaking.get(dependency).copied())
+            .reduce(|lhs, rhs| lhs || rhs)
+        else {
+            continue;
+        };
+
+        let Some(package) = packages
+            .iter()
+            .find(|package| package.name() == dependent && package.kind() != PackageKind::Go)
+        else {
+            continue;
+        };
+
+        let mut package = package.clone();
+        let previous = package.version();
+
+        match breaking_dependency {
+            true => package.bump_version(Bump::Minor)?,
+            false => package.bump_version(Bump::Patch)?,
+        };
+
+        let version = package.version();
+
+        breaking.insert(dependent.to_owned(), is_breaking(&previous, &version));
+        dependents.push((package, version));
+    }
+
+    Ok(dependents)
+}
+
+/// Adds a change for an updated dependency to the release.
+fn add_dependency_change(release: &mut Release, name: &str, version: &Version) {
+    let change = Change::new(format!("Update `{name}` to `{version}`"));
+
+    match release.get_changeset_mut("Changed") {
+        Some(changeset) => {
+            changeset.add_change(change);
+        }
+        None => {
+            release.add_changeset(Changeset::changed().with_change(change));
+        }
+    }
+}
+
+/// Checks whether the new version is not semver compatible with the previous.
+fn is_breaking(previous: &Version, version: &Version) -> bool {
+    match (previous.major, previous.minor) {
+        (0, 0) => version.major != 0 || version.minor != 0 || version.patch != previous.patch,
+        (0, _) => version.major != 0 || version.minor != previous.minor,
+        _ => version.major != previous.major,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use relative_path::RelativePath;
+    use semver::Version;
+
+    use crate::package::PackageKind;
+    use crate::project::Project;
+    use crate::project::graph::tests::package;
+
+    use super::{Manifests, ReleaseRequest, bump_dependents, is_breaking};
+
+    #[test]
+    fn test_is_breaking() {
+        assert!(is_breaking(&Version::new(0, 0, 1), &Version::new(0, 0, 2)));
+        assert!(is_breaking(&Version::new(0, 1, 0), &Version::new(0, 2, 0)));
+        assert!(!is_breaking(&Version::new(0, 1, 0), &Version::new(0, 1, 1)));
+        assert!(is_breaking(&Version::new(1, 2, 3), &Version::new(2, 0, 0)));
+        assert!(!is_breaking(&Version::new(1, 2, 3), &Version::new(1, 3, 0)));
+    }
+
+    #[test]
+    fn test_parse_packages() {
+        let body = indoc::indoc! {"
+            Releasing the following packages:
+
+            - `example` version `0.2.0`
+            - `@example/example-one` version `1.0.0-rc.1`
+
+            <!-- ploys-release: example@0.2.0, @example/example-one@1.0.0-rc.1 -->
+        "};
+
+        assert_eq!(
+            ReleaseRequest::parse_packages(body),
+            Some(vec![
+                ("example".to_owned(), Version::new(0, 2, 0)),
+                (
+                    "@example/example-one".to_owned(),
+                    "1.0.0-rc.1".parse().unwrap()
+                ),
+            ])
+        );
+        assert_eq!(ReleaseRequest::parse_packages("Release notes."), None);
+        assert_eq!(
+            ReleaseRequest::parse_packages("<!-- ploys-release: example -->"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bump_dependents() {
+        let project = Project::new("example")
+            .with_package(package("example-one", Version::new(0, 1, 0), &[]))
+            .unwrap()
+            .with_package(package(
+                "example-two",
+                Version::new(1, 0, 0),
+                &["example-one"],
+            ))
+            .unwrap()
+            .with_package(package(
+                "example-three",
+                Version::new(0, 3, 0),
+                &["example-two"],
+            ))
+            .unwrap()
+            .with_package(package("example-four", Version::new(0, 4, 0), &[]))
+            .unwrap();
+
+        let graph = project.dependency_graph();
+        let packages = project.packages().collect::<Vec<_>>();
+
+        let dependents = bump_dependents(
+            &graph,
+            &packages,
+            &[(
+                "example-one",
+                &Version::new(0, 1, 0),
+                &Version::new(0, 2, 0),
+            )],
+        )
+        .unwrap()
+        .into_iter()
+        .map(|(package, version)| (package.name().to_owned(), version))
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            dependents,
+            [
+                ("example-two".to_owned(), Version::new(1, 1, 0)),
+                ("example-three".to_owned(), Version::new(0, 3, 1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_manifests() {
+        let project = Project::new("example")
+            .with_package(package("example-one", Version::new(0, 1, 0), &[]))
+            .unwrap()
+            .with_package(package(
+                "example-two",
+                Version::new(0, 1, 0),
+                &["example-one"],
+            ))
+            .unwrap();
+
+        let mut manifests = Manifests::new(&project);
+
+        manifests.set_package_version(
+            RelativePath::new("packages/example-one"),
+            PackageKind::Cargo,
+            &Version::new(0, 2, 0),
+        );
+        manifests.set_dependency_version(
+            RelativePath::new("packages/example-two"),
+            PackageKind::Cargo,
+            "example-one",
+            &Version::new(0, 2, 0),
+        );
+        manifests.set_dependency_version(
+            RelativePath::new("packages/example-two"),
+            PackageKind::Cargo,
+            "example-three",
+            &Version::new(0, 2, 0),
+        );
+        manifests.set_workspace_dependency_version("example-one", &Version::new(0, 2, 0));
+
+        let files = manifests.into_files();
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].0, "packages/example-one/Cargo.toml");
+        assert!(files[0].1.contains("version = \"0.2.0\""));
+        assert_eq!(files[1].0, "packages/example-two/Cargo.toml");
+        assert!(files[1].1.contains("version = \"0.2.0\""));
     }
 }
@@ -0,0 +1,345 @@
+use std::fmt::{self, Display};
+
+use minijinja::Environment;
+use relative_path::RelativePathBuf;
+use serde::Serialize;
+use strum::EnumIter;
+
+use crate::changelog::Changeset;
+
+/// The release template kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter)]
+pub enum TemplateKind {
+    /// The release request branch name.
+    Branch,
+    /// The release request title.
+    PullRequestTitle,
+    /// The release request body.
+    PullRequestBody,
+    /// The release name.
+    ReleaseName,
+    /// The release body.
+    ReleaseBody,
+}
+
+impl TemplateKind {
+    /// Gets the template name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Branch => "branch",
+            Self::PullRequestTitle => "pull-request-title",
+            Self::PullRequestBody => "pull-request-body",
+            Self::ReleaseName => "release-name",
+            Self::ReleaseBody => "release-body",
+        }
+    }
+
+    /// Gets the template file path in the repository.
+    pub fn file_path(&self) -> RelativePathBuf {
+        RelativePathBuf::from(format!(".ploys/templates/{}.jinja", self.name()))
+    }
+
+    /// Checks whether the rendered template is a single line.
+    fn is_single_line(&self) -> bool {
+        matches!(
+            self,
+            Self::Branch | Self::PullRequestTitle | Self::ReleaseName
+        )
+    }
+}
+
+/// The release templates.
+///
+/// These replace the default release request and release text with templates
+/// in the Jinja syntax. Each template is rendered with the following context:
+///
+/// | Variable           | Description                                       |
+/// | ------------------ | ------------------------------------------------- |
+/// | `package`          | The package name.                                 |
+/// | `version`          | The release version.                              |
+/// | `previous_version` | The previously tagged version if one exists.      |
+/// | `changesets`       | The changesets with their `label` and `changes`.  |
+/// | `compare_url`      | The URL comparing the previous and new releases.  |
+/// | `packages`         | Each released package with the variables above.   |
+///
+/// The top-level variables refer to the first package when releasing more
+/// than one package. The branch, title and release name are trimmed and the
+/// branch is prefixed with `release/` when the template does not include it.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Templates(Vec<(TemplateKind, String)>);
+
+impl Templates {
+    /// Constructs a new empty set of templates.
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Gets an iterator over the templates.
+    pub fn templates(&self) -> impl Iterator<Item = (TemplateKind, &str)> {
+        self.0
+            .iter()
+            .map(|(kind, template)| (*kind, template.as_str()))
+    }
+
+    /// Gets the template of the given kind.
+    pub fn get_template(&self, kind: TemplateKind) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(current, _)| *current == kind)
+            .map(|(_, template)| template.as_str())
+    }
+
+    /// Adds the template of the given kind.
+    pub fn add_template(&mut self, kind: TemplateKind, template: impl Into<String>) -> &mut Self {
+        let template = template.into();
+
+        match self.0.iter_mut().find(|(current, _)| *current == kind) {
+            Some((_, current)) => *current = template,
+            None => self.0.push((kind, template)),
+        }
+
+        self
+    }
+
+    /// Builds the templates with the given template.
+    pub fn with_template(mut self, kind: TemplateKind, template: impl Into<String>) -> Self {
+        self.add_template(kind, template);
+        self
+    }
+
+    /// Renders the template of the given kind if it exists.
+    pub(super) fn render(
+        &self,
+        kind: TemplateKind,
+        context: &TemplateContext,
+    ) -> Result<Option<String>, Error> {
+        let Some(template) = self.get_template(kind) else {
+            return Ok(None);
+        };
+
+        let output = Environment::new()
+            .render_str(template, context)
+            .map_err(|err| Error::Render(kind, err.to_string()))?;
+
+        match kind {
+            TemplateKind::Branch => {
+                let output = output.trim();
+                let branch = match output.starts_with("release/") {
+                    true => output.to_owned(),
+                    false => format!("release/{output}"),
+                };
+
+                match is_valid_branch(&branch) {
+                    true => Ok(Some(branch)),
+                    false => Err(Error::InvalidBranch(branch)),
+                }
+            }
+            _ if kind.is_single_line() => Ok(Some(output.trim().to_owned())),
+            _ => Ok(Some(output)),
+        }
+    }
+}
+
+/// Checks whether the branch name is a valid git reference.
+///
+/// This follows the rules of `git check-ref-format --branch` and requires a
+/// name after the `release/` prefix.
+fn is_valid_branch(branch: &str) -> bool {
+    let Some(name) = branch.strip_prefix("release/") else {
+        return false;
+    };
+
+    !name.is_empty()
+        && !branch.contains("..")
+        && !branch.contains("@{")
+        && !branch.ends_with(".lock")
+        && !branch.ends_with('.')
+        && !branch.chars().any(|c| {
+            c.is_ascii_control() || matches!(c, ' ' | '~' | '^' | ':' | '?' | '*' | '[' | '\\')
+        })
+        && branch
+            .split('/')
+            .all(|part| !part.is_empty() && !part.starts_with('.'))
+}
+
+/// The release template error.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A template that failed to render with the given message.
+    Render(TemplateKind, String),
+    /// A rendered branch name that is not a valid git reference.
+    InvalidBranch(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Render(kind, message) => {
+                write!(f, "Invalid `{}` template: {message}", kind.name())
+            }
+            Self::InvalidBranch(branch) => write!(f, "Invalid release branch `{branch}`"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The release template context.
+#[derive(Clone, Debug, Serialize)]
+pub(super) struct TemplateContext {
+    #[serde(flatten)]
+    package: PackageContext,
+    packages: Vec<PackageContext>,
+}
+
+impl TemplateContext {
+    /// Constructs a new template context from the released packages.
+    ///
+    /// The fields of the first package are also available at the top level.
+    pub(super) fn new(
+        package: PackageContext,
+        packages: impl IntoIterator<Item = PackageContext>,
+    ) -> Self {
+        Self {
+            packages: std::iter::once(package.clone()).chain(packages).collect(),
+            package,
+        }
+    }
+}
+
+/// The release template context for a single package.
+#[derive(Clone, Debug, Serialize)]
+pub(super) struct PackageContext {
+    package: String,
+    version: String,
+    previous_version: Option<String>,
+    changesets: Vec<Changeset>,
+    compare_url: Option<String>,
+}
+
+impl PackageContext {
+    /// Constructs a new package context.
+    pub(super) fn new(
+        package: impl Into<String>,
+        version: impl ToString,
+        previous_version: Option<impl ToString>,
+        changesets: impl IntoIterator<Item = Changeset>,
+        compare_url: Option<String>,
+    ) -> Self {
+        Self {
+            package: package.into(),
+            version: version.to_string(),
+            previous_version: previous_version.map(|version| version.to_string()),
+            changesets: changesets.into_iter().collect(),
+            compare_url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::changelog::{Change, Changeset};
+
+    use super::{Error, PackageContext, TemplateContext, TemplateKind, Templates};
+
+    #[test]
+    fn test_templates() {
+        let templates = Templates::new()
+            .with_template(TemplateKind::PullRequestTitle, "Release {{ version }}\n")
+            .with_template(
+                TemplateKind::ReleaseBody,
+                indoc::indoc! {"
+                    {% for changeset in changesets %}
+                    ### {{ changeset.label }}
+                    {% for change in changeset.changes %}
+                    - {{ change.message }}{% for author in change.authors %} @{{ author }}{% endfor %}
+                    {% endfor %}
+                    {% endfor %}
+                    Compare: {{ compare_url }}
+                "},
+            );
+
+        let context = TemplateContext::new(
+            PackageContext::new(
+                "example",
+                "0.2.0",
+                Some("0.1.0"),
+                [Changeset::fixed().with_change(Change::new("Fixed one").with_author("one"))],
+                Some(String::from(
+                    "https://github.com/ploys/example/compare/0.1.0...0.2.0",
+                )),
+            ),
+            [],
+        );
+
+        assert_eq!(
+            templates
+                .render(TemplateKind::PullRequestTitle, &context)
+                .unwrap()
+                .as_deref(),
+            Some("Release 0.2.0")
+        );
+        assert_eq!(
+            templates
+                .render(TemplateKind::ReleaseBody, &context)
+                .unwrap()
+                .as_deref(),
+            Some(
+                "\n### Fixed\n\n- Fixed one @one\n\n\nCompare: https://github.com/ploys/example/compare/0.1.0...0.2.0"
+            )
+        );
+        assert_eq!(
+            templates.render(TemplateKind::Branch, &context).unwrap(),
+            None
+        );
+
+        let templates = templates.with_template(TemplateKind::Branch, "{% if %}");
+
+        assert!(matches!(
+            templates.render(TemplateKind::Branch, &context),
+            Err(Error::Render(TemplateKind::Branch, _))
+        ));
+
+        let templates =
+            templates.with_template(TemplateKind::Branch, " {{ package }}-{{ version }}\n");
+
+        assert_eq!(
+            templates
+                .render(TemplateKind::Branch, &context)
+                .unwrap()
+                .as_deref(),
+            Some("release/example-0.2.0")
+        );
+
+        let templates = templates.with_template(TemplateKind::Branch, "release/{{ version }}");
+
+        assert_eq!(
+            templates
+                .render(TemplateKind::Branch, &context)
+                .unwrap()
+                .as_deref(),
+            Some("release/0.2.0")
+        );
+
+        for template in [
+            "",
+            "release/",
+            "{{ package }} {{ version }}",
+            "../{{ version }}",
+        ] {
+            let templates = templates
+                .clone()
+                .with_template(TemplateKind::Branch, template);
+
+            assert!(matches!(
+                templates.render(TemplateKind::Branch, &context),
+                Err(Error::InvalidBranch(_))
+            ));
+        }
+
+        assert_eq!(
+            TemplateKind::ReleaseBody.file_path(),
+            ".ploys/templates/release-body.jinja"
+        );
+    }
+}
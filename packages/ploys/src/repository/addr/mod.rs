@@ -13,10 +13,13 @@ pub use self::error::Error;
 pub struct RepoAddr(Url);
 
 impl RepoAddr {
-    /// Constructs a new repository address.
+    /// Constructs a new repository address on `github.com`.
     ///
     /// This requires that both `owner` and `name` match the following regular
     /// expression: `^[a-zA-Z0-9\-_\.]+$`
+    ///
+    /// See [`RepoAddr::with_host`] for repositories on other hosts such as a
+    /// GitHub Enterprise Server.
     pub fn new(owner: impl AsRef<str>, name: impl AsRef<str>) -> Result<Self, Error> {
         let owner = owner.as_ref();
         let name = name.as_ref();
@@ -34,23 +37,56 @@ impl RepoAddr {
         Ok(Self(Url::parse(&url).expect("valid url")))
     }
 
+    /// Constructs a new repository address on the given host.
+    ///
+    /// The host may include the scheme and port and otherwise defaults to
+    /// `https`. The `owner` may contain nested groups separated by `/` and each
+    /// group has the same requirements as the `name`.
+    pub fn with_host(
+        host: impl AsRef<str>,
+        owner: impl AsRef<str>,
+        name: impl AsRef<str>,
+    ) -> Result<Self, Error> {
+        let host = host.as_ref().trim_end_matches('/');
+        let owner = owner.as_ref().trim_matches('/');
+        let name = name.as_ref();
+
+        let url = match host.contains("://") {
+            true => format!("{host}/{owner}/{name}"),
+            false => format!("https://{host}/{owner}/{name}"),
+        };
+
+        match Url::parse(&url) {
+            Ok(url) => Self::try_from(url),
+            Err(_) => Err(Error::invalid(url)),
+        }
+    }
+
+    /// Gets the repository host.
+    pub fn host(&self) -> &str {
+        self.0.host_str().expect("valid host")
+    }
+
+    /// Gets the repository base URL with the scheme, host and port.
+    pub fn base_url(&self) -> &str {
+        &self.0[..url::Position::BeforePath]
+    }
+
     /// Gets the repository owner.
+    ///
+    /// This includes every group for repositories nested in subgroups.
     pub fn owner(&self) -> &str {
-        self.0
-            .path()
-            .trim_start_matches("/")
-            .split('/')
-            .next()
+        self.full_name()
+            .rsplit_once('/')
+            .map(|(owner, _)| owner)
             .expect("valid owner")
     }
 
     /// Gets the repository name.
     pub fn name(&self) -> &str {
-        self.0
-            .path()
-            .trim_start_matches("/")
-            .split('/')
-            .nth(1)
+        self.full_name()
+            .rsplit_once('/')
+            .map(|(_, name)| name)
             .expect("valid name")
     }
 
@@ -121,10 +157,14 @@ impl FromStr for RepoAddr {
     type Err = Error;
 
     fn from_str(addr: &str) -> Result<Self, Self::Err> {
-        match addr
-            .trim_start_matches("https://github.com/")
-            .split_once('/')
-        {
+        if addr.contains("://") {
+            return match Url::parse(addr) {
+                Ok(url) => Self::try_from(url),
+                Err(_) => Err(Error::invalid(addr)),
+            };
+        }
+
+        match addr.split_once('/') {
             Some((owner, name)) => Self::new(owner, name),
             None => Err(Error::invalid(addr)),
         }
@@ -158,23 +198,42 @@ impl TryFrom<String> for RepoAddr {
 impl TryFrom<Url> for RepoAddr {
     type Error = Error;
 
+    /// Converts the repository URL into an address.
+    ///
+    /// GitHub repositories must have exactly an owner and name whereas other
+    /// hosts may nest the repository in any number of groups.
     fn try_from(url: Url) -> Result<Self, Self::Error> {
-        match url.as_str().starts_with("https://github.com/") {
-            true => {
-                if url.query().is_none()
-                    && url.fragment().is_none()
-                    && let Some((owner, name)) = url.path().trim_start_matches("/").split_once('/')
-                    && !owner.is_empty()
-                    && owner.chars().all(is_valid_char)
-                    && !name.is_empty()
-                    && name.chars().all(is_valid_char)
-                {
-                    Ok(Self(url))
-                } else {
-                    Err(Error::invalid(url))
-                }
-            }
-            false => Err(Error::invalid(url)),
+        if !matches!(url.scheme(), "https" | "http")
+            || url.host_str().is_none()
+            || url.query().is_some()
+            || url.fragment().is_some()
+        {
+            return Err(Error::invalid(url));
+        }
+
+        let segments = url
+            .path()
+            .trim_start_matches("/")
+            .split('/')
+            .collect::<Vec<_>>();
+
+        let is_github = url
+            .host_str()
+            .is_some_and(|host| host.eq_ignore_ascii_case("github.com"));
+
+        let is_valid = match is_github {
+            true => segments.len() == 2,
+            false => segments.len() >= 2,
+        };
+
+        if is_valid
+            && segments
+                .iter()
+                .all(|segment| !segment.is_empty() && segment.chars().all(is_valid_char))
+        {
+            Ok(Self(url))
+        } else {
+            Err(Error::invalid(url))
         }
     }
 }
@@ -209,5 +268,41 @@ mod tests {
             repo_addr.url(),
             &Url::parse("https://github.com/owner/name").unwrap()
         );
+        assert_eq!(repo_addr.host(), "github.com");
+        assert_eq!(repo_addr.base_url(), "https://github.com");
+    }
+
+    #[test]
+    fn test_host() {
+        let repo_addr = RepoAddr::with_host("gitlab.com", "group/subgroup", "name").unwrap();
+
+        assert_eq!(repo_addr.owner(), "group/subgroup");
+        assert_eq!(repo_addr.name(), "name");
+        assert_eq!(repo_addr.full_name(), "group/subgroup/name");
+        assert_eq!(repo_addr.host(), "gitlab.com");
+        assert_eq!(repo_addr.base_url(), "https://gitlab.com");
+
+        let repo_addr = RepoAddr::with_host("http://localhost:8080/", "owner", "name").unwrap();
+
+        assert_eq!(repo_addr.base_url(), "http://localhost:8080");
+        assert_eq!(repo_addr.url().as_str(), "http://localhost:8080/owner/name");
+
+        assert!(
+            "https://gitlab.com/group/subgroup/name"
+                .parse::<RepoAddr>()
+                .is_ok()
+        );
+        assert!(
+            "https://github.com/one/two/three"
+                .parse::<RepoAddr>()
+                .is_err()
+        );
+        assert!(
+            "http://GitHub.com/one/two/three"
+                .parse::<RepoAddr>()
+                .is_err()
+        );
+        assert!("ftp://gitlab.com/owner/name".parse::<RepoAddr>().is_err());
+        assert!(RepoAddr::with_host("gitlab.com", "", "name").is_err());
     }
 }
@@ -1,6 +1,6 @@
 use semver::Version;
 
-use crate::changelog::Release;
+use crate::changelog::{LabelMapping, Release};
 
 use super::GitLike;
 
@@ -10,13 +10,37 @@ use super::GitLike;
 /// handling.
 pub trait Remote: GitLike {
     /// Gets the changelog release for the given package version.
+    ///
+    /// The tag prefix is prepended to each version to find the package tags
+    /// and the label mapping groups the changes into changesets.
     fn get_changelog_release(
         &self,
         package: &str,
         version: &Version,
-        is_primary: bool,
+        tag_prefix: &str,
+        labels: &LabelMapping,
     ) -> Result<Release, Self::Error>;
 
+    /// Gets the names of all tags.
+    fn get_tags(&self) -> Result<Vec<String>, Self::Error>;
+
+    /// Gets the change messages since the latest release of the given package.
+    ///
+    /// These are the messages of the changes that would be included in the
+    /// next release, such as pull request titles and descriptions.
+    fn get_unreleased_changes(
+        &self,
+        package: &str,
+        tag_prefix: &str,
+    ) -> Result<Vec<String>, Self::Error>;
+
+    /// Gets the URL comparing the given revisions.
+    ///
+    /// This returns `None` by default for repositories without a web interface.
+    fn get_compare_url(&self, _base: &str, _head: &str) -> Option<String> {
+        None
+    }
+
     /// Creates a pull request.
     fn create_pull_request(
         &self,
@@ -46,9 +70,26 @@ where
         &self,
         package: &str,
         version: &Version,
-        is_primary: bool,
+        tag_prefix: &str,
+        labels: &LabelMapping,
     ) -> Result<Release, Self::Error> {
-        (**self).get_changelog_release(package, version, is_primary)
+        (**self).get_changelog_release(package, version, tag_prefix, labels)
+    }
+
+    fn get_tags(&self) -> Result<Vec<String>, Self::Error> {
+        (**self).get_tags()
+    }
+
+    fn get_unreleased_changes(
+        &self,
+        package: &str,
+        tag_prefix: &str,
+    ) -> Result<Vec<String>, Self::Error> {
+        (**self).get_unreleased_changes(package, tag_prefix)
+    }
+
+    fn get_compare_url(&self, base: &str, head: &str) -> Option<String> {
+        (**self).get_compare_url(base, head)
     }
 
     fn create_pull_request(
@@ -82,9 +123,26 @@ where
         &self,
         package: &str,
         version: &Version,
-        is_primary: bool,
+        tag_prefix: &str,
+        labels: &LabelMapping,
     ) -> Result<Release, Self::Error> {
-        (**self).get_changelog_release(package, version, is_primary)
+        (**self).get_changelog_release(package, version, tag_prefix, labels)
+    }
+
+    fn get_tags(&self) -> Result<Vec<String>, Self::Error> {
+        (**self).get_tags()
+    }
+
+    fn get_unreleased_changes(
+        &self,
+        package: &str,
+        tag_prefix: &str,
+    ) -> Result<Vec<String>, Self::Error> {
+        (**self).get_unreleased_changes(package, tag_prefix)
+    }
+
+    fn get_compare_url(&self, base: &str, head: &str) -> Option<String> {
+        (**self).get_compare_url(base, head)
     }
 
     fn create_pull_request(
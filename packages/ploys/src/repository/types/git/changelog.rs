@@ -0,0 +1,349 @@
+use gix::ObjectId;
+use semver::Version;
+use time::OffsetDateTime;
+use time::format_description::well_known::Iso8601;
+
+use crate::changelog::{Change, Changeset, LabelMapping, Release};
+
+use super::Error;
+
+/// Gets the changelog release for the given package version.
+///
+/// Each commit subject is parsed as a conventional commit so that the commit
+/// type and scope can stand in for the `type: ` and `package: ` labels.
+pub(super) fn get_release(
+    repository: &gix::Repository,
+    package: &str,
+    version: &Version,
+    tag_prefix: &str,
+    labels: &LabelMapping,
+) -> Result<Release, Error> {
+    let tags = get_tags(repository)?;
+    let tagname = format!("{tag_prefix}{version}");
+
+    let tag = match tags.contains(&tagname) {
+        true => Some(get_tag_commit(repository, &tagname)?),
+        false => None,
+    };
+
+    let prev_version = get_previous_version(tag_prefix, version, &tags);
+    let prev_tag = prev_version
+        .as_ref()
+        .map(|version| format!("{tag_prefix}{version}"));
+
+    let timestamp = match tag {
+        Some(id) => {
+            let seconds = repository.find_object(id)?.into_commit().time()?.seconds;
+
+            OffsetDateTime::from_unix_timestamp(seconds)
+                .unwrap_or_else(|_| OffsetDateTime::now_utc())
+        }
+        None => OffsetDateTime::now_utc(),
+    };
+
+    let commits = match prev_tag {
+        Some(_) if prev_version.expect("prev") > *version => Vec::new(),
+        Some(from) => self::between(repository, Some(&from), tag)?,
+        None => self::between(repository, None, tag)?,
+    };
+
+    let mut release = Release::new(version.to_string());
+    let mut changesets = Vec::<Changeset>::new();
+
+    release.set_date(timestamp.format(&Iso8601::DATE).expect("date"));
+
+    for commit in filter_commits(commits, package) {
+        let label = labels.get_changeset_label(commit.labels.iter().map(String::as_str));
+
+        let index = match changesets
+            .iter()
+            .position(|changeset| changeset.label() == label)
+        {
+            Some(index) => index,
+            None => {
+                changesets.push(Changeset::new(label));
+                changesets.len() - 1
+            }
+        };
+
+        changesets[index].add_change(Change::new(commit.description));
+    }
+
+    LabelMapping::sort_changesets(&mut changesets);
+
+    for changeset in changesets {
+        release.add_changeset(changeset);
+    }
+
+    Ok(release)
+}
+
+/// Gets the change messages since the latest release of the given package.
+pub(super) fn get_unreleased_changes(
+    repository: &gix::Repository,
+    package: &str,
+    tag_prefix: &str,
+) -> Result<Vec<String>, Error> {
+    let latest = get_tags(repository)?
+        .into_iter()
+        .filter_map(|tag| tag.strip_prefix(tag_prefix)?.parse::<Version>().ok())
+        .max();
+
+    let commits = match latest {
+        Some(version) => self::between(repository, Some(&format!("{tag_prefix}{version}")), None)?,
+        None => self::between(repository, None, None)?,
+    };
+
+    Ok(filter_commits(commits, package)
+        .map(|commit| commit.message)
+        .collect())
+}
+
+/// Gets the names of all tags.
+pub(super) fn get_tags(repository: &gix::Repository) -> Result<Vec<String>, Error> {
+    let mut tags = Vec::new();
+
+    for reference in repository.references()?.tags()? {
+        tags.push(reference?.name().shorten().to_string());
+    }
+
+    Ok(tags)
+}
+
+/// Gets the commit that the given tag points to.
+fn get_tag_commit(repository: &gix::Repository, tag: &str) -> Result<ObjectId, Error> {
+    Ok(repository
+        .rev_parse_single(&*format!("refs/tags/{tag}"))?
+        .object()?
+        .peel_to_commit()?
+        .id)
+}
+
+/// Gets the previous version or last version if no previous exists.
+fn get_previous_version(tag_prefix: &str, version: &Version, tags: &[String]) -> Option<Version> {
+    let mut versions = tags
+        .iter()
+        .filter_map(|tag| tag.strip_prefix(tag_prefix)?.parse::<Version>().ok())
+        .collect::<Vec<_>>();
+
+    versions.sort();
+
+    let previous_version = versions
+        .iter()
+        .filter(|previous_version| *previous_version < version)
+        .rfind(|previous_version| version.pre.is_empty() || previous_version.pre.is_empty());
+
+    match previous_version {
+        Some(previous_version) => Some(previous_version.clone()),
+        None => match versions.last() {
+            Some(last_version) if last_version != version => Some(last_version.clone()),
+            _ => None,
+        },
+    }
+}
+
+/// Gets the commits after the `from` tag up to the `to` commit or `HEAD`.
+fn between(
+    repository: &gix::Repository,
+    from: Option<&str>,
+    to: Option<ObjectId>,
+) -> Result<Vec<GitCommit>, Error> {
+    let to = match to {
+        Some(to) => to,
+        None => match repository.head()?.is_unborn() {
+            true => return Ok(Vec::new()),
+            false => repository.head_commit()?.id,
+        },
+    };
+
+    let hidden = match from {
+        Some(from) => Some(get_tag_commit(repository, from)?),
+        None => None,
+    };
+
+    let mut commits = Vec::new();
+
+    for info in repository.rev_walk([to]).with_hidden(hidden).all()? {
+        let info = info?;
+
+        if info.parent_ids.len() > 1 {
+            continue;
+        }
+
+        let commit = info.object()?;
+
+        commits.push(GitCommit::new(
+            commit.message_raw_sloppy().to_string().trim(),
+        ));
+    }
+
+    Ok(commits)
+}
+
+/// Filters the commits to those that belong to the given package.
+///
+/// Commits without a scope belong to every package and release commits are
+/// excluded.
+fn filter_commits(commits: Vec<GitCommit>, package: &str) -> impl Iterator<Item = GitCommit> {
+    let package_label = format!("package: {package}");
+
+    commits
+        .into_iter()
+        .filter(move |commit| {
+            !commit
+                .labels
+                .iter()
+                .any(|label| label.starts_with("package: "))
+                || commit.labels.contains(&package_label)
+        })
+        .filter(|commit| {
+            !commit.message.starts_with("Release ")
+                && !commit.labels.iter().any(|label| label == "type: release")
+        })
+}
+
+/// A local commit.
+#[derive(Debug)]
+struct GitCommit {
+    message: String,
+    description: String,
+    labels: Vec<String>,
+}
+
+impl GitCommit {
+    /// Constructs a new commit from the commit message.
+    fn new(message: &str) -> Self {
+        let subject = message.lines().next().unwrap_or_default().trim();
+
+        let Some((prefix, description)) = subject.split_once(": ") else {
+            return Self {
+                message: message.to_owned(),
+                description: subject.to_owned(),
+                labels: Vec::new(),
+            };
+        };
+
+        let prefix = prefix.trim_end_matches('!');
+        let (kind, scope) = match prefix.split_once('(') {
+            Some((kind, scope)) => (kind, scope.strip_suffix(')')),
+            None => (prefix, None),
+        };
+
+        if kind.is_empty() || !kind.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Self {
+                message: message.to_owned(),
+                description: subject.to_owned(),
+                labels: Vec::new(),
+            };
+        }
+
+        let mut labels = vec![match kind.to_ascii_lowercase().as_str() {
+            "feat" => String::from("type: feature"),
+            kind => format!("type: {kind}"),
+        }];
+
+        if let Some(scope) = scope {
+            labels.push(format!("package: {scope}"));
+        }
+
+        Self {
+            message: message.to_owned(),
+            description: description.trim().to_owned(),
+            labels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+    use tempfile::tempdir;
+
+    use crate::changelog::{Change, Changeset, LabelMapping};
+    use crate::repository::types::git::{Error, Git};
+    use crate::repository::{Commit, GitLike, Remote, Repository, Stage};
+
+    use super::GitCommit;
+
+    #[test]
+    fn test_commit_labels() {
+        let commit = GitCommit::new("feat(example)!: Add a feature\n\nBody");
+
+        assert_eq!(commit.description, "Add a feature");
+        assert_eq!(commit.labels, ["type: feature", "package: example"]);
+
+        let commit = GitCommit::new("fix: Fix a bug");
+
+        assert_eq!(commit.description, "Fix a bug");
+        assert_eq!(commit.labels, ["type: fix"]);
+
+        let commit = GitCommit::new("Update the readme");
+
+        assert_eq!(commit.description, "Update the readme");
+        assert!(commit.labels.is_empty());
+
+        let commit = GitCommit::new("Release `example@0.2.0`");
+
+        assert_eq!(commit.description, "Release `example@0.2.0`");
+        assert!(commit.labels.is_empty());
+    }
+
+    #[test]
+    fn test_local_release() -> Result<(), Error> {
+        let dir = tempdir()?;
+        let mut repo = Git::init(dir.path())?;
+
+        repo.add_file("README.md", "# Example")?;
+        repo.commit("Initial commit")?;
+
+        let sha = repo.sha()?;
+
+        repo.create_release("0.1.0", &sha, "0.1.0", "", false, true)?;
+        repo.add_file("src/lib.rs", "")?;
+        repo.commit("feat(example): Add a library")?;
+        repo.add_file("src/lib.rs", "// Fixed")?;
+        repo.commit("fix(other): Fix another package")?;
+        repo.add_file("src/lib.rs", "// Fixed again")?;
+        repo.commit("fix: Fix the library")?;
+
+        assert_eq!(repo.get_tags()?, ["0.1.0"]);
+        assert_eq!(
+            repo.get_unreleased_changes("example", "")?,
+            ["fix: Fix the library", "feat(example): Add a library"]
+        );
+
+        let release = repo.get_changelog_release(
+            "example",
+            &Version::new(0, 2, 0),
+            "",
+            &LabelMapping::default(),
+        )?;
+
+        assert_eq!(
+            release.changesets().cloned().collect::<Vec<_>>(),
+            [
+                Changeset::added().with_change(Change::new("Add a library")),
+                Changeset::fixed().with_change(Change::new("Fix the library")),
+            ]
+        );
+
+        let branch = repo.get_default_branch()?;
+
+        repo.create_branch("release/0.2.0")?;
+
+        let sha = repo.commit_branch(
+            "release/0.2.0",
+            "Release `0.2.0`",
+            vec![("CHANGELOG.md".into(), Some(String::from("# Changelog")))],
+        )?;
+
+        repo.update_branch(&branch, &sha)?;
+        repo.create_release("0.2.0", &sha, "0.2.0", "Notes", false, true)?;
+
+        assert_eq!(repo.get_file("CHANGELOG.md")?, Some("# Changelog".into()));
+        assert_eq!(repo.get_tags()?, ["0.1.0", "0.2.0"]);
+        assert!(repo.get_unreleased_changes("example", "")?.is_empty());
+
+        Ok(())
+    }
+}
@@ -121,6 +121,60 @@ impl From<gix::repository::edit_tree::Error> for Error {
     }
 }
 
+impl From<gix::object::commit::Error> for Error {
+    fn from(err: gix::object::commit::Error) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
+impl From<gix::reference::iter::Error> for Error {
+    fn from(err: gix::reference::iter::Error) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
+impl From<gix::reference::iter::init::Error> for Error {
+    fn from(err: gix::reference::iter::init::Error) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
+impl From<Box<dyn std::error::Error + Send + Sync>> for Error {
+    fn from(err: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
+impl From<gix::reference::head_commit::Error> for Error {
+    fn from(err: gix::reference::head_commit::Error) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
+impl From<gix::revision::walk::Error> for Error {
+    fn from(err: gix::revision::walk::Error) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
+impl From<gix::revision::walk::iter::Error> for Error {
+    fn from(err: gix::revision::walk::iter::Error) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
+impl From<gix::reference::edit::Error> for Error {
+    fn from(err: gix::reference::edit::Error) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
+impl From<gix::tag::Error> for Error {
+    fn from(err: gix::tag::Error) -> Self {
+        Self::Gix(err.into())
+    }
+}
+
 impl From<Infallible> for Error {
     fn from(err: Infallible) -> Self {
         match err {}
@@ -158,6 +212,24 @@ pub enum GixError {
     FindReference(Box<gix::reference::find::existing::Error>),
     /// An edit tree error.
     EditTree(Box<gix::repository::edit_tree::Error>),
+    /// A commit object error.
+    CommitObject(Box<gix::object::commit::Error>),
+    /// A references error.
+    References(Box<gix::reference::iter::Error>),
+    /// A references initialization error.
+    ReferencesInit(Box<gix::reference::iter::init::Error>),
+    /// A references iteration error.
+    ReferencesIter(Box<dyn std::error::Error + Send + Sync>),
+    /// A head commit error.
+    HeadCommit(Box<gix::reference::head_commit::Error>),
+    /// A revision walk error.
+    RevWalk(Box<gix::revision::walk::Error>),
+    /// A revision walk iteration error.
+    RevWalkIter(Box<gix::revision::walk::iter::Error>),
+    /// An edit reference error.
+    EditReference(Box<gix::reference::edit::Error>),
+    /// A tag error.
+    Tag(Box<gix::tag::Error>),
 }
 
 impl Display for GixError {
@@ -177,6 +249,15 @@ impl Display for GixError {
             Self::Commit(err) => Display::fmt(err, f),
             Self::FindReference(err) => Display::fmt(err, f),
             Self::EditTree(err) => Display::fmt(err, f),
+            Self::CommitObject(err) => Display::fmt(err, f),
+            Self::References(err) => Display::fmt(err, f),
+            Self::ReferencesInit(err) => Display::fmt(err, f),
+            Self::ReferencesIter(err) => Display::fmt(err, f),
+            Self::HeadCommit(err) => Display::fmt(err, f),
+            Self::RevWalk(err) => Display::fmt(err, f),
+            Self::RevWalkIter(err) => Display::fmt(err, f),
+            Self::EditReference(err) => Display::fmt(err, f),
+            Self::Tag(err) => Display::fmt(err, f),
         }
     }
 }
@@ -266,3 +347,57 @@ impl From<gix::repository::edit_tree::Error> for GixError {
         Self::EditTree(Box::new(err))
     }
 }
+
+impl From<gix::object::commit::Error> for GixError {
+    fn from(err: gix::object::commit::Error) -> Self {
+        Self::CommitObject(Box::new(err))
+    }
+}
+
+impl From<gix::reference::iter::Error> for GixError {
+    fn from(err: gix::reference::iter::Error) -> Self {
+        Self::References(Box::new(err))
+    }
+}
+
+impl From<gix::reference::iter::init::Error> for GixError {
+    fn from(err: gix::reference::iter::init::Error) -> Self {
+        Self::ReferencesInit(Box::new(err))
+    }
+}
+
+impl From<Box<dyn std::error::Error + Send + Sync>> for GixError {
+    fn from(err: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        Self::ReferencesIter(err)
+    }
+}
+
+impl From<gix::reference::head_commit::Error> for GixError {
+    fn from(err: gix::reference::head_commit::Error) -> Self {
+        Self::HeadCommit(Box::new(err))
+    }
+}
+
+impl From<gix::revision::walk::Error> for GixError {
+    fn from(err: gix::revision::walk::Error) -> Self {
+        Self::RevWalk(Box::new(err))
+    }
+}
+
+impl From<gix::revision::walk::iter::Error> for GixError {
+    fn from(err: gix::revision::walk::iter::Error) -> Self {
+        Self::RevWalkIter(Box::new(err))
+    }
+}
+
+impl From<gix::reference::edit::Error> for GixError {
+    fn from(err: gix::reference::edit::Error) -> Self {
+        Self::EditReference(Box::new(err))
+    }
+}
+
+impl From<gix::tag::Error> for GixError {
+    fn from(err: gix::tag::Error) -> Self {
+        Self::Tag(Box::new(err))
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! This module contains the utilities related to local Git project management.
 
+mod changelog;
 mod error;
 mod params;
 
@@ -11,14 +12,18 @@ use std::path::{Path, PathBuf};
 use bytes::Bytes;
 use gix::config::File;
 use gix::create::{Kind, Options};
+use gix::refs::transaction::PreviousValue;
 use gix::traverse::tree::Recorder;
 use gix::{ObjectId, ThreadSafeRepository};
 use relative_path::{RelativePath, RelativePathBuf};
+use semver::Version;
+
+use crate::changelog::{LabelMapping, Release};
 
 use crate::repository::adapters::cached::Cached;
 use crate::repository::adapters::staged::Staged;
 use crate::repository::revision::{Reference, Revision};
-use crate::repository::{Commit, Open, Repository, Stage};
+use crate::repository::{Commit, GitLike, Open, Remote, Repository, Stage};
 
 pub use self::error::Error;
 pub use self::params::CommitParams;
@@ -228,6 +233,188 @@ impl Open for Git {
     }
 }
 
+impl GitLike for Git {
+    fn sha(&self) -> Result<String, Self::Error> {
+        match &self.inner.inner.inner().revision {
+            Revision::Sha(sha) => Ok(sha.clone()),
+            revision => {
+                let repo = self.inner.inner.inner().repository.to_thread_local();
+                let commit = repo
+                    .rev_parse_single(&*revision.to_string())?
+                    .object()?
+                    .peel_to_commit()?;
+
+                Ok(commit.id.to_string())
+            }
+        }
+    }
+
+    fn commit_branch(
+        &self,
+        branch_name: &str,
+        message: &str,
+        files: Vec<(RelativePathBuf, Option<String>)>,
+    ) -> Result<String, Self::Error> {
+        let repo = self.inner.inner.inner().repository.to_thread_local();
+        let reference = format!("refs/heads/{branch_name}");
+        let object_id = repo.rev_parse_single(&*reference)?;
+        let parent = object_id.object()?.peel_to_commit()?.id;
+        let mut editor = object_id.object()?.peel_to_tree()?.edit()?;
+
+        for (path, file) in files {
+            match file {
+                Some(content) => {
+                    let blob_id = repo.write_blob(content.as_bytes())?;
+
+                    editor.upsert(path.as_str(), gix::object::tree::EntryKind::Blob, blob_id)?;
+                }
+                None => {
+                    editor.remove(path.as_str())?;
+                }
+            }
+        }
+
+        let tree_id = editor.write()?;
+        let commit_id = repo.commit(reference, message, tree_id, Some(parent))?;
+
+        Ok(commit_id.to_string())
+    }
+
+    /// Gets the default branch.
+    ///
+    /// This uses the `init.defaultBranch` configuration if it is set and the
+    /// branch exists, otherwise it falls back to the branch referenced by
+    /// `HEAD`.
+    fn get_default_branch(&self) -> Result<String, Self::Error> {
+        let repo = self.inner.inner.inner().repository.to_thread_local();
+
+        if let Some(branch) = repo.config_snapshot().string("init.defaultBranch")
+            && repo
+                .try_find_reference(&format!("refs/heads/{branch}"))
+                .is_ok_and(|reference| reference.is_some())
+        {
+            return Ok(branch.to_string());
+        }
+
+        Ok(repo
+            .head()?
+            .referent_name()
+            .map(|name| name.shorten().to_string())
+            .unwrap_or_else(|| gix::init::DEFAULT_BRANCH_NAME.to_string()))
+    }
+
+    fn create_branch(&self, name: &str) -> Result<(), Self::Error> {
+        let repo = self.inner.inner.inner().repository.to_thread_local();
+        let sha = self.sha()?;
+
+        repo.reference(
+            format!("refs/heads/{name}"),
+            repo.rev_parse_single(&*sha)?,
+            PreviousValue::MustNotExist,
+            format!("branch: Created from {sha}"),
+        )?;
+
+        Ok(())
+    }
+
+    fn update_branch(&self, name: &str, sha: &str) -> Result<(), Self::Error> {
+        let repo = self.inner.inner.inner().repository.to_thread_local();
+
+        repo.reference(
+            format!("refs/heads/{name}"),
+            repo.rev_parse_single(sha)?,
+            PreviousValue::MustExist,
+            format!("branch: Updated to {sha}"),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Remote for Git {
+    fn get_changelog_release(
+        &self,
+        package: &str,
+        version: &Version,
+        tag_prefix: &str,
+        labels: &LabelMapping,
+    ) -> Result<Release, Self::Error> {
+        self::changelog::get_release(
+            &self.inner.inner.inner().repository.to_thread_local(),
+            package,
+            version,
+            tag_prefix,
+            labels,
+        )
+    }
+
+    fn get_tags(&self) -> Result<Vec<String>, Self::Error> {
+        self::changelog::get_tags(&self.inner.inner.inner().repository.to_thread_local())
+    }
+
+    fn get_unreleased_changes(
+        &self,
+        package: &str,
+        tag_prefix: &str,
+    ) -> Result<Vec<String>, Self::Error> {
+        self::changelog::get_unreleased_changes(
+            &self.inner.inner.inner().repository.to_thread_local(),
+            package,
+            tag_prefix,
+        )
+    }
+
+    /// Creates a pull request.
+    ///
+    /// A local repository has no pull requests so the release branch is left
+    /// to be merged by hand and the returned identifier is always zero.
+    fn create_pull_request(
+        &self,
+        _head: &str,
+        _base: &str,
+        _title: &str,
+        _body: &str,
+    ) -> Result<u64, Self::Error> {
+        Ok(0)
+    }
+
+    /// Creates a release.
+    ///
+    /// This creates an annotated tag with the release name and body as the
+    /// message and the returned identifier is always zero.
+    fn create_release(
+        &self,
+        tag: &str,
+        sha: &str,
+        name: &str,
+        body: &str,
+        _prerelease: bool,
+        _latest: bool,
+    ) -> Result<u64, Self::Error> {
+        let repo = self.inner.inner.inner().repository.to_thread_local();
+        let tagger = repo
+            .committer()
+            .ok_or(gix::commit::Error::CommitterMissing)?
+            .map_err(gix::commit::Error::from)?;
+
+        let message = match body.trim().is_empty() {
+            true => name.to_owned(),
+            false => format!("{name}\n\n{}", body.trim()),
+        };
+
+        repo.tag(
+            tag,
+            repo.rev_parse_single(sha)?,
+            gix::objs::Kind::Commit,
+            Some(tagger),
+            message,
+            PreviousValue::MustNotExist,
+        )?;
+
+        Ok(0)
+    }
+}
+
 #[derive(Clone)]
 struct Inner {
     repository: ThreadSafeRepository,
@@ -0,0 +1,238 @@
+use std::collections::{HashMap, HashSet};
+
+use semver::Version;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::changelog::{LabelMapping, Release};
+use crate::repository::types::pulls;
+
+use super::{Error, Repo};
+
+/// Gets the changelog release for the given package version.
+pub(super) fn get_release(
+    repository: &Repo,
+    package: &str,
+    version: &Version,
+    tag_prefix: &str,
+    labels: &LabelMapping,
+    default_branch: &str,
+) -> Result<Release, Error> {
+    let tags = get_all_tags(repository)?;
+    let tagname = format!("{tag_prefix}{version}");
+
+    let tag = tags.iter().find(|tag| tag.name == tagname);
+
+    let prev_version =
+        pulls::get_previous_version(tag_prefix, version, tags.iter().map(|tag| &*tag.name));
+    let prev_tag = prev_version
+        .as_ref()
+        .map(|version| format!("{tag_prefix}{version}"));
+
+    let timestamp = tag
+        .as_ref()
+        .map(|tag| tag.commit.created)
+        .unwrap_or_else(OffsetDateTime::now_utc);
+
+    let to = tag.map(|tag| tag.name.as_str()).unwrap_or(default_branch);
+
+    let pull_requests = get_merged_pull_requests(repository)?;
+    let mut first_pull_requests = HashMap::<String, u64>::new();
+
+    for pull_request in &pull_requests {
+        if let Some(user) = &pull_request.user {
+            first_pull_requests
+                .entry(user.login.clone())
+                .and_modify(|number| *number = (*number).min(pull_request.number))
+                .or_insert(pull_request.number);
+        }
+    }
+
+    let pull_requests = match prev_tag {
+        Some(_) if prev_version.expect("prev") > *version => Vec::new(),
+        Some(from) => merged_in(pull_requests, self::between(repository, &from, to)?),
+        None => merged_in(pull_requests, self::until(repository, to)?),
+    };
+
+    pulls::build_release(
+        version,
+        timestamp,
+        format!("{}/releases/tag/{tagname}", repository.addr.url()),
+        pull_requests.into_iter().map(Into::into).collect(),
+        package,
+        labels,
+        |login| Ok(first_pull_requests.get(login).copied()),
+    )
+}
+
+/// Gets the change messages since the latest release of the given package.
+pub(super) fn get_unreleased_changes(
+    repository: &Repo,
+    package: &str,
+    tag_prefix: &str,
+    default_branch: &str,
+) -> Result<Vec<String>, Error> {
+    let tags = get_all_tags(repository)?;
+    let latest = pulls::get_latest_version(tag_prefix, tags.iter().map(|tag| &*tag.name));
+
+    let commits = match latest {
+        Some(version) => self::between(
+            repository,
+            &format!("{tag_prefix}{version}"),
+            default_branch,
+        )?,
+        None => self::until(repository, default_branch)?,
+    };
+
+    let pull_requests = merged_in(get_merged_pull_requests(repository)?, commits);
+
+    Ok(pulls::get_change_messages(
+        pull_requests.into_iter().map(Into::into).collect(),
+        package,
+    ))
+}
+
+/// Gets the names of all tags.
+pub(super) fn get_tags(repository: &Repo) -> Result<Vec<String>, Error> {
+    Ok(get_all_tags(repository)?
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect())
+}
+
+/// Gets all tags.
+fn get_all_tags(repository: &Repo) -> Result<Vec<GitTag>, Error> {
+    repository.get_all("tags", &())
+}
+
+/// Gets all merged pull requests sorted by the merge date.
+fn get_merged_pull_requests(repository: &Repo) -> Result<Vec<PullRequest>, Error> {
+    let mut pull_requests = repository
+        .get_all::<_, _, PullRequest>("pulls", &[("state", "closed")])?
+        .into_iter()
+        .filter(|pull_request| pull_request.merged)
+        .collect::<Vec<_>>();
+
+    pull_requests.sort_by_key(|pull_request| pull_request.merged_at);
+
+    Ok(pull_requests)
+}
+
+/// Gets the commits in the history up to the given revision.
+fn until(repository: &Repo, to: &str) -> Result<HashSet<String>, Error> {
+    Ok(repository
+        .get_all::<_, _, GitCommit>(
+            "commits",
+            &[
+                ("sha", to),
+                ("stat", "false"),
+                ("verification", "false"),
+                ("files", "false"),
+            ],
+        )?
+        .into_iter()
+        .map(|commit| commit.sha)
+        .collect())
+}
+
+/// Gets the commits between the given revisions with the compare API.
+fn between(repository: &Repo, from: &str, to: &str) -> Result<HashSet<String>, Error> {
+    Ok(repository
+        .get(format!("compare/{from}...{to}"))?
+        .send()?
+        .error_for_status()?
+        .json::<CompareResponse>()?
+        .commits
+        .into_iter()
+        .map(|commit| commit.sha)
+        .collect())
+}
+
+/// Filters the pull requests to those that resulted in one of the commits.
+///
+/// The pull requests are matched on the merge and head commits as the
+/// repository may use any merge style.
+fn merged_in(pull_requests: Vec<PullRequest>, commits: HashSet<String>) -> Vec<PullRequest> {
+    pull_requests
+        .into_iter()
+        .filter(|pull_request| {
+            pull_request
+                .merge_commit_sha
+                .iter()
+                .chain(Some(&pull_request.head.sha))
+                .any(|sha| commits.contains(sha))
+        })
+        .collect()
+}
+
+impl From<PullRequest> for pulls::PullRequest {
+    fn from(pull_request: PullRequest) -> Self {
+        Self {
+            number: pull_request.number,
+            reference: format!("#{}", pull_request.number),
+            title: pull_request.title,
+            body: pull_request.body.unwrap_or_default(),
+            url: pull_request.html_url,
+            author: pull_request.user.map(|user| user.login),
+            labels: pull_request
+                .labels
+                .into_iter()
+                .map(|label| label.name)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CompareResponse {
+    commits: Vec<GitCommit>,
+}
+
+#[derive(Deserialize)]
+struct PullRequest {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    html_url: String,
+    user: Option<User>,
+    #[serde(default)]
+    labels: Vec<Label>,
+    #[serde(default)]
+    merged: bool,
+    #[serde(default, with = "time::serde::iso8601::option")]
+    merged_at: Option<OffsetDateTime>,
+    merge_commit_sha: Option<String>,
+    head: Head,
+}
+
+#[derive(Deserialize)]
+struct User {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct Label {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct Head {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct GitTag {
+    name: String,
+    commit: GitTagCommit,
+}
+
+#[derive(Deserialize)]
+struct GitTagCommit {
+    #[serde(with = "time::serde::iso8601")]
+    created: OffsetDateTime,
+}
+
+#[derive(Deserialize)]
+struct GitCommit {
+    sha: String,
+}
@@ -0,0 +1,665 @@
+//! Gitea repository inspection and management
+//!
+//! This module contains the utilities related to remote Gitea repository
+//! management. Forgejo shares the same API and is supported in the same way.
+
+mod changelog;
+mod error;
+mod params;
+mod repo;
+
+use std::borrow::Cow;
+use std::io;
+
+use base64::prelude::{BASE64_STANDARD, Engine};
+use bytes::Bytes;
+use relative_path::{RelativePath, RelativePathBuf};
+use reqwest::StatusCode;
+use reqwest::blocking::Client as HttpClient;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::changelog::{LabelMapping, Release};
+use crate::repository::adapters::cached::Cached;
+use crate::repository::adapters::staged::Staged;
+use crate::repository::addr::RepoAddr;
+use crate::repository::path::prepare_path;
+use crate::repository::revision::{Reference, Revision};
+use crate::repository::{Commit, GitLike, Open, Remote, Repository, Stage};
+
+pub use self::error::Error;
+pub use self::params::CommitParams;
+pub use self::repo::Repo;
+
+/// The remote Gitea repository.
+#[derive(Clone)]
+pub struct Gitea {
+    inner: Staged<Cached<Inner>>,
+}
+
+impl Gitea {
+    /// Constructs a new Gitea repository.
+    fn new<R>(http_client: HttpClient, repo: R) -> Result<Self, Error>
+    where
+        R: TryInto<RepoAddr, Error: Into<Error>>,
+    {
+        Ok(Self {
+            inner: Staged::new(Cached::new(Inner {
+                repository: Repo::new(http_client, repo.try_into().map_err(Into::into)?),
+                revision: Revision::Head,
+            })),
+        })
+    }
+
+    /// Gets the revision.
+    pub fn revision(&self) -> &Revision {
+        &self.inner.inner.inner().revision
+    }
+
+    /// Sets the revision.
+    pub fn set_revision(&mut self, revision: impl Into<Revision>) {
+        let revision = revision.into();
+
+        if let Revision::Sha(_) = &revision {
+            self.inner.inner.enable(true);
+
+            if revision != self.inner.inner.inner().revision {
+                self.inner.inner.clear();
+            }
+        } else {
+            if let Revision::Sha(_) = self.inner.inner.inner().revision {
+                self.inner.inner.clear();
+            }
+
+            self.inner.inner.enable(false);
+        }
+
+        self.inner.inner.inner_mut().revision = revision;
+    }
+
+    /// Builds the repository with the given revision.
+    pub fn with_revision(mut self, revision: impl Into<Revision>) -> Self {
+        self.set_revision(revision);
+        self
+    }
+
+    /// Sets the access token.
+    pub fn set_token(&mut self, token: impl Into<String>) {
+        self.inner.inner.inner_mut().repository.set_token(token);
+    }
+
+    /// Builds the repository with the given access token.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.set_token(token);
+        self
+    }
+
+    /// Builds the repository with validation to ensure it exists.
+    pub fn validated(self) -> Result<Self, Error> {
+        self.inner.inner.inner().repository.validate()?;
+
+        Ok(self)
+    }
+}
+
+impl Gitea {
+    /// Gets the commit SHA.
+    pub(crate) fn sha(&self) -> Result<String, Error> {
+        let inner = self.inner.inner.inner();
+
+        match &inner.revision {
+            Revision::Sha(sha) => Ok(sha.clone()),
+            Revision::Head => inner.get_branch_sha(&self.get_default_branch()?),
+            Revision::Reference(Reference::Branch(branch_name)) => {
+                inner.get_branch_sha(branch_name)
+            }
+            Revision::Reference(Reference::Tag(tag_name)) => inner.get_tag_sha(tag_name),
+        }
+    }
+
+    /// Gets the branch that commits are added to for the current revision.
+    fn branch(&self) -> Result<String, Error> {
+        match self.revision() {
+            Revision::Head => self.get_default_branch(),
+            Revision::Reference(Reference::Branch(branch_name)) => Ok(branch_name.clone()),
+            Revision::Sha(_) | Revision::Reference(Reference::Tag(_)) => Err(Error::Unsupported(
+                "Gitea cannot commit to a detached revision",
+            )),
+        }
+    }
+}
+
+impl Repository for Gitea {
+    type Error = Error;
+
+    fn get_file(&self, path: impl AsRef<RelativePath>) -> Result<Option<Bytes>, Self::Error> {
+        self.inner.get_file(path)
+    }
+
+    fn get_index(&self) -> Result<impl Iterator<Item = Cow<'_, RelativePath>>, Self::Error> {
+        self.inner.get_index()
+    }
+}
+
+impl Stage for Gitea {
+    fn add_file(
+        &mut self,
+        path: impl Into<RelativePathBuf>,
+        file: impl Into<Bytes>,
+    ) -> Result<&mut Self, Self::Error> {
+        self.inner.add_file(path, file)?;
+
+        Ok(self)
+    }
+
+    fn remove_file(
+        &mut self,
+        path: impl AsRef<RelativePath>,
+    ) -> Result<Option<Bytes>, Self::Error> {
+        self.inner.remove_file(path)
+    }
+}
+
+impl Commit for Gitea {
+    type Params = CommitParams;
+
+    /// Commits the staged files.
+    ///
+    /// Gitea can only commit to a branch so this returns an error when the
+    /// revision is a SHA or a tag.
+    fn commit(&mut self, params: impl Into<Self::Params>) -> Result<(), Self::Error> {
+        let params = params.into();
+        let branch_name = self.branch()?;
+        let files = self.inner.drain().collect::<Vec<_>>();
+
+        let mut changes = Vec::new();
+
+        for (path, file) in files {
+            let sha = self.inner.inner.inner().get_file_sha(&branch_name, &path)?;
+
+            changes.push(FileChange::new(path, file, sha));
+        }
+
+        self.inner
+            .inner
+            .inner()
+            .create_commit(&branch_name, params.message(), changes)?;
+
+        Ok(())
+    }
+}
+
+impl Open for Gitea {
+    type Context = RepoAddr;
+
+    /// Opens a Gitea repository.
+    ///
+    /// Note that this does not validate the existence of the repository as it
+    /// may require an access token.
+    fn open<T, E>(ctx: T) -> Result<Self, Self::Error>
+    where
+        T: TryInto<Self::Context, Error = E>,
+        E: Into<Self::Error>,
+    {
+        let http_client = HttpClient::builder()
+            .user_agent(concat!("ploys/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Self::new(http_client, ctx)
+    }
+}
+
+#[derive(Clone)]
+struct Inner {
+    repository: Repo,
+    revision: Revision,
+}
+
+impl Inner {
+    /// Gets the short reference name for the revision.
+    ///
+    /// This is empty for the head revision as Gitea resolves a missing
+    /// reference to the default branch.
+    fn reference(&self) -> Option<&str> {
+        match &self.revision {
+            Revision::Head => None,
+            Revision::Sha(sha) => Some(sha),
+            Revision::Reference(Reference::Branch(branch_name)) => Some(branch_name),
+            Revision::Reference(Reference::Tag(tag_name)) => Some(tag_name),
+        }
+    }
+
+    /// Gets the commit SHA at the head of the given branch.
+    fn get_branch_sha(&self, branch_name: &str) -> Result<String, Error> {
+        #[derive(Deserialize)]
+        struct BranchResponse {
+            commit: BranchCommit,
+        }
+
+        #[derive(Deserialize)]
+        struct BranchCommit {
+            id: String,
+        }
+
+        let sha = self
+            .repository
+            .get(format!("branches/{branch_name}"))?
+            .send()?
+            .error_for_status()?
+            .json::<BranchResponse>()?
+            .commit
+            .id;
+
+        Ok(sha)
+    }
+
+    /// Gets the commit SHA that the given tag points to.
+    fn get_tag_sha(&self, tag_name: &str) -> Result<String, Error> {
+        #[derive(Deserialize)]
+        struct TagResponse {
+            commit: TagCommit,
+        }
+
+        #[derive(Deserialize)]
+        struct TagCommit {
+            sha: String,
+        }
+
+        let sha = self
+            .repository
+            .get(format!("tags/{tag_name}"))?
+            .send()?
+            .error_for_status()?
+            .json::<TagResponse>()?
+            .commit
+            .sha;
+
+        Ok(sha)
+    }
+
+    /// Gets the blob SHA of the file on the given branch if it exists.
+    fn get_file_sha(
+        &self,
+        branch_name: &str,
+        path: &RelativePath,
+    ) -> Result<Option<String>, Error> {
+        #[derive(Deserialize)]
+        struct ContentsResponse {
+            sha: String,
+        }
+
+        let response = self
+            .repository
+            .get(format!("contents/{path}"))?
+            .query(&[("ref", branch_name)])
+            .send()?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let sha = response.error_for_status()?.json::<ContentsResponse>()?.sha;
+
+        Ok(Some(sha))
+    }
+
+    /// Creates a commit on the given branch with the file changes endpoint.
+    fn create_commit(
+        &self,
+        branch_name: &str,
+        message: &str,
+        files: Vec<FileChange>,
+    ) -> Result<String, Error> {
+        #[derive(Serialize)]
+        struct CreateCommit<'a> {
+            branch: &'a str,
+            message: &'a str,
+            files: Vec<FileChange>,
+        }
+
+        #[derive(Deserialize)]
+        struct NewCommit {
+            commit: NewCommitSha,
+        }
+
+        #[derive(Deserialize)]
+        struct NewCommitSha {
+            sha: String,
+        }
+
+        let sha = self
+            .repository
+            .post("contents")?
+            .json(&CreateCommit {
+                branch: branch_name,
+                message,
+                files,
+            })
+            .send()?
+            .error_for_status()?
+            .json::<NewCommit>()?
+            .commit
+            .sha;
+
+        Ok(sha)
+    }
+}
+
+impl Repository for Inner {
+    type Error = Error;
+
+    fn get_file(&self, path: impl AsRef<RelativePath>) -> Result<Option<Bytes>, Self::Error> {
+        let mut request = self.repository.get(format!("contents/{}", path.as_ref()))?;
+
+        if let Some(reference) = self.reference() {
+            request = request.query(&[("ref", reference)]);
+        }
+
+        let response = request.send()?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        // Directories are returned as an array of entries and have no content.
+        let response = response.error_for_status()?.json::<serde_json::Value>()?;
+
+        match response.get("content").and_then(serde_json::Value::as_str) {
+            Some(content) => {
+                let contents = BASE64_STANDARD
+                    .decode(content)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+                Ok(Some(contents.into()))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn get_index(&self) -> Result<impl Iterator<Item = Cow<'_, RelativePath>>, Self::Error> {
+        #[derive(Deserialize)]
+        struct TreeResponse {
+            tree: Vec<TreeEntry>,
+            truncated: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct TreeEntry {
+            path: RelativePathBuf,
+            r#type: String,
+        }
+
+        let sha = self.reference().unwrap_or("HEAD");
+
+        let mut entries = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let response = self
+                .repository
+                .get(format!("git/trees/{sha}"))?
+                .query(&[("recursive", "true")])
+                .query(&[("page", page)])
+                .send()?
+                .error_for_status()?
+                .json::<TreeResponse>()?;
+
+            entries.extend(
+                response
+                    .tree
+                    .into_iter()
+                    .filter(|entry| entry.r#type == "blob")
+                    .map(|entry| Cow::Owned(entry.path)),
+            );
+
+            if !response.truncated {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(entries.into_iter())
+    }
+}
+
+impl GitLike for Gitea {
+    fn sha(&self) -> Result<String, Self::Error> {
+        self.sha()
+    }
+
+    fn commit_branch(
+        &self,
+        branch_name: &str,
+        message: &str,
+        files: Vec<(RelativePathBuf, Option<String>)>,
+    ) -> Result<String, Self::Error> {
+        let inner = self.inner.inner.inner();
+        let mut changes = Vec::new();
+
+        for (path, contents) in files {
+            let path = prepare_path(Cow::Owned(path))?.into_owned();
+            let sha = inner.get_file_sha(branch_name, &path)?;
+
+            changes.push(FileChange::new(path, contents.map(Bytes::from), sha));
+        }
+
+        inner.create_commit(branch_name, message, changes)
+    }
+
+    fn get_default_branch(&self) -> Result<String, Self::Error> {
+        #[derive(Deserialize)]
+        struct RepositoryResponse {
+            default_branch: String,
+        }
+
+        let default_branch = self
+            .inner
+            .inner
+            .inner()
+            .repository
+            .get("")?
+            .send()?
+            .error_for_status()?
+            .json::<RepositoryResponse>()?
+            .default_branch;
+
+        Ok(default_branch)
+    }
+
+    fn create_branch(&self, name: &str) -> Result<(), Self::Error> {
+        let sha = self.sha()?;
+
+        self.inner
+            .inner
+            .inner()
+            .repository
+            .create_branch(name.trim_start_matches('/'), &sha)
+    }
+
+    /// Updates the branch to point to the given SHA.
+    ///
+    /// Gitea has no endpoint to move a branch so this deletes and recreates
+    /// the branch which fails for protected branches.
+    fn update_branch(&self, name: &str, sha: &str) -> Result<(), Self::Error> {
+        let repository = &self.inner.inner.inner().repository;
+        let name = name.trim_start_matches('/');
+
+        repository
+            .delete(format!("branches/{name}"))?
+            .send()?
+            .error_for_status()?;
+
+        repository.create_branch(name, sha)
+    }
+}
+
+impl Remote for Gitea {
+    fn get_changelog_release(
+        &self,
+        package: &str,
+        version: &Version,
+        tag_prefix: &str,
+        labels: &LabelMapping,
+    ) -> Result<Release, Self::Error> {
+        self::changelog::get_release(
+            &self.inner.inner.inner().repository,
+            package,
+            version,
+            tag_prefix,
+            labels,
+            &self.get_default_branch()?,
+        )
+    }
+
+    fn get_tags(&self) -> Result<Vec<String>, Self::Error> {
+        self::changelog::get_tags(&self.inner.inner.inner().repository)
+    }
+
+    fn get_unreleased_changes(
+        &self,
+        package: &str,
+        tag_prefix: &str,
+    ) -> Result<Vec<String>, Self::Error> {
+        self::changelog::get_unreleased_changes(
+            &self.inner.inner.inner().repository,
+            package,
+            tag_prefix,
+            &self.get_default_branch()?,
+        )
+    }
+
+    fn get_compare_url(&self, base: &str, head: &str) -> Option<String> {
+        Some(format!(
+            "{}/compare/{base}...{head}",
+            self.inner.inner.inner().repository.addr.url()
+        ))
+    }
+
+    fn create_pull_request(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<u64, Self::Error> {
+        #[derive(Serialize)]
+        struct NewPullRequest<'a> {
+            head: &'a str,
+            base: &'a str,
+            title: &'a str,
+            body: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct PullRequestResponse {
+            number: u64,
+        }
+
+        let number = self
+            .inner
+            .inner
+            .inner()
+            .repository
+            .post("pulls")?
+            .json(&NewPullRequest {
+                head,
+                base,
+                title,
+                body,
+            })
+            .send()?
+            .error_for_status()?
+            .json::<PullRequestResponse>()?
+            .number;
+
+        Ok(number)
+    }
+
+    /// Creates a release.
+    ///
+    /// Gitea has no latest flag as the latest release is always the most
+    /// recent one that is not a prerelease.
+    fn create_release(
+        &self,
+        tag: &str,
+        sha: &str,
+        name: &str,
+        body: &str,
+        prerelease: bool,
+        _latest: bool,
+    ) -> Result<u64, Self::Error> {
+        #[derive(Serialize)]
+        struct NewRelease<'a> {
+            tag_name: &'a str,
+            target_commitish: &'a str,
+            name: &'a str,
+            body: &'a str,
+            draft: bool,
+            prerelease: bool,
+        }
+
+        #[derive(Deserialize)]
+        struct ReleaseResponse {
+            id: u64,
+        }
+
+        let id = self
+            .inner
+            .inner
+            .inner()
+            .repository
+            .post("releases")?
+            .json(&NewRelease {
+                tag_name: tag,
+                target_commitish: sha,
+                name,
+                body,
+                draft: false,
+                prerelease,
+            })
+            .send()?
+            .error_for_status()?
+            .json::<ReleaseResponse>()?
+            .id;
+
+        Ok(id)
+    }
+}
+
+impl From<RepoAddr> for Gitea {
+    fn from(repo: RepoAddr) -> Self {
+        Self::open(repo).expect("valid repo specification")
+    }
+}
+
+/// A file change for the multiple file contents endpoint.
+#[derive(Serialize)]
+struct FileChange {
+    operation: &'static str,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
+}
+
+impl FileChange {
+    /// Constructs a new change to create, update or delete the file.
+    ///
+    /// The blob SHA of the existing file is required to update or delete it.
+    fn new(path: RelativePathBuf, file: Option<Bytes>, sha: Option<String>) -> Self {
+        let operation = match (&file, &sha) {
+            (Some(_), Some(_)) => "update",
+            (Some(_), None) => "create",
+            (None, _) => "delete",
+        };
+
+        Self {
+            operation,
+            path: path.into_string(),
+            content: file.map(|bytes| BASE64_STANDARD.encode(bytes)),
+            sha,
+        }
+    }
+}
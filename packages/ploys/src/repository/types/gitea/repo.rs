@@ -0,0 +1,163 @@
+use reqwest::Method;
+use reqwest::blocking::{Client as HttpClient, RequestBuilder};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::repository::addr::RepoAddr;
+
+use super::Error;
+
+/// The number of items requested per page.
+const PAGE_LIMIT: usize = 50;
+
+/// The Gitea repository information.
+#[derive(Clone, Debug)]
+pub struct Repo {
+    pub(crate) addr: RepoAddr,
+    token: Option<String>,
+    http_client: HttpClient,
+}
+
+impl Repo {
+    /// Constructs a new repository.
+    pub(crate) fn new(http_client: HttpClient, addr: RepoAddr) -> Self {
+        Self {
+            addr,
+            token: None,
+            http_client,
+        }
+    }
+
+    /// Gets the repository owner.
+    pub fn owner(&self) -> &str {
+        self.addr.owner()
+    }
+
+    /// Gets the repository name.
+    pub fn name(&self) -> &str {
+        self.addr.name()
+    }
+
+    /// Sets the access token.
+    pub(super) fn set_token(&mut self, token: impl Into<String>) {
+        self.token = Some(token.into());
+    }
+
+    /// Validates whether the remote repository exists.
+    pub(super) fn validate(&self) -> Result<(), Error> {
+        self.get("")?.send()?.error_for_status()?;
+
+        Ok(())
+    }
+}
+
+impl Repo {
+    /// Gets the API endpoint.
+    pub(super) fn endpoint<P>(&self, path: P) -> String
+    where
+        P: AsRef<str>,
+    {
+        match path.as_ref() {
+            "" => format!("{}/api/v1/repos/{}", self.addr.base_url(), self.addr),
+            path => format!(
+                "{}/api/v1/repos/{}/{}",
+                self.addr.base_url(),
+                self.addr,
+                path.trim_start_matches('/')
+            ),
+        }
+    }
+
+    /// Creates a HTTP request.
+    pub(super) fn request<P>(&self, method: Method, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        let mut request = self.http_client.request(method, self.endpoint(path));
+
+        if let Some(token) = &self.token {
+            request = request.header("Authorization", format!("token {token}"));
+        }
+
+        Ok(request)
+    }
+
+    /// Creates a GET request.
+    pub(super) fn get<P>(&self, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        self.request(Method::GET, path)
+    }
+
+    /// Creates a POST request.
+    pub(super) fn post<P>(&self, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        self.request(Method::POST, path)
+    }
+
+    /// Creates a DELETE request.
+    pub(super) fn delete<P>(&self, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        self.request(Method::DELETE, path)
+    }
+
+    /// Creates a branch from the given commit SHA.
+    pub(super) fn create_branch(&self, name: &str, sha: &str) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct NewBranch<'a> {
+            new_branch_name: &'a str,
+            old_ref_name: &'a str,
+        }
+
+        self.post("branches")?
+            .json(&NewBranch {
+                new_branch_name: name,
+                old_ref_name: sha,
+            })
+            .send()?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Gets every page of a paginated GET request.
+    ///
+    /// Gitea has no next page indicator in the response body so this stops at
+    /// the first page with fewer items than the limit.
+    pub(super) fn get_all<P, Q, T>(&self, path: P, query: &Q) -> Result<Vec<T>, Error>
+    where
+        P: AsRef<str>,
+        Q: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let mut items = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let response = self
+                .get(path.as_ref())?
+                .query(query)
+                .query(&[("limit", PAGE_LIMIT), ("page", page)])
+                .send()?
+                .error_for_status()?
+                .json::<Vec<T>>()?;
+
+            let len = response.len();
+
+            items.extend(response);
+
+            if len < PAGE_LIMIT {
+                break;
+            }
+
+            page += 1;
+        }
+
+        Ok(items)
+    }
+}
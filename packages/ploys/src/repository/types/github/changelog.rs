@@ -3,9 +3,9 @@ use std::collections::BTreeMap;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
-use time::format_description::well_known::Iso8601;
 
-use crate::changelog::{Change, Changeset, Release};
+use crate::changelog::{LabelMapping, Release};
+use crate::repository::types::pulls;
 
 use super::{Error, Repo};
 
@@ -14,21 +14,19 @@ pub(super) fn get_release(
     repository: &Repo,
     package: &str,
     version: &Version,
-    is_primary: bool,
+    tag_prefix: &str,
+    labels: &LabelMapping,
 ) -> Result<Release, Error> {
     let tags = get_all_tags(repository)?;
-    let tagname = match is_primary {
-        true => version.to_string(),
-        false => format!("{package}-{version}"),
-    };
+    let tagname = format!("{tag_prefix}{version}");
 
     let tag = tags.iter().find(|tag| tag.name == tagname);
 
-    let prev_version = get_previous_version(package, version, is_primary, &tags);
-    let prev_tag = prev_version.as_ref().map(|version| match is_primary {
-        true => version.to_string(),
-        false => format!("{package}-{version}"),
-    });
+    let prev_version =
+        pulls::get_previous_version(tag_prefix, version, tags.iter().map(|tag| &*tag.name));
+    let prev_tag = prev_version
+        .as_ref()
+        .map(|version| format!("{tag_prefix}{version}"));
 
     let timestamp = tag
         .as_ref()
@@ -43,46 +41,73 @@ pub(super) fn get_release(
         (Some(from), Some(to)) => self::between(repository, &from, &to.name)?,
     };
 
-    let package_label = format!("package: {package}");
-    let pull_requests = pull_requests
-        .into_iter()
-        .filter(|pull_request| {
-            pull_request
-                .labels
-                .nodes
-                .iter()
-                .any(|label| label.name == package_label)
-        })
-        .filter(|pull_request| {
-            !pull_request
-                .labels
-                .nodes
-                .iter()
-                .any(|label| label.name.contains("release"))
-        });
+    pulls::build_release(
+        version,
+        timestamp,
+        format!("{}/releases/tag/{tagname}", repository.addr.url()),
+        pull_requests.into_iter().map(Into::into).collect(),
+        package,
+        labels,
+        |login| get_first_pull_request(repository, login),
+    )
+}
 
-    let mut release = Release::new(version.to_string());
-    let mut changeset = Changeset::changed();
+/// Gets the change messages since the latest release of the given package.
+pub(super) fn get_unreleased_changes(
+    repository: &Repo,
+    package: &str,
+    tag_prefix: &str,
+) -> Result<Vec<String>, Error> {
+    let tags = get_all_tags(repository)?;
+    let latest = pulls::get_latest_version(tag_prefix, tags.iter().map(|tag| &*tag.name));
 
-    release.set_date(timestamp.format(&Iso8601::DATE).expect("date"));
-    release.set_url(format!(
-        "https://github.com/{}/{}/releases/tag/{tagname}",
-        repository.owner(),
-        repository.name()
-    ));
+    let pull_requests = match latest {
+        Some(version) => self::between(repository, &format!("{tag_prefix}{version}"), "HEAD")?,
+        None => self::all(repository)?,
+    };
 
-    for pull_request in pull_requests {
-        changeset.add_change(
-            Change::new(pull_request.title)
-                .with_url(format!("#{}", pull_request.number), pull_request.permalink),
-        );
-    }
+    Ok(pulls::get_change_messages(
+        pull_requests.into_iter().map(Into::into).collect(),
+        package,
+    ))
+}
 
-    if changeset.changes().count() > 0 {
-        release.add_changeset(changeset);
+static FIRST_PULL_REQUEST_QUERY: &str = r#"
+query($search: String!) {
+    search(query: $search, type: ISSUE, first: 1) {
+        nodes {
+            ... on PullRequest {
+                number
+            }
+        }
     }
+}
+"#;
 
-    Ok(release)
+/// Gets the number of the first merged pull request by the given author.
+fn get_first_pull_request(repository: &Repo, author: &str) -> Result<Option<u64>, Error> {
+    let search = format!(
+        "repo:{}/{} is:pr is:merged author:{author} sort:created-asc",
+        repository.owner(),
+        repository.name()
+    );
+
+    let response = repository
+        .graphql()?
+        .json(&SearchQuery {
+            query: FIRST_PULL_REQUEST_QUERY,
+            variables: SearchVariables { search: &search },
+        })
+        .send()?
+        .error_for_status()?
+        .json::<SearchResponse>()?;
+
+    Ok(response
+        .data
+        .search
+        .nodes
+        .into_iter()
+        .find_map(|node| node.number))
 }
 
 static ALL_TAGS_QUERY: &str = r#"
@@ -107,6 +132,14 @@ query($owner: String!, $name: String!, $cursor: String) {
 }
 "#;
 
+/// Gets the names of all tags.
+pub(super) fn get_tags(repository: &Repo) -> Result<Vec<String>, Error> {
+    Ok(get_all_tags(repository)?
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect())
+}
+
 /// Gets all tags.
 fn get_all_tags(repository: &Repo) -> Result<Vec<GitTag>, Error> {
     let mut tags = Vec::new();
@@ -143,44 +176,6 @@ fn get_all_tags(repository: &Repo) -> Result<Vec<GitTag>, Error> {
     Ok(tags)
 }
 
-/// Gets the previous version or last version if no previous exists.
-fn get_previous_version(
-    package: &str,
-    version: &Version,
-    is_primary: bool,
-    tags: &[GitTag],
-) -> Option<Version> {
-    let mut versions = tags
-        .iter()
-        .filter_map(|tag| match is_primary {
-            true => tag.name.parse::<Version>().ok(),
-            false => {
-                match tag.name.starts_with(package)
-                    && tag.name.as_bytes().get(package.len()) == Some(&b'-')
-                {
-                    true => tag.name[package.len() + 1..].parse::<Version>().ok(),
-                    false => None,
-                }
-            }
-        })
-        .collect::<Vec<_>>();
-
-    versions.sort();
-
-    let previous_version = versions
-        .iter()
-        .filter(|previous_version| *previous_version < version)
-        .rfind(|previous_version| version.pre.is_empty() || previous_version.pre.is_empty());
-
-    match previous_version {
-        Some(previous_version) => Some(previous_version.clone()),
-        None => match versions.last() {
-            Some(last_version) if last_version != version => Some(last_version.clone()),
-            _ => None,
-        },
-    }
-}
-
 static ALL_QUERY: &str = r#"
 query($owner: String!, $name: String!, $cursor: String) {
     repository(owner: $owner, name: $name) {
@@ -198,8 +193,12 @@ query($owner: String!, $name: String!, $cursor: String) {
                                 nodes {
                                     number
                                     title
+                                    body
                                     mergedAt
                                     permalink
+                                    author {
+                                        login
+                                    }
                                     labels(first: 20) {
                                         nodes {
                                             name
@@ -281,8 +280,12 @@ query($owner: String!, $name: String!, $to: String!, $cursor: String) {
                                 nodes {
                                     number
                                     title
+                                    body
                                     mergedAt
                                     permalink
+                                    author {
+                                        login
+                                    }
                                     labels(first: 20) {
                                         nodes {
                                             name
@@ -367,8 +370,12 @@ query($owner: String!, $name: String!, $from: String!, $to: String!, $cursor: St
                             nodes {
                                 number
                                 title
+                                body
                                 mergedAt
                                 permalink
+                                author {
+                                    login
+                                }
                                 labels(first: 20) {
                                     nodes {
                                         name
@@ -450,6 +457,37 @@ struct Query<'a> {
     variables: Variables<'a>,
 }
 
+#[derive(Serialize)]
+struct SearchVariables<'a> {
+    search: &'a str,
+}
+
+#[derive(Serialize)]
+struct SearchQuery<'a> {
+    query: &'a str,
+    variables: SearchVariables<'a>,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    data: SearchResponseData,
+}
+
+#[derive(Deserialize)]
+struct SearchResponseData {
+    search: SearchResponseNodes,
+}
+
+#[derive(Deserialize)]
+struct SearchResponseNodes {
+    nodes: Vec<SearchResponseNode>,
+}
+
+#[derive(Deserialize)]
+struct SearchResponseNode {
+    number: Option<u64>,
+}
+
 #[derive(Deserialize)]
 struct MatchingTagsResponse {
     data: MatchingTagsResponseData,
@@ -579,12 +617,38 @@ struct PullRequests {
 pub struct PullRequest {
     number: u64,
     title: String,
+    body: String,
     #[serde(with = "time::serde::iso8601")]
     merged_at: OffsetDateTime,
     permalink: String,
+    author: Option<Author>,
     labels: Labels,
 }
 
+impl From<PullRequest> for pulls::PullRequest {
+    fn from(pull_request: PullRequest) -> Self {
+        Self {
+            number: pull_request.number,
+            reference: format!("#{}", pull_request.number),
+            title: pull_request.title,
+            body: pull_request.body,
+            url: pull_request.permalink,
+            author: pull_request.author.map(|author| author.login),
+            labels: pull_request
+                .labels
+                .nodes
+                .into_iter()
+                .map(|label| label.name)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Author {
+    login: String,
+}
+
 #[derive(Deserialize)]
 struct Labels {
     nodes: Vec<Label>,
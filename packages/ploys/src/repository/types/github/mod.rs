@@ -18,7 +18,7 @@ use reqwest::header::CONTENT_TYPE;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 
-use crate::changelog::Release;
+use crate::changelog::{LabelMapping, Release};
 use crate::client::{Client, Error as ClientError};
 use crate::repository::adapters::cached::Cached;
 use crate::repository::adapters::staged::Staged;
@@ -432,7 +432,7 @@ impl GitLike for GitHub {
         &self,
         branch_name: &str,
         message: &str,
-        files: Vec<(RelativePathBuf, String)>,
+        files: Vec<(RelativePathBuf, Option<String>)>,
     ) -> Result<String, Self::Error> {
         #[derive(Serialize)]
         struct Query {
@@ -469,6 +469,7 @@ impl GitLike for GitHub {
         #[derive(Serialize)]
         struct FileChanges {
             additions: Vec<FileAddition>,
+            deletions: Vec<FileDeletion>,
         }
 
         #[derive(Serialize)]
@@ -477,6 +478,11 @@ impl GitLike for GitHub {
             contents: String,
         }
 
+        #[derive(Serialize)]
+        struct FileDeletion {
+            path: String,
+        }
+
         #[derive(Deserialize)]
         struct QueryResponse {
             data: QueryResponseData,
@@ -508,15 +514,20 @@ impl GitLike for GitHub {
             .full_name()
             .to_string();
 
-        let file_additions = files
-            .into_iter()
-            .map(|(path, contents)| {
-                prepare_path(Cow::Owned(path)).map(|path| FileAddition {
-                    path: path.to_string(),
+        let mut file_additions = Vec::new();
+        let mut file_deletions = Vec::new();
+
+        for (path, contents) in files {
+            let path = prepare_path(Cow::Owned(path))?.to_string();
+
+            match contents {
+                Some(contents) => file_additions.push(FileAddition {
+                    path,
                     contents: BASE64_STANDARD.encode(contents),
-                })
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+                }),
+                None => file_deletions.push(FileDeletion { path }),
+            }
+        }
 
         let query = "mutation ($input: CreateCommitOnBranchInput!) { createCommitOnBranch(input: $input) { commit { oid } } }";
         let response = self
@@ -538,6 +549,7 @@ impl GitLike for GitHub {
                         },
                         file_changes: FileChanges {
                             additions: file_additions,
+                            deletions: file_deletions,
                         },
                         expected_head_oid,
                     },
@@ -633,16 +645,41 @@ impl Remote for GitHub {
         &self,
         package: &str,
         version: &Version,
-        is_primary: bool,
+        tag_prefix: &str,
+        labels: &LabelMapping,
     ) -> Result<Release, Self::Error> {
         self::changelog::get_release(
             &self.inner.inner.inner().repository,
             package,
             version,
-            is_primary,
+            tag_prefix,
+            labels,
         )
     }
 
+    fn get_tags(&self) -> Result<Vec<String>, Self::Error> {
+        self::changelog::get_tags(&self.inner.inner.inner().repository)
+    }
+
+    fn get_unreleased_changes(
+        &self,
+        package: &str,
+        tag_prefix: &str,
+    ) -> Result<Vec<String>, Self::Error> {
+        self::changelog::get_unreleased_changes(
+            &self.inner.inner.inner().repository,
+            package,
+            tag_prefix,
+        )
+    }
+
+    fn get_compare_url(&self, base: &str, head: &str) -> Option<String> {
+        Some(format!(
+            "{}/compare/{base}...{head}",
+            self.inner.inner.inner().repository.addr.url()
+        ))
+    }
+
     fn create_pull_request(
         &self,
         head: &str,
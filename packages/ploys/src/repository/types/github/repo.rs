@@ -1,7 +1,9 @@
+use std::borrow::Cow;
+
 use reqwest::Method;
 use reqwest::blocking::RequestBuilder;
 
-use crate::client::Client;
+use crate::client::{Client, GitHubAddr};
 use crate::repository::addr::RepoAddr;
 
 use super::Error;
@@ -38,15 +40,29 @@ impl Repo {
 }
 
 impl Repo {
+    /// Gets the GitHub address of the repository host.
+    ///
+    /// This uses the client address when the hosts match so that a configured
+    /// API URL is respected and otherwise derives it from the host.
+    fn github(&self) -> Cow<'_, GitHubAddr> {
+        match self.client.github().url() == self.addr.base_url() {
+            true => Cow::Borrowed(self.client.github()),
+            false => Cow::Owned(GitHubAddr::from(&self.addr)),
+        }
+    }
+
     /// Gets the API endpoint.
     pub(super) fn endpoint<P>(&self, path: P) -> String
     where
         P: AsRef<str>,
     {
+        let github = self.github();
+
         match path.as_ref() {
-            "" => format!("https://api.github.com/repos/{}", self.addr),
+            "" => format!("{}/repos/{}", github.api_url(), self.addr),
             path => format!(
-                "https://api.github.com/repos/{}/{}",
+                "{}/repos/{}/{}",
+                github.api_url(),
                 self.addr,
                 path.trim_start_matches('/')
             ),
@@ -104,10 +120,7 @@ impl Repo {
 
     /// Creates a GraphQL HTTP request.
     pub(super) fn graphql(&self) -> Result<RequestBuilder, Error> {
-        let mut request = self
-            .client
-            .http_client()
-            .post("https://api.github.com/graphql");
+        let mut request = self.client.http_client().post(self.github().graphql_url());
 
         if let Some(token) = self.client.authenticate().map_err(Error::Auth)? {
             request = request.bearer_auth(token);
@@ -0,0 +1,218 @@
+use std::collections::HashSet;
+
+use semver::Version;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+use crate::changelog::{LabelMapping, Release};
+use crate::repository::types::pulls;
+
+use super::{Error, Repo};
+
+/// Gets the changelog release for the given package version.
+pub(super) fn get_release(
+    repository: &Repo,
+    package: &str,
+    version: &Version,
+    tag_prefix: &str,
+    labels: &LabelMapping,
+) -> Result<Release, Error> {
+    let tags = get_all_tags(repository)?;
+    let tagname = format!("{tag_prefix}{version}");
+
+    let tag = tags.iter().find(|tag| tag.name == tagname);
+
+    let prev_version =
+        pulls::get_previous_version(tag_prefix, version, tags.iter().map(|tag| &*tag.name));
+    let prev_tag = prev_version
+        .as_ref()
+        .map(|version| format!("{tag_prefix}{version}"));
+
+    let timestamp = tag
+        .as_ref()
+        .map(|tag| tag.commit.committed_date)
+        .unwrap_or_else(OffsetDateTime::now_utc);
+
+    let to = tag.map(|tag| tag.name.as_str()).unwrap_or("HEAD");
+
+    let merge_requests = match prev_tag {
+        Some(_) if prev_version.expect("prev") > *version => Vec::new(),
+        Some(from) => self::between(repository, &from, to)?,
+        None => self::until(repository, to)?,
+    };
+
+    pulls::build_release(
+        version,
+        timestamp,
+        format!("{}/-/releases/{tagname}", repository.addr.url()),
+        merge_requests.into_iter().map(Into::into).collect(),
+        package,
+        labels,
+        |username| get_first_merge_request(repository, username),
+    )
+}
+
+/// Gets the change messages since the latest release of the given package.
+pub(super) fn get_unreleased_changes(
+    repository: &Repo,
+    package: &str,
+    tag_prefix: &str,
+) -> Result<Vec<String>, Error> {
+    let tags = get_all_tags(repository)?;
+    let latest = pulls::get_latest_version(tag_prefix, tags.iter().map(|tag| &*tag.name));
+
+    let merge_requests = match latest {
+        Some(version) => self::between(repository, &format!("{tag_prefix}{version}"), "HEAD")?,
+        None => self::until(repository, "HEAD")?,
+    };
+
+    Ok(pulls::get_change_messages(
+        merge_requests.into_iter().map(Into::into).collect(),
+        package,
+    ))
+}
+
+/// Gets the names of all tags.
+pub(super) fn get_tags(repository: &Repo) -> Result<Vec<String>, Error> {
+    Ok(get_all_tags(repository)?
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect())
+}
+
+/// Gets all tags.
+fn get_all_tags(repository: &Repo) -> Result<Vec<GitTag>, Error> {
+    repository.get_all("repository/tags", &[("order_by", "updated")])
+}
+
+/// Gets the iid of the first merged merge request by the given author.
+fn get_first_merge_request(repository: &Repo, author: &str) -> Result<Option<u64>, Error> {
+    let merge_requests = repository
+        .get("merge_requests")?
+        .query(&[
+            ("state", "merged"),
+            ("author_username", author),
+            ("order_by", "created_at"),
+            ("sort", "asc"),
+            ("per_page", "1"),
+        ])
+        .send()?
+        .error_for_status()?
+        .json::<Vec<MergeRequest>>()?;
+
+    Ok(merge_requests
+        .into_iter()
+        .next()
+        .map(|merge_request| merge_request.iid))
+}
+
+/// Gets the merge requests merged in the history up to the given revision.
+fn until(repository: &Repo, to: &str) -> Result<Vec<MergeRequest>, Error> {
+    let commits =
+        repository.get_all::<_, _, GitCommit>("repository/commits", &[("ref_name", to)])?;
+
+    merged_in(repository, commits)
+}
+
+/// Gets the merge requests merged between the given revisions.
+fn between(repository: &Repo, from: &str, to: &str) -> Result<Vec<MergeRequest>, Error> {
+    let commits = repository
+        .get("repository/compare")?
+        .query(&[("from", from), ("to", to)])
+        .send()?
+        .error_for_status()?
+        .json::<CompareResponse>()?
+        .commits;
+
+    merged_in(repository, commits)
+}
+
+/// Gets the merged merge requests that resulted in one of the given commits.
+///
+/// The merge requests are matched on the merge, squash and head commits as the
+/// repository may use any merge method.
+fn merged_in(repository: &Repo, commits: Vec<GitCommit>) -> Result<Vec<MergeRequest>, Error> {
+    if commits.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let commits = commits
+        .into_iter()
+        .map(|commit| commit.id)
+        .collect::<HashSet<_>>();
+
+    let mut merge_requests = repository
+        .get_all::<_, _, MergeRequest>("merge_requests", &[("state", "merged")])?
+        .into_iter()
+        .filter(|merge_request| {
+            [
+                &merge_request.merge_commit_sha,
+                &merge_request.squash_commit_sha,
+                &merge_request.sha,
+            ]
+            .into_iter()
+            .flatten()
+            .any(|sha| commits.contains(sha))
+        })
+        .collect::<Vec<_>>();
+
+    merge_requests.sort_by_key(|merge_request| merge_request.merged_at);
+
+    Ok(merge_requests)
+}
+
+#[derive(Deserialize)]
+struct CompareResponse {
+    commits: Vec<GitCommit>,
+}
+
+#[derive(Deserialize)]
+struct MergeRequest {
+    iid: u64,
+    title: String,
+    description: Option<String>,
+    #[serde(default, with = "time::serde::iso8601::option")]
+    merged_at: Option<OffsetDateTime>,
+    web_url: String,
+    author: Option<Author>,
+    labels: Vec<String>,
+    sha: Option<String>,
+    merge_commit_sha: Option<String>,
+    squash_commit_sha: Option<String>,
+}
+
+impl From<MergeRequest> for pulls::PullRequest {
+    fn from(merge_request: MergeRequest) -> Self {
+        Self {
+            number: merge_request.iid,
+            reference: format!("!{}", merge_request.iid),
+            title: merge_request.title,
+            body: merge_request.description.unwrap_or_default(),
+            url: merge_request.web_url,
+            author: merge_request.author.map(|author| author.username),
+            labels: merge_request.labels,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Author {
+    username: String,
+}
+
+#[derive(Deserialize)]
+struct GitTag {
+    name: String,
+    commit: GitTagCommit,
+}
+
+#[derive(Deserialize)]
+struct GitTagCommit {
+    #[serde(with = "time::serde::iso8601")]
+    committed_date: OffsetDateTime,
+}
+
+#[derive(Deserialize)]
+struct GitCommit {
+    id: String,
+}
@@ -0,0 +1,597 @@
+//! GitLab project inspection and management
+//!
+//! This module contains the utilities related to remote GitLab project
+//! management, including self-hosted instances and nested groups.
+
+mod changelog;
+mod error;
+mod params;
+mod repo;
+
+use std::borrow::Cow;
+use std::io::Read;
+
+use base64::prelude::{BASE64_STANDARD, Engine};
+use bytes::Bytes;
+use relative_path::{RelativePath, RelativePathBuf};
+use reqwest::StatusCode;
+use reqwest::blocking::Client as HttpClient;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use crate::changelog::{LabelMapping, Release};
+use crate::repository::adapters::cached::Cached;
+use crate::repository::adapters::staged::Staged;
+use crate::repository::addr::RepoAddr;
+use crate::repository::path::prepare_path;
+use crate::repository::revision::{Reference, Revision};
+use crate::repository::{Commit, GitLike, Open, Remote, Repository, Stage};
+
+use self::repo::encode;
+
+pub use self::error::Error;
+pub use self::params::CommitParams;
+pub use self::repo::Repo;
+
+/// The remote GitLab repository.
+#[derive(Clone)]
+pub struct GitLab {
+    inner: Staged<Cached<Inner>>,
+}
+
+impl GitLab {
+    /// Constructs a new GitLab repository.
+    fn new<R>(http_client: HttpClient, repo: R) -> Result<Self, Error>
+    where
+        R: TryInto<RepoAddr, Error: Into<Error>>,
+    {
+        Ok(Self {
+            inner: Staged::new(Cached::new(Inner {
+                repository: Repo::new(http_client, repo.try_into().map_err(Into::into)?),
+                revision: Revision::Head,
+            })),
+        })
+    }
+
+    /// Gets the revision.
+    pub fn revision(&self) -> &Revision {
+        &self.inner.inner.inner().revision
+    }
+
+    /// Sets the revision.
+    pub fn set_revision(&mut self, revision: impl Into<Revision>) {
+        let revision = revision.into();
+
+        if let Revision::Sha(_) = &revision {
+            self.inner.inner.enable(true);
+
+            if revision != self.inner.inner.inner().revision {
+                self.inner.inner.clear();
+            }
+        } else {
+            if let Revision::Sha(_) = self.inner.inner.inner().revision {
+                self.inner.inner.clear();
+            }
+
+            self.inner.inner.enable(false);
+        }
+
+        self.inner.inner.inner_mut().revision = revision;
+    }
+
+    /// Builds the repository with the given revision.
+    pub fn with_revision(mut self, revision: impl Into<Revision>) -> Self {
+        self.set_revision(revision);
+        self
+    }
+
+    /// Sets the access token.
+    ///
+    /// This may be a personal, group or project access token and is sent in
+    /// the `PRIVATE-TOKEN` header.
+    pub fn set_token(&mut self, token: impl Into<String>) {
+        self.inner.inner.inner_mut().repository.set_token(token);
+    }
+
+    /// Builds the repository with the given access token.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.set_token(token);
+        self
+    }
+
+    /// Builds the repository with validation to ensure it exists.
+    pub fn validated(self) -> Result<Self, Error> {
+        self.inner.inner.inner().repository.validate()?;
+
+        Ok(self)
+    }
+}
+
+impl GitLab {
+    /// Gets the commit SHA.
+    pub(crate) fn sha(&self) -> Result<String, Error> {
+        match &self.inner.inner.inner().revision {
+            Revision::Sha(sha) => Ok(sha.clone()),
+            revision => self
+                .inner
+                .inner
+                .inner()
+                .get_commit_sha(&revision.to_string()),
+        }
+    }
+
+    /// Gets the branch that commits are added to for the current revision.
+    fn branch(&self) -> Result<String, Error> {
+        match self.revision() {
+            Revision::Head => self.get_default_branch(),
+            Revision::Reference(Reference::Branch(branch_name)) => Ok(branch_name.clone()),
+            Revision::Sha(_) | Revision::Reference(Reference::Tag(_)) => Err(Error::Unsupported(
+                "GitLab cannot commit to a detached revision",
+            )),
+        }
+    }
+}
+
+impl Repository for GitLab {
+    type Error = Error;
+
+    fn get_file(&self, path: impl AsRef<RelativePath>) -> Result<Option<Bytes>, Self::Error> {
+        self.inner.get_file(path)
+    }
+
+    fn get_index(&self) -> Result<impl Iterator<Item = Cow<'_, RelativePath>>, Self::Error> {
+        self.inner.get_index()
+    }
+}
+
+impl Stage for GitLab {
+    fn add_file(
+        &mut self,
+        path: impl Into<RelativePathBuf>,
+        file: impl Into<Bytes>,
+    ) -> Result<&mut Self, Self::Error> {
+        self.inner.add_file(path, file)?;
+
+        Ok(self)
+    }
+
+    fn remove_file(
+        &mut self,
+        path: impl AsRef<RelativePath>,
+    ) -> Result<Option<Bytes>, Self::Error> {
+        self.inner.remove_file(path)
+    }
+}
+
+impl Commit for GitLab {
+    type Params = CommitParams;
+
+    /// Commits the staged files.
+    ///
+    /// GitLab can only commit to a branch so this returns an error when the
+    /// revision is a SHA or a tag.
+    fn commit(&mut self, params: impl Into<Self::Params>) -> Result<(), Self::Error> {
+        let params = params.into();
+        let branch_name = self.branch()?;
+        let files = self.inner.drain().collect::<Vec<_>>();
+
+        let mut actions = Vec::new();
+
+        for (path, file) in files {
+            let exists = self.inner.inner.inner().has_file(&branch_name, &path)?;
+
+            actions.push(Action::new(path, file, exists));
+        }
+
+        self.inner
+            .inner
+            .inner()
+            .create_commit(&branch_name, params.message(), actions)?;
+
+        Ok(())
+    }
+}
+
+impl Open for GitLab {
+    type Context = RepoAddr;
+
+    /// Opens a GitLab repository.
+    ///
+    /// Note that this does not validate the existence of the repository as it
+    /// may require an access token.
+    fn open<T, E>(ctx: T) -> Result<Self, Self::Error>
+    where
+        T: TryInto<Self::Context, Error = E>,
+        E: Into<Self::Error>,
+    {
+        let http_client = HttpClient::builder()
+            .user_agent(concat!("ploys/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Self::new(http_client, ctx)
+    }
+}
+
+#[derive(Clone)]
+struct Inner {
+    repository: Repo,
+    revision: Revision,
+}
+
+impl Inner {
+    /// Gets the commit SHA for the given revision.
+    fn get_commit_sha(&self, revision: &str) -> Result<String, Error> {
+        #[derive(Deserialize)]
+        struct CommitResponse {
+            id: String,
+        }
+
+        let sha = self
+            .repository
+            .get(format!("repository/commits/{}", encode(revision)))?
+            .send()?
+            .error_for_status()?
+            .json::<CommitResponse>()?
+            .id;
+
+        Ok(sha)
+    }
+
+    /// Checks whether the file exists on the given branch.
+    fn has_file(&self, branch_name: &str, path: &RelativePath) -> Result<bool, Error> {
+        let response = self
+            .repository
+            .head(format!("repository/files/{}", encode(path.as_str())))?
+            .query(&[("ref", branch_name)])
+            .send()?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => {
+                response.error_for_status()?;
+
+                Ok(true)
+            }
+        }
+    }
+
+    /// Creates a commit on the given branch with the multi-action endpoint.
+    fn create_commit(
+        &self,
+        branch_name: &str,
+        message: &str,
+        actions: Vec<Action>,
+    ) -> Result<String, Error> {
+        #[derive(Serialize)]
+        struct CreateCommit<'a> {
+            branch: &'a str,
+            commit_message: &'a str,
+            actions: Vec<Action>,
+        }
+
+        #[derive(Deserialize)]
+        struct NewCommit {
+            id: String,
+        }
+
+        let sha = self
+            .repository
+            .post("repository/commits")?
+            .json(&CreateCommit {
+                branch: branch_name,
+                commit_message: message,
+                actions,
+            })
+            .send()?
+            .error_for_status()?
+            .json::<NewCommit>()?
+            .id;
+
+        Ok(sha)
+    }
+}
+
+impl Repository for Inner {
+    type Error = Error;
+
+    fn get_file(&self, path: impl AsRef<RelativePath>) -> Result<Option<Bytes>, Self::Error> {
+        let response = self
+            .repository
+            .get(format!(
+                "repository/files/{}/raw",
+                encode(path.as_ref().as_str())
+            ))?
+            .query(&[("ref", self.revision.to_string())])
+            .send()?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let mut response = response.error_for_status()?;
+        let mut contents = Vec::new();
+
+        response.read_to_end(&mut contents)?;
+
+        Ok(Some(contents.into()))
+    }
+
+    fn get_index(&self) -> Result<impl Iterator<Item = Cow<'_, RelativePath>>, Self::Error> {
+        #[derive(Deserialize)]
+        struct TreeEntry {
+            path: RelativePathBuf,
+            r#type: String,
+        }
+
+        let entries = self
+            .repository
+            .get_all::<_, _, TreeEntry>(
+                "repository/tree",
+                &[
+                    ("ref", self.revision.to_string()),
+                    ("recursive", String::from("true")),
+                ],
+            )?
+            .into_iter()
+            .filter(|entry| entry.r#type == "blob")
+            .map(|entry| entry.path)
+            .map(Cow::Owned);
+
+        Ok(entries)
+    }
+}
+
+impl GitLike for GitLab {
+    fn sha(&self) -> Result<String, Self::Error> {
+        self.sha()
+    }
+
+    fn commit_branch(
+        &self,
+        branch_name: &str,
+        message: &str,
+        files: Vec<(RelativePathBuf, Option<String>)>,
+    ) -> Result<String, Self::Error> {
+        let inner = self.inner.inner.inner();
+        let mut actions = Vec::new();
+
+        for (path, contents) in files {
+            let path = prepare_path(Cow::Owned(path))?.into_owned();
+            let exists = inner.has_file(branch_name, &path)?;
+
+            actions.push(Action::new(path, contents.map(Bytes::from), exists));
+        }
+
+        inner.create_commit(branch_name, message, actions)
+    }
+
+    fn get_default_branch(&self) -> Result<String, Self::Error> {
+        #[derive(Deserialize)]
+        struct ProjectResponse {
+            default_branch: String,
+        }
+
+        let default_branch = self
+            .inner
+            .inner
+            .inner()
+            .repository
+            .get("")?
+            .send()?
+            .error_for_status()?
+            .json::<ProjectResponse>()?
+            .default_branch;
+
+        Ok(default_branch)
+    }
+
+    fn create_branch(&self, name: &str) -> Result<(), Self::Error> {
+        let sha = self.sha()?;
+        let name = name.trim_start_matches('/');
+
+        self.inner
+            .inner
+            .inner()
+            .repository
+            .post("repository/branches")?
+            .query(&[("branch", name), ("ref", &sha)])
+            .send()?
+            .error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Updates the branch to point to the given SHA.
+    ///
+    /// The GitLab branches API can only create and delete branches and the
+    /// commits API always adds a new commit, even when forced, so there is no
+    /// way to move a branch to an existing commit. This instead deletes and
+    /// recreates the branch which is not atomic and fails for protected
+    /// branches. The branch is only briefly missing and is recreated at the
+    /// given SHA so no commits are lost.
+    fn update_branch(&self, name: &str, sha: &str) -> Result<(), Self::Error> {
+        let repository = &self.inner.inner.inner().repository;
+        let name = name.trim_start_matches('/');
+
+        repository
+            .delete(format!("repository/branches/{}", encode(name)))?
+            .send()?
+            .error_for_status()?;
+
+        repository
+            .post("repository/branches")?
+            .query(&[("branch", name), ("ref", sha)])
+            .send()?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+impl Remote for GitLab {
+    fn get_changelog_release(
+        &self,
+        package: &str,
+        version: &Version,
+        tag_prefix: &str,
+        labels: &LabelMapping,
+    ) -> Result<Release, Self::Error> {
+        self::changelog::get_release(
+            &self.inner.inner.inner().repository,
+            package,
+            version,
+            tag_prefix,
+            labels,
+        )
+    }
+
+    fn get_tags(&self) -> Result<Vec<String>, Self::Error> {
+        self::changelog::get_tags(&self.inner.inner.inner().repository)
+    }
+
+    fn get_unreleased_changes(
+        &self,
+        package: &str,
+        tag_prefix: &str,
+    ) -> Result<Vec<String>, Self::Error> {
+        self::changelog::get_unreleased_changes(
+            &self.inner.inner.inner().repository,
+            package,
+            tag_prefix,
+        )
+    }
+
+    /// Gets the URL comparing the given revisions.
+    ///
+    /// GitLab serves the comparison under the `/-/compare` path.
+    fn get_compare_url(&self, base: &str, head: &str) -> Option<String> {
+        Some(format!(
+            "{}/-/compare/{base}...{head}",
+            self.inner.inner.inner().repository.addr.url()
+        ))
+    }
+
+    /// Creates a merge request.
+    ///
+    /// This returns the merge request iid which is unique to the project.
+    fn create_pull_request(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<u64, Self::Error> {
+        #[derive(Serialize)]
+        struct NewMergeRequest<'a> {
+            source_branch: &'a str,
+            target_branch: &'a str,
+            title: &'a str,
+            description: &'a str,
+        }
+
+        #[derive(Deserialize)]
+        struct MergeRequestResponse {
+            iid: u64,
+        }
+
+        let iid = self
+            .inner
+            .inner
+            .inner()
+            .repository
+            .post("merge_requests")?
+            .json(&NewMergeRequest {
+                source_branch: head,
+                target_branch: base,
+                title,
+                description: body,
+            })
+            .send()?
+            .error_for_status()?
+            .json::<MergeRequestResponse>()?
+            .iid;
+
+        Ok(iid)
+    }
+
+    /// Creates a release.
+    ///
+    /// GitLab releases are identified by their tag and have no prerelease or
+    /// latest flags so this creates the tag and always returns zero.
+    fn create_release(
+        &self,
+        tag: &str,
+        sha: &str,
+        name: &str,
+        body: &str,
+        _prerelease: bool,
+        _latest: bool,
+    ) -> Result<u64, Self::Error> {
+        #[derive(Serialize)]
+        struct NewRelease<'a> {
+            tag_name: &'a str,
+            r#ref: &'a str,
+            name: &'a str,
+            description: &'a str,
+        }
+
+        self.inner
+            .inner
+            .inner()
+            .repository
+            .post("releases")?
+            .json(&NewRelease {
+                tag_name: tag,
+                r#ref: sha,
+                name,
+                description: body,
+            })
+            .send()?
+            .error_for_status()?;
+
+        Ok(0)
+    }
+}
+
+impl From<RepoAddr> for GitLab {
+    fn from(repo: RepoAddr) -> Self {
+        Self::open(repo).expect("valid repo specification")
+    }
+}
+
+/// A commit action for the multi-action commits endpoint.
+#[derive(Serialize)]
+struct Action {
+    action: &'static str,
+    file_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<&'static str>,
+}
+
+impl Action {
+    /// Constructs a new action to create, update or delete the file.
+    fn new(path: RelativePathBuf, file: Option<Bytes>, exists: bool) -> Self {
+        let action = match (&file, exists) {
+            (Some(_), true) => "update",
+            (Some(_), false) => "create",
+            (None, _) => "delete",
+        };
+
+        let (content, encoding) = match file {
+            Some(bytes) => match std::str::from_utf8(&bytes) {
+                Ok(string) => (Some(string.to_owned()), Some("text")),
+                Err(_) => (Some(BASE64_STANDARD.encode(bytes)), Some("base64")),
+            },
+            None => (None, None),
+        };
+
+        Self {
+            action,
+            file_path: path.into_string(),
+            content,
+            encoding,
+        }
+    }
+}
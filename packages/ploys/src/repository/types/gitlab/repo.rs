@@ -0,0 +1,173 @@
+use reqwest::Method;
+use reqwest::blocking::{Client as HttpClient, RequestBuilder};
+use serde::de::DeserializeOwned;
+
+use crate::repository::addr::RepoAddr;
+
+use super::Error;
+
+/// The GitLab repository information.
+#[derive(Clone, Debug)]
+pub struct Repo {
+    pub(crate) addr: RepoAddr,
+    token: Option<String>,
+    http_client: HttpClient,
+}
+
+impl Repo {
+    /// Constructs a new repository.
+    pub(crate) fn new(http_client: HttpClient, addr: RepoAddr) -> Self {
+        Self {
+            addr,
+            token: None,
+            http_client,
+        }
+    }
+
+    /// Gets the repository owner.
+    ///
+    /// This includes every group for repositories nested in subgroups.
+    pub fn owner(&self) -> &str {
+        self.addr.owner()
+    }
+
+    /// Gets the repository name.
+    pub fn name(&self) -> &str {
+        self.addr.name()
+    }
+
+    /// Sets the access token.
+    pub(super) fn set_token(&mut self, token: impl Into<String>) {
+        self.token = Some(token.into());
+    }
+
+    /// Validates whether the remote repository exists.
+    pub(super) fn validate(&self) -> Result<(), Error> {
+        self.head("")?.send()?.error_for_status()?;
+
+        Ok(())
+    }
+}
+
+impl Repo {
+    /// Gets the API endpoint.
+    ///
+    /// GitLab identifies projects by the URL-encoded path which includes any
+    /// nested groups.
+    pub(super) fn endpoint<P>(&self, path: P) -> String
+    where
+        P: AsRef<str>,
+    {
+        let id = encode(self.addr.full_name());
+
+        match path.as_ref() {
+            "" => format!("{}/api/v4/projects/{id}", self.addr.base_url()),
+            path => format!(
+                "{}/api/v4/projects/{id}/{}",
+                self.addr.base_url(),
+                path.trim_start_matches('/')
+            ),
+        }
+    }
+
+    /// Creates a HTTP request.
+    pub(super) fn request<P>(&self, method: Method, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        let mut request = self.http_client.request(method, self.endpoint(path));
+
+        if let Some(token) = &self.token {
+            request = request.header("PRIVATE-TOKEN", token);
+        }
+
+        Ok(request)
+    }
+
+    /// Creates a HEAD request.
+    pub(super) fn head<P>(&self, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        self.request(Method::HEAD, path)
+    }
+
+    /// Creates a GET request.
+    pub(super) fn get<P>(&self, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        self.request(Method::GET, path)
+    }
+
+    /// Creates a POST request.
+    pub(super) fn post<P>(&self, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        self.request(Method::POST, path)
+    }
+
+    /// Creates a DELETE request.
+    pub(super) fn delete<P>(&self, path: P) -> Result<RequestBuilder, Error>
+    where
+        P: AsRef<str>,
+    {
+        self.request(Method::DELETE, path)
+    }
+
+    /// Gets every page of a paginated GET request.
+    ///
+    /// GitLab returns the next page number in the `X-Next-Page` header which
+    /// is empty on the last page.
+    pub(super) fn get_all<P, Q, T>(&self, path: P, query: &Q) -> Result<Vec<T>, Error>
+    where
+        P: AsRef<str>,
+        Q: serde::Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let mut items = Vec::new();
+        let mut page = String::from("1");
+
+        loop {
+            let response = self
+                .get(path.as_ref())?
+                .query(query)
+                .query(&[("per_page", "100"), ("page", &page)])
+                .send()?
+                .error_for_status()?;
+
+            let next_page = response
+                .headers()
+                .get("X-Next-Page")
+                .and_then(|value| value.to_str().ok())
+                .filter(|value| !value.is_empty())
+                .map(ToOwned::to_owned);
+
+            items.extend(response.json::<Vec<T>>()?);
+
+            match next_page {
+                Some(next_page) => page = next_page,
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+/// Encodes the value as a single URL path segment.
+///
+/// This percent-encodes every byte other than the unreserved characters which
+/// includes the `/` separators in project and file paths.
+pub(super) fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                char::from(byte).to_string()
+            }
+            byte => format!("%{byte:02X}"),
+        })
+        .collect()
+}
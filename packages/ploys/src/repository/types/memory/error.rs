@@ -0,0 +1,40 @@
+use std::fmt::{self, Display};
+
+use crate::repository::types::staging::Error as StagingError;
+
+/// The `MemoryRemote` repository error.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An invalid path error.
+    Path(crate::repository::path::Error),
+    /// A branch not found error.
+    BranchNotFound(String),
+    /// A branch already exists error.
+    BranchExists(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Path(err) => Display::fmt(err, f),
+            Self::BranchNotFound(name) => write!(f, "Branch not found: {name}"),
+            Self::BranchExists(name) => write!(f, "Branch already exists: {name}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<crate::repository::path::Error> for Error {
+    fn from(err: crate::repository::path::Error) -> Self {
+        Self::Path(err)
+    }
+}
+
+impl From<StagingError> for Error {
+    fn from(err: StagingError) -> Self {
+        match err {
+            StagingError::Path(err) => Self::Path(err),
+        }
+    }
+}
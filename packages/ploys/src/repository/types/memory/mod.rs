@@ -0,0 +1,548 @@
+//! In-memory remote repository
+//!
+//! This module contains a remote repository that keeps its files in memory and
+//! records the branches, commits, pull requests, tags and releases created
+//! through it. The changelog history is scripted up front so that release
+//! workflows can be exercised and inspected without network access.
+
+mod error;
+mod records;
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use bytes::Bytes;
+use relative_path::{RelativePath, RelativePathBuf};
+use semver::Version;
+
+use crate::changelog::{LabelMapping, Release};
+use crate::repository::path::prepare_path;
+use crate::repository::types::staging::Staging;
+use crate::repository::{GitLike, Remote, Repository, Stage};
+
+pub use self::error::Error;
+pub use self::records::{MemoryCommit, MemoryPullRequest, MemoryRelease};
+
+/// The default branch name.
+const DEFAULT_BRANCH_NAME: &str = "main";
+
+/// An in-memory remote repository.
+///
+/// The files are owned by each instance whereas the recorded history is shared
+/// between clones. This allows inspecting the history through the original
+/// instance after a project or package has operated on a detached clone.
+#[derive(Clone)]
+pub struct MemoryRemote {
+    files: Staging,
+    state: Arc<Mutex<State>>,
+}
+
+impl MemoryRemote {
+    /// Creates a new in-memory remote repository.
+    ///
+    /// The default branch is `main` and starts with a single commit.
+    pub fn new() -> Self {
+        Self {
+            files: Staging::new(),
+            state: Arc::new(Mutex::new(State::new(DEFAULT_BRANCH_NAME))),
+        }
+    }
+
+    /// Sets the default branch.
+    ///
+    /// This renames the current default branch.
+    pub fn set_default_branch(&mut self, branch_name: impl Into<String>) {
+        let mut state = self.state();
+        let branch_name = branch_name.into();
+        let default_branch = std::mem::replace(&mut state.default_branch, branch_name.clone());
+
+        if let Some(sha) = state.branches.remove(&default_branch) {
+            state.branches.insert(branch_name, sha);
+        }
+    }
+
+    /// Builds the repository with the given default branch.
+    pub fn with_default_branch(mut self, branch_name: impl Into<String>) -> Self {
+        self.set_default_branch(branch_name);
+        self
+    }
+
+    /// Adds a tag pointing to the head of the default branch.
+    pub fn add_tag(&mut self, tag: impl Into<String>) -> &mut Self {
+        let mut state = self.state();
+        let sha = state.head().to_owned();
+
+        state.tags.insert(tag.into(), sha);
+        drop(state);
+
+        self
+    }
+
+    /// Builds the repository with the given tag.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.add_tag(tag);
+        self
+    }
+
+    /// Adds the changelog release for the given package.
+    ///
+    /// This is returned when requesting the changelog release of the package
+    /// with the same version.
+    pub fn add_changelog_release(
+        &mut self,
+        package: impl Into<String>,
+        release: impl Into<Release>,
+    ) -> &mut Self {
+        let release = release.into();
+
+        self.state()
+            .changelog_releases
+            .insert((package.into(), release.version().to_owned()), release);
+
+        self
+    }
+
+    /// Builds the repository with the given changelog release.
+    pub fn with_changelog_release(
+        mut self,
+        package: impl Into<String>,
+        release: impl Into<Release>,
+    ) -> Self {
+        self.add_changelog_release(package, release);
+        self
+    }
+
+    /// Adds an unreleased change message for the given package.
+    pub fn add_unreleased_change(
+        &mut self,
+        package: impl Into<String>,
+        message: impl Into<String>,
+    ) -> &mut Self {
+        self.state()
+            .unreleased_changes
+            .entry(package.into())
+            .or_default()
+            .push(message.into());
+
+        self
+    }
+
+    /// Builds the repository with the given unreleased change message.
+    pub fn with_unreleased_change(
+        mut self,
+        package: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.add_unreleased_change(package, message);
+        self
+    }
+}
+
+impl MemoryRemote {
+    /// Gets the branches and the commit SHA that each points to.
+    pub fn branches(&self) -> BTreeMap<String, String> {
+        self.state().branches.clone()
+    }
+
+    /// Gets the recorded commits in the order they were created.
+    pub fn commits(&self) -> Vec<MemoryCommit> {
+        self.state().commits.clone()
+    }
+
+    /// Gets the recorded pull requests in the order they were created.
+    pub fn pull_requests(&self) -> Vec<MemoryPullRequest> {
+        self.state().pull_requests.clone()
+    }
+
+    /// Gets the tags and the commit SHA that each points to.
+    pub fn tags(&self) -> BTreeMap<String, String> {
+        self.state().tags.clone()
+    }
+
+    /// Gets the recorded releases in the order they were created.
+    pub fn releases(&self) -> Vec<MemoryRelease> {
+        self.state().releases.clone()
+    }
+
+    /// Locks the shared state.
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl Repository for MemoryRemote {
+    type Error = Error;
+
+    fn get_file(&self, path: impl AsRef<RelativePath>) -> Result<Option<Bytes>, Self::Error> {
+        Ok(self.files.get_file(path)?)
+    }
+
+    fn get_index(&self) -> Result<impl Iterator<Item = Cow<'_, RelativePath>>, Self::Error> {
+        Ok(self.files.get_index()?)
+    }
+}
+
+impl Stage for MemoryRemote {
+    fn add_file(
+        &mut self,
+        path: impl Into<RelativePathBuf>,
+        file: impl Into<Bytes>,
+    ) -> Result<&mut Self, Self::Error> {
+        self.files.add_file(path, file)?;
+
+        Ok(self)
+    }
+
+    fn remove_file(
+        &mut self,
+        path: impl AsRef<RelativePath>,
+    ) -> Result<Option<Bytes>, Self::Error> {
+        Ok(self.files.remove_file(path)?)
+    }
+}
+
+impl GitLike for MemoryRemote {
+    fn sha(&self) -> Result<String, Self::Error> {
+        Ok(self.state().head().to_owned())
+    }
+
+    /// Records a commit on the given branch.
+    ///
+    /// The files are recorded with the commit and are not applied to the
+    /// repository files which always reflect the default branch.
+    fn commit_branch(
+        &self,
+        branch_name: &str,
+        message: &str,
+        files: Vec<(RelativePathBuf, Option<String>)>,
+    ) -> Result<String, Self::Error> {
+        let mut state = self.state();
+
+        if !state.branches.contains_key(branch_name) {
+            return Err(Error::BranchNotFound(branch_name.to_owned()));
+        }
+
+        let files = files
+            .into_iter()
+            .map(|(path, contents)| Ok((prepare_path(Cow::Owned(path))?.into_owned(), contents)))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let sha = state.next_sha();
+
+        state.commits.push(MemoryCommit {
+            sha: sha.clone(),
+            branch: branch_name.to_owned(),
+            message: message.to_owned(),
+            files,
+        });
+        state.branches.insert(branch_name.to_owned(), sha.clone());
+
+        Ok(sha)
+    }
+
+    fn get_default_branch(&self) -> Result<String, Self::Error> {
+        Ok(self.state().default_branch.clone())
+    }
+
+    fn create_branch(&self, name: &str) -> Result<(), Self::Error> {
+        let mut state = self.state();
+        let name = name.trim_start_matches('/');
+
+        if state.branches.contains_key(name) {
+            return Err(Error::BranchExists(name.to_owned()));
+        }
+
+        let sha = state.head().to_owned();
+
+        state.branches.insert(name.to_owned(), sha);
+
+        Ok(())
+    }
+
+    fn update_branch(&self, name: &str, sha: &str) -> Result<(), Self::Error> {
+        match self.state().branches.get_mut(name) {
+            Some(branch_sha) => {
+                *branch_sha = sha.to_owned();
+
+                Ok(())
+            }
+            None => Err(Error::BranchNotFound(name.to_owned())),
+        }
+    }
+}
+
+impl Remote for MemoryRemote {
+    /// Gets the changelog release for the given package version.
+    ///
+    /// This returns the scripted release or an empty release if there is none
+    /// for the version.
+    fn get_changelog_release(
+        &self,
+        package: &str,
+        version: &Version,
+        _tag_prefix: &str,
+        _labels: &LabelMapping,
+    ) -> Result<Release, Self::Error> {
+        let version = version.to_string();
+
+        Ok(self
+            .state()
+            .changelog_releases
+            .get(&(package.to_owned(), version.clone()))
+            .cloned()
+            .unwrap_or_else(|| Release::new(version)))
+    }
+
+    fn get_tags(&self) -> Result<Vec<String>, Self::Error> {
+        Ok(self.state().tags.keys().cloned().collect())
+    }
+
+    fn get_unreleased_changes(
+        &self,
+        package: &str,
+        _tag_prefix: &str,
+    ) -> Result<Vec<String>, Self::Error> {
+        Ok(self
+            .state()
+            .unreleased_changes
+            .get(package)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn create_pull_request(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<u64, Self::Error> {
+        let mut state = self.state();
+        let number = state.pull_requests.len() as u64 + 1;
+
+        state.pull_requests.push(MemoryPullRequest {
+            number,
+            head: head.to_owned(),
+            base: base.to_owned(),
+            title: title.to_owned(),
+            body: body.to_owned(),
+        });
+
+        Ok(number)
+    }
+
+    /// Creates a release.
+    ///
+    /// This also creates the tag if it does not already exist.
+    fn create_release(
+        &self,
+        tag: &str,
+        sha: &str,
+        name: &str,
+        body: &str,
+        prerelease: bool,
+        latest: bool,
+    ) -> Result<u64, Self::Error> {
+        let mut state = self.state();
+        let id = state.releases.len() as u64 + 1;
+
+        state
+            .tags
+            .entry(tag.to_owned())
+            .or_insert_with(|| sha.to_owned());
+
+        state.releases.push(MemoryRelease {
+            id,
+            tag: tag.to_owned(),
+            sha: sha.to_owned(),
+            name: name.to_owned(),
+            body: body.to_owned(),
+            prerelease,
+            latest,
+        });
+
+        Ok(id)
+    }
+}
+
+impl Default for MemoryRemote {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The shared state of the in-memory remote.
+struct State {
+    default_branch: String,
+    branches: BTreeMap<String, String>,
+    commits: Vec<MemoryCommit>,
+    pull_requests: Vec<MemoryPullRequest>,
+    tags: BTreeMap<String, String>,
+    releases: Vec<MemoryRelease>,
+    changelog_releases: HashMap<(String, String), Release>,
+    unreleased_changes: HashMap<String, Vec<String>>,
+    shas: u64,
+}
+
+impl State {
+    /// Creates a new state with an initial commit on the default branch.
+    fn new(default_branch: &str) -> Self {
+        let mut state = Self {
+            default_branch: default_branch.to_owned(),
+            branches: BTreeMap::new(),
+            commits: Vec::new(),
+            pull_requests: Vec::new(),
+            tags: BTreeMap::new(),
+            releases: Vec::new(),
+            changelog_releases: HashMap::new(),
+            unreleased_changes: HashMap::new(),
+            shas: 0,
+        };
+
+        let sha = state.next_sha();
+
+        state.branches.insert(default_branch.to_owned(), sha);
+        state
+    }
+
+    /// Gets the commit SHA at the head of the default branch.
+    fn head(&self) -> &str {
+        &self.branches[&self.default_branch]
+    }
+
+    /// Generates the next commit SHA.
+    ///
+    /// These are sequential to keep the history deterministic.
+    fn next_sha(&mut self) -> String {
+        self.shas += 1;
+
+        format!("{:040x}", self.shas)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use semver::Version;
+
+    use crate::changelog::{Change, Changeset, Release};
+    use crate::project::Project;
+    use crate::repository::{GitLike, Remote, Stage};
+
+    use super::{Error, MemoryRemote};
+
+    #[test]
+    fn test_history() -> Result<(), Error> {
+        let remote = MemoryRemote::new().with_tag("0.1.0");
+        let head = remote.sha()?;
+
+        assert_eq!(remote.get_default_branch()?, "main");
+        assert_eq!(remote.get_tags()?, ["0.1.0"]);
+
+        remote.create_branch("feature")?;
+
+        assert_eq!(
+            remote.create_branch("feature"),
+            Err(Error::BranchExists("feature".into()))
+        );
+        assert_eq!(
+            remote.commit_branch("missing", "Commit", Vec::new()),
+            Err(Error::BranchNotFound("missing".into()))
+        );
+
+        let sha = remote.commit_branch(
+            "feature",
+            "Add file",
+            vec![("README.md".into(), Some("# Example".into()))],
+        )?;
+
+        assert_ne!(sha, head);
+        assert_eq!(remote.sha()?, head);
+        assert_eq!(remote.branches()["feature"], sha);
+        assert_eq!(
+            remote.commits()[0].get_file("README.md"),
+            Some(Some("# Example"))
+        );
+
+        assert_eq!(remote.create_pull_request("feature", "main", "Add", "")?, 1);
+        assert_eq!(
+            remote.create_release("0.2.0", &sha, "0.2.0", "", false, true)?,
+            1
+        );
+        assert_eq!(remote.tags()["0.2.0"], sha);
+        assert_eq!(remote.releases()[0].tag(), "0.2.0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_release_request() -> Result<(), Box<dyn std::error::Error>> {
+        let release = Release::new("0.2.0").with_changeset(
+            Changeset::fixed().with_change(Change::new("Fix a bug").with_url("#2", "")),
+        );
+
+        let remote = MemoryRemote::new()
+            .with_file("Ploys.toml", "[project]\nname = \"example\"")?
+            .with_file(
+                "Cargo.toml",
+                "[package]\nname = \"example\"\nversion = \"0.1.0\"\n",
+            )?
+            .with_tag("0.1.0")
+            .with_changelog_release("example", release)
+            .with_unreleased_change("example", "Fix a bug");
+
+        let project = Project::open(remote.clone())?;
+        let request = project
+            .create_package_release_request("example", Version::new(0, 2, 0))?
+            .finish()?;
+
+        let pull_requests = remote.pull_requests();
+        let commits = remote.commits();
+
+        assert_eq!(request.id(), 1);
+        assert_eq!(request.version(), &Version::new(0, 2, 0));
+        assert_eq!(pull_requests.len(), 1);
+        assert_eq!(pull_requests[0].base(), "main");
+        assert_eq!(pull_requests[0].title(), request.title());
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].branch(), pull_requests[0].head());
+        assert_eq!(remote.branches()[pull_requests[0].head()], commits[0].sha());
+
+        let manifest = commits[0].get_file("Cargo.toml").flatten().unwrap();
+        let changelog = commits[0].get_file("CHANGELOG.md").flatten().unwrap();
+
+        assert!(manifest.contains("version = \"0.2.0\""));
+        assert!(changelog.contains("Fix a bug"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_go_tagged_versions() -> Result<(), Box<dyn std::error::Error>> {
+        let remote = MemoryRemote::new()
+            .with_file("Ploys.toml", "[project]\nname = \"example\"")?
+            .with_file("go.mod", "module github.com/ploys/example\n\ngo 1.22\n")?
+            .with_tag("v0.1.0")
+            .with_tag("v0.2.0");
+
+        let project = Project::open(remote.clone())?;
+
+        assert_eq!(
+            project.get_package("example").unwrap().version(),
+            Version::new(0, 0, 0)
+        );
+
+        let project = project.with_tags()?;
+
+        assert_eq!(
+            project.get_package("example").unwrap().version(),
+            Version::new(0, 2, 0)
+        );
+        assert_eq!(
+            project.packages().next().unwrap().version(),
+            Version::new(0, 2, 0)
+        );
+
+        Ok(())
+    }
+}
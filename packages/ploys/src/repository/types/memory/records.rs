@@ -0,0 +1,130 @@
+use relative_path::{RelativePath, RelativePathBuf};
+
+/// A commit recorded by the in-memory remote.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryCommit {
+    pub(super) sha: String,
+    pub(super) branch: String,
+    pub(super) message: String,
+    pub(super) files: Vec<(RelativePathBuf, Option<String>)>,
+}
+
+impl MemoryCommit {
+    /// Gets the commit SHA.
+    pub fn sha(&self) -> &str {
+        &self.sha
+    }
+
+    /// Gets the branch that the commit was added to.
+    pub fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    /// Gets the commit message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Gets an iterator over the changed files.
+    ///
+    /// Files without contents were removed by the commit.
+    pub fn files(&self) -> impl Iterator<Item = (&RelativePath, Option<&str>)> {
+        self.files
+            .iter()
+            .map(|(path, contents)| (path.as_relative_path(), contents.as_deref()))
+    }
+
+    /// Gets the contents of the given file if it was changed by the commit.
+    pub fn get_file(&self, path: impl AsRef<RelativePath>) -> Option<Option<&str>> {
+        self.files
+            .iter()
+            .find(|(file_path, _)| file_path == path.as_ref())
+            .map(|(_, contents)| contents.as_deref())
+    }
+}
+
+/// A pull request recorded by the in-memory remote.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryPullRequest {
+    pub(super) number: u64,
+    pub(super) head: String,
+    pub(super) base: String,
+    pub(super) title: String,
+    pub(super) body: String,
+}
+
+impl MemoryPullRequest {
+    /// Gets the pull request number.
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    /// Gets the head branch.
+    pub fn head(&self) -> &str {
+        &self.head
+    }
+
+    /// Gets the base branch.
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    /// Gets the pull request title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Gets the pull request body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+}
+
+/// A release recorded by the in-memory remote.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryRelease {
+    pub(super) id: u64,
+    pub(super) tag: String,
+    pub(super) sha: String,
+    pub(super) name: String,
+    pub(super) body: String,
+    pub(super) prerelease: bool,
+    pub(super) latest: bool,
+}
+
+impl MemoryRelease {
+    /// Gets the release id.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Gets the release tag.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Gets the commit SHA that the release tag points to.
+    pub fn sha(&self) -> &str {
+        &self.sha
+    }
+
+    /// Gets the release name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the release body.
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    /// Checks whether the release is a prerelease.
+    pub fn is_prerelease(&self) -> bool {
+        self.prerelease
+    }
+
+    /// Checks whether the release is marked as the latest release.
+    pub fn is_latest(&self) -> bool {
+        self.latest
+    }
+}
@@ -1,9 +1,14 @@
 pub mod staging;
 
+mod pulls;
+
 #[cfg(feature = "fs")]
 pub mod fs;
 
 #[cfg(feature = "git")]
 pub mod git;
 
+pub mod gitea;
 pub mod github;
+pub mod gitlab;
+pub mod memory;
@@ -0,0 +1,165 @@
+use semver::Version;
+use time::OffsetDateTime;
+use time::format_description::well_known::Iso8601;
+
+use crate::changelog::{Change, Changeset, LabelMapping, Release};
+
+/// A merged pull request.
+///
+/// This is the common representation of a pull or merge request used to build
+/// changelog releases from the request labels on each remote repository.
+pub(super) struct PullRequest {
+    pub(super) number: u64,
+    pub(super) reference: String,
+    pub(super) title: String,
+    pub(super) body: String,
+    pub(super) url: String,
+    pub(super) author: Option<String>,
+    pub(super) labels: Vec<String>,
+}
+
+/// Builds the changelog release from the merged pull requests.
+///
+/// The pull requests are filtered to those labelled for the given package and
+/// the first pull request of each author is found with the given function to
+/// list new contributors.
+pub(super) fn build_release<E>(
+    version: &Version,
+    date: OffsetDateTime,
+    url: String,
+    pull_requests: Vec<PullRequest>,
+    package: &str,
+    labels: &LabelMapping,
+    mut get_first_pull_request: impl FnMut(&str) -> Result<Option<u64>, E>,
+) -> Result<Release, E> {
+    let mut release = Release::new(version.to_string());
+    let mut changesets = Vec::<Changeset>::new();
+
+    release.set_date(date.format(&Iso8601::DATE).expect("date"));
+    release.set_url(url);
+
+    let mut authors = Vec::<(String, u64, String, String)>::new();
+
+    for pull_request in filter_pull_requests(pull_requests, package) {
+        let label = labels.get_changeset_label(pull_request.labels.iter().map(String::as_str));
+
+        let index = match changesets
+            .iter()
+            .position(|changeset| changeset.label() == label)
+        {
+            Some(index) => index,
+            None => {
+                changesets.push(Changeset::new(label));
+                changesets.len() - 1
+            }
+        };
+
+        let mut change =
+            Change::new(pull_request.title).with_url(&pull_request.reference, &pull_request.url);
+
+        if let Some(author) = pull_request.author {
+            change.add_author(&author);
+
+            if !authors.iter().any(|(login, _, _, _)| *login == author) {
+                authors.push((
+                    author,
+                    pull_request.number,
+                    pull_request.reference,
+                    pull_request.url,
+                ));
+            }
+        }
+
+        changesets[index].add_change(change);
+    }
+
+    LabelMapping::sort_changesets(&mut changesets);
+
+    let mut new_contributors = Changeset::new_contributors();
+
+    for (login, number, reference, url) in authors {
+        if get_first_pull_request(&login)? == Some(number) {
+            new_contributors.add_change(
+                Change::new(format!("@{login} made their first contribution"))
+                    .with_url(reference, url),
+            );
+        }
+    }
+
+    if new_contributors.changes().next().is_some() {
+        changesets.push(new_contributors);
+    }
+
+    for changeset in changesets {
+        release.add_changeset(changeset);
+    }
+
+    Ok(release)
+}
+
+/// Gets the change messages of the pull requests for the given package.
+pub(super) fn get_change_messages(pull_requests: Vec<PullRequest>, package: &str) -> Vec<String> {
+    filter_pull_requests(pull_requests, package)
+        .map(|pull_request| match pull_request.body.trim().is_empty() {
+            true => pull_request.title,
+            false => format!("{}\n\n{}", pull_request.title, pull_request.body),
+        })
+        .collect()
+}
+
+/// Gets the previous version or last version if no previous exists.
+pub(super) fn get_previous_version<'a>(
+    tag_prefix: &str,
+    version: &Version,
+    tags: impl IntoIterator<Item = &'a str>,
+) -> Option<Version> {
+    let mut versions = tags
+        .into_iter()
+        .filter_map(|tag| tag.strip_prefix(tag_prefix)?.parse::<Version>().ok())
+        .collect::<Vec<_>>();
+
+    versions.sort();
+
+    let previous_version = versions
+        .iter()
+        .filter(|previous_version| *previous_version < version)
+        .rfind(|previous_version| version.pre.is_empty() || previous_version.pre.is_empty());
+
+    match previous_version {
+        Some(previous_version) => Some(previous_version.clone()),
+        None => match versions.last() {
+            Some(last_version) if last_version != version => Some(last_version.clone()),
+            _ => None,
+        },
+    }
+}
+
+/// Gets the latest version from the tags with the given prefix.
+pub(super) fn get_latest_version<'a>(
+    tag_prefix: &str,
+    tags: impl IntoIterator<Item = &'a str>,
+) -> Option<Version> {
+    tags.into_iter()
+        .filter_map(|tag| tag.strip_prefix(tag_prefix)?.parse::<Version>().ok())
+        .max()
+}
+
+/// Filters the pull requests to those labelled for the given package.
+///
+/// This excludes release pull requests that would otherwise be included.
+fn filter_pull_requests(
+    pull_requests: Vec<PullRequest>,
+    package: &str,
+) -> impl Iterator<Item = PullRequest> {
+    let package_label = format!("package: {package}");
+
+    pull_requests
+        .into_iter()
+        .filter(move |pull_request| pull_request.labels.contains(&package_label))
+        .filter(|pull_request| {
+            !pull_request
+                .labels
+                .iter()
+                .any(|label| label.contains("release"))
+        })
+}
@@ -8,11 +8,13 @@ pub trait GitLike: Repository {
     fn sha(&self) -> Result<String, Self::Error>;
 
     /// Commits the changes to the repository.
+    ///
+    /// Files without contents are removed from the branch.
     fn commit_branch(
         &self,
         branch_name: &str,
         message: &str,
-        files: Vec<(RelativePathBuf, String)>,
+        files: Vec<(RelativePathBuf, Option<String>)>,
     ) -> Result<String, Self::Error>;
 
     /// Gets the default branch.
@@ -37,7 +39,7 @@ where
         &self,
         branch_name: &str,
         message: &str,
-        files: Vec<(RelativePathBuf, String)>,
+        files: Vec<(RelativePathBuf, Option<String>)>,
     ) -> Result<String, Self::Error> {
         (**self).commit_branch(branch_name, message, files)
     }
@@ -67,7 +69,7 @@ where
         &self,
         branch_name: &str,
         message: &str,
-        files: Vec<(RelativePathBuf, String)>,
+        files: Vec<(RelativePathBuf, Option<String>)>,
     ) -> Result<String, Self::Error> {
         (**self).commit_branch(branch_name, message, files)
     }
@@ -1,7 +1,8 @@
 use ploys::project::{Error, Project};
 use ploys::repository::revision::Revision;
 use ploys::repository::types::git::{Error as GitError, Git};
-use ploys::repository::{Commit, Open, Repository, Stage};
+use ploys::repository::{Commit, GitLike, Open, Remote, Repository, Stage};
+use semver::Version;
 use tempfile::tempdir;
 
 #[test]
@@ -45,7 +46,7 @@ fn test_repository() -> Result<(), GitError> {
         .map(|name| name.shorten().to_string())
         .unwrap_or_else(|| gix::init::DEFAULT_BRANCH_NAME.to_string());
 
-    let mut repo = Git::open(dir.path())?.with_revision(Revision::branch(branch_name));
+    let mut repo = Git::open(dir.path())?.with_revision(Revision::branch(&branch_name));
 
     repo.add_file("Cargo.toml", "[package]\nname = \"example\"")?;
     repo.commit("Fourth commit")?;
@@ -85,6 +86,60 @@ fn test_repository() -> Result<(), GitError> {
         Some("[project]\nname = \"example\"".into())
     );
     assert_eq!(repo.get_file("commit")?, None);
+    assert_eq!(repo.get_default_branch()?, branch_name);
+
+    std::fs::write(
+        dir.path().join(".git/config"),
+        format!(
+            "{}\n[init]\n\tdefaultBranch = missing\n",
+            std::fs::read_to_string(dir.path().join(".git/config"))?
+        ),
+    )?;
+
+    let repo = Git::open(dir.path())?;
+
+    assert_eq!(repo.get_default_branch()?, branch_name);
+    assert_eq!(
+        repo.create_pull_request(&branch_name, &branch_name, "Title", "Body")?,
+        0
+    );
+
+    dir.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_release() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = tempdir()?;
+    let mut repo = Git::init(dir.path())?;
+
+    repo.add_file("Ploys.toml", "[project]\nname = \"example\"")?;
+    repo.add_file(
+        "Cargo.toml",
+        "[package]\nname = \"example\"\nversion = \"0.1.0\"\n",
+    )?;
+    repo.commit("Initial commit")?;
+
+    let project = Project::git(dir.path())?;
+    let request = project
+        .create_package_release_request("example", Version::new(0, 2, 0))?
+        .finish()?;
+
+    assert_eq!(request.id(), 0);
+    assert_eq!(request.version(), &Version::new(0, 2, 0));
+
+    let project = Project::git_with_revision(dir.path(), Revision::branch("release/0.2.0"))?;
+
+    assert_eq!(
+        project.get_package("example").unwrap().version(),
+        Version::new(0, 2, 0)
+    );
+
+    let release = project.create_package_release("example")?.finish()?;
+
+    assert_eq!(release.name(), "0.2.0");
+    assert_eq!(Git::open(dir.path())?.get_tags()?, ["0.2.0"]);
 
     dir.close()?;
 
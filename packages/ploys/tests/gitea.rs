@@ -0,0 +1,416 @@
+mod mock;
+
+use ploys::changelog::{Change, Changeset, LabelMapping};
+use ploys::repository::types::gitea::{Error, Gitea};
+use ploys::repository::{GitLike, Open, Remote, RepoAddr, Repository};
+use semver::Version;
+
+use self::mock::MockServer;
+
+#[test]
+fn test_repository() -> Result<(), Error> {
+    let server = MockServer::start(|request| {
+        let path = request
+            .path
+            .strip_prefix("/api/v1/repos/owner/example")
+            .unwrap_or_default();
+
+        match (request.method.as_str(), path) {
+            ("GET", "") => (200, r#"{"default_branch":"main"}"#.into()),
+            ("GET", "/contents/README.md") if request.query.is_empty() => {
+                (200, r#"{"content":"IyBFeGFtcGxl"}"#.into())
+            }
+            ("GET", "/contents/src/lib.rs") => (404, "{}".into()),
+            ("GET", "/branches/main") => (200, r#"{"commit":{"id":"c3"}}"#.into()),
+            ("GET", "/contents/README.md") => (200, r#"{"sha":"b0"}"#.into()),
+            ("POST", "/branches") => (201, "{}".into()),
+            ("POST", "/contents") => (201, r#"{"commit":{"sha":"c4"}}"#.into()),
+            ("POST", "/pulls") => (201, r#"{"number":3}"#.into()),
+            ("POST", "/releases") => (201, r#"{"id":7}"#.into()),
+            ("GET", "/tags") => (
+                200,
+                r#"[{"name":"0.1.0","commit":{"sha":"c1","created":"2024-01-01T00:00:00Z"}}]"#
+                    .into(),
+            ),
+            ("GET", "/compare/0.1.0...main") => {
+                (200, r#"{"commits":[{"sha":"c2"},{"sha":"c3"}]}"#.into())
+            }
+            ("GET", "/pulls") if request.query.contains("state=closed") => (
+                200,
+                r#"[
+                    {"number":2,"title":"Fix a bug","body":"Details","html_url":"https://gitea.com/owner/example/pulls/2","user":{"login":"one"},"labels":[{"name":"package: example"},{"name":"type: fix"}],"merged":true,"merged_at":"2024-01-02T00:00:00Z","merge_commit_sha":"c2","head":{"sha":"b2"}},
+                    {"number":1,"title":"Initial","body":"","html_url":"","user":{"login":"one"},"labels":[{"name":"package: example"}],"merged":true,"merged_at":"2023-12-31T00:00:00Z","merge_commit_sha":"c1","head":{"sha":"b1"}}
+                ]"#
+                    .into(),
+            ),
+            _ => (404, "{}".into()),
+        }
+    });
+
+    let addr = RepoAddr::with_host(server.url(), "owner", "example").unwrap();
+    let repo = Gitea::open(addr)?.with_token("token");
+
+    assert_eq!(repo.get_file("README.md")?, Some("# Example".into()));
+    assert_eq!(repo.get_file("src/lib.rs")?, None);
+    assert_eq!(repo.get_default_branch()?, "main");
+    assert_eq!(repo.sha()?, "c3");
+    assert_eq!(
+        repo.get_compare_url("0.1.0", "0.2.0"),
+        Some(format!(
+            "{}/owner/example/compare/0.1.0...0.2.0",
+            server.url()
+        ))
+    );
+
+    repo.create_branch("release/0.2.0")?;
+
+    let sha = repo.commit_branch(
+        "release/0.2.0",
+        "Release `0.2.0`",
+        vec![
+            ("README.md".into(), Some(String::from("# Example\n"))),
+            ("CHANGELOG.md".into(), Some(String::from("# Changelog\n"))),
+        ],
+    )?;
+
+    assert_eq!(sha, "c4");
+    assert_eq!(
+        repo.create_pull_request("release/0.2.0", "main", "Release `0.2.0`", "")?,
+        3
+    );
+    assert_eq!(
+        repo.get_unreleased_changes("example", "")?,
+        ["Fix a bug\n\nDetails"]
+    );
+
+    let release = repo.get_changelog_release(
+        "example",
+        &Version::new(0, 2, 0),
+        "",
+        &LabelMapping::default(),
+    )?;
+
+    assert_eq!(
+        release.changesets().cloned().collect::<Vec<_>>(),
+        [Changeset::fixed().with_change(
+            Change::new("Fix a bug")
+                .with_url("#2", "https://gitea.com/owner/example/pulls/2")
+                .with_author("one")
+        )]
+    );
+
+    assert_eq!(
+        repo.create_release("0.2.0", "c4", "0.2.0", "Notes", false, true)?,
+        7
+    );
+
+    let requests = server.requests();
+    let commit = requests
+        .iter()
+        .find(|request| request.method == "POST" && request.path.ends_with("/contents"))
+        .unwrap();
+
+    assert!(
+        commit
+            .body
+            .contains(r#""operation":"update","path":"README.md""#)
+    );
+    assert!(
+        commit
+            .body
+            .contains(r#""operation":"create","path":"CHANGELOG.md""#)
+    );
+    assert!(
+        requests.iter().any(|request| {
+            request.path.ends_with("/releases") && request.body.contains("Notes")
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_commit_branch() -> Result<(), Error> {
+    let server = MockServer::start(|request| {
+        let path = request
+            .path
+            .strip_prefix("/api/v1/repos/owner/example")
+            .unwrap_or_default();
+
+        match (request.method.as_str(), path) {
+            ("GET", "/contents/README.md") => (200, r#"{"sha":"b0"}"#.into()),
+            ("GET", "/contents/old.txt") => (200, r#"{"sha":"b1"}"#.into()),
+            ("GET", "/contents/CHANGELOG.md") => (404, "{}".into()),
+            ("POST", "/contents") => (201, r#"{"commit":{"sha":"c5"}}"#.into()),
+            _ => (404, "{}".into()),
+        }
+    });
+
+    let addr = RepoAddr::with_host(server.url(), "owner", "example").unwrap();
+    let repo = Gitea::open(addr)?.with_token("token");
+
+    let sha = repo.commit_branch(
+        "release/0.2.0",
+        "Release `0.2.0`",
+        vec![
+            ("README.md".into(), Some(String::from("# Example\n"))),
+            ("CHANGELOG.md".into(), Some(String::from("# Changelog\n"))),
+            ("old.txt".into(), None),
+        ],
+    )?;
+
+    assert_eq!(sha, "c5");
+
+    let requests = server.requests();
+    let lookups = requests
+        .iter()
+        .filter(|request| request.method == "GET")
+        .collect::<Vec<_>>();
+
+    assert_eq!(lookups.len(), 3);
+    assert!(
+        lookups
+            .iter()
+            .all(|request| request.query == "ref=release%2F0.2.0")
+    );
+
+    let commit = requests
+        .iter()
+        .find(|request| request.method == "POST")
+        .unwrap();
+    let body = serde_json::from_str::<serde_json::Value>(&commit.body).unwrap();
+
+    assert_eq!(body["branch"], "release/0.2.0");
+    assert_eq!(body["message"], "Release `0.2.0`");
+    assert_eq!(
+        body["files"],
+        serde_json::json!([
+            {"operation": "update", "path": "README.md", "content": "IyBFeGFtcGxlCg==", "sha": "b0"},
+            {"operation": "create", "path": "CHANGELOG.md", "content": "IyBDaGFuZ2Vsb2cK"},
+            {"operation": "delete", "path": "old.txt", "sha": "b1"},
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_update_branch() -> Result<(), Error> {
+    let server = MockServer::start(|request| {
+        let path = request
+            .path
+            .strip_prefix("/api/v1/repos/owner/example")
+            .unwrap_or_default();
+
+        match (request.method.as_str(), path) {
+            ("DELETE", "/branches/release/0.2.0") => (204, String::new()),
+            ("POST", "/branches") => (201, "{}".into()),
+            _ => (404, "{}".into()),
+        }
+    });
+
+    let addr = RepoAddr::with_host(server.url(), "owner", "example").unwrap();
+    let repo = Gitea::open(addr)?.with_token("token");
+
+    repo.update_branch("/release/0.2.0", "c4")?;
+
+    let requests = server.requests();
+
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "DELETE");
+    assert_eq!(requests[1].method, "POST");
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&requests[1].body).unwrap(),
+        serde_json::json!({"new_branch_name": "release/0.2.0", "old_ref_name": "c4"})
+    );
+
+    assert!(repo.update_branch("missing", "c4").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_create_pull_request() -> Result<(), Error> {
+    let server =
+        MockServer::start(
+            |request| match (request.method.as_str(), request.path.as_str()) {
+                ("POST", "/api/v1/repos/owner/example/pulls") => (201, r#"{"number":12}"#.into()),
+                _ => (404, "{}".into()),
+            },
+        );
+
+    let addr = RepoAddr::with_host(server.url(), "owner", "example").unwrap();
+    let repo = Gitea::open(addr)?.with_token("token");
+
+    assert_eq!(
+        repo.create_pull_request("release/0.2.0", "main", "Release `0.2.0`", "Notes")?,
+        12
+    );
+
+    let requests = server.requests();
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&requests[0].body).unwrap(),
+        serde_json::json!({
+            "head": "release/0.2.0",
+            "base": "main",
+            "title": "Release `0.2.0`",
+            "body": "Notes",
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_changelog_labels() -> Result<(), Error> {
+    let server = MockServer::start(|request| {
+        let path = request
+            .path
+            .strip_prefix("/api/v1/repos/owner/example")
+            .unwrap_or_default();
+
+        match (request.method.as_str(), path) {
+            ("GET", "") => (200, r#"{"default_branch":"main"}"#.into()),
+            ("GET", "/tags") => (
+                200,
+                r#"[
+                    {"name":"0.2.0","commit":{"sha":"c4","created":"2024-02-01T00:00:00Z"}},
+                    {"name":"0.1.0","commit":{"sha":"c1","created":"2024-01-01T00:00:00Z"}}
+                ]"#
+                .into(),
+            ),
+            ("GET", "/compare/0.1.0...0.2.0") => (
+                200,
+                r#"{"commits":[{"sha":"c2"},{"sha":"c3"},{"sha":"c4"}]}"#.into(),
+            ),
+            ("GET", "/pulls") => (
+                200,
+                r#"[
+                    {"number":5,"title":"Abandoned","body":null,"html_url":"https://gitea.com/owner/example/pulls/5","user":{"login":"three"},"labels":[{"name":"package: example"}],"merged":false,"merged_at":null,"merge_commit_sha":null,"head":{"sha":"b5"}},
+                    {"number":4,"title":"Add a feature","body":null,"html_url":"https://gitea.com/owner/example/pulls/4","user":{"login":"two"},"labels":[{"name":"package: example"},{"name":"kind: feature"}],"merged":true,"merged_at":"2024-01-04T00:00:00Z","merge_commit_sha":"c4","head":{"sha":"b4"}},
+                    {"number":3,"title":"Update docs","body":null,"html_url":"https://gitea.com/owner/example/pulls/3","user":{"login":"one"},"labels":[{"name":"package: example"}],"merged":true,"merged_at":"2024-01-03T00:00:00Z","merge_commit_sha":null,"head":{"sha":"c3"}},
+                    {"number":2,"title":"Fix a bug","body":null,"html_url":"https://gitea.com/owner/example/pulls/2","user":{"login":"one"},"labels":[{"name":"package: example"},{"name":"kind: bug"}],"merged":true,"merged_at":"2024-01-02T00:00:00Z","merge_commit_sha":"c2","head":{"sha":"b2"}},
+                    {"number":1,"title":"Initial","body":null,"html_url":"https://gitea.com/owner/example/pulls/1","user":{"login":"one"},"labels":[{"name":"package: example"}],"merged":true,"merged_at":"2023-12-31T00:00:00Z","merge_commit_sha":"c1","head":{"sha":"b1"}}
+                ]"#
+                .into(),
+            ),
+            _ => (404, "{}".into()),
+        }
+    });
+
+    let addr = RepoAddr::with_host(server.url(), "owner", "example").unwrap();
+    let repo = Gitea::open(addr)?.with_token("token");
+    let labels = LabelMapping::new()
+        .with_label("kind: feature", "Added")
+        .with_label("kind: bug", "Fixed");
+
+    let release = repo.get_changelog_release("example", &Version::new(0, 2, 0), "", &labels)?;
+
+    assert_eq!(release.date(), Some("2024-02-01"));
+    assert_eq!(
+        release.changesets().cloned().collect::<Vec<_>>(),
+        [
+            Changeset::added().with_change(
+                Change::new("Add a feature")
+                    .with_url("#4", "https://gitea.com/owner/example/pulls/4")
+                    .with_author("two")
+            ),
+            Changeset::changed().with_change(
+                Change::new("Update docs")
+                    .with_url("#3", "https://gitea.com/owner/example/pulls/3")
+                    .with_author("one")
+            ),
+            Changeset::fixed().with_change(
+                Change::new("Fix a bug")
+                    .with_url("#2", "https://gitea.com/owner/example/pulls/2")
+                    .with_author("one")
+            ),
+            Changeset::new_contributors().with_change(
+                Change::new("@two made their first contribution")
+                    .with_url("#4", "https://gitea.com/owner/example/pulls/4")
+            ),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_pagination() -> Result<(), Error> {
+    let server = MockServer::start(|request| {
+        let path = request
+            .path
+            .strip_prefix("/api/v1/repos/owner/example")
+            .unwrap_or_default();
+
+        if path.is_empty() {
+            return (200, r#"{"default_branch":"main"}"#.into());
+        }
+
+        let page = match request.query.ends_with("limit=50&page=1") {
+            true => 1,
+            false if request.query.ends_with("limit=50&page=2") => 2,
+            false => return (400, "{}".into()),
+        };
+
+        let items = match (path, page) {
+            ("/tags", 1) => (0..50)
+                .map(|patch| {
+                    format!(
+                        r#"{{"name":"0.0.{patch}","commit":{{"sha":"t{patch}","created":"2023-01-01T00:00:00Z"}}}}"#
+                    )
+                })
+                .collect::<Vec<_>>(),
+            ("/tags", _) => vec![
+                r#"{"name":"0.1.0","commit":{"sha":"c1","created":"2024-01-01T00:00:00Z"}}"#
+                    .to_owned(),
+            ],
+            ("/commits", 1) => (0..50).map(|index| format!(r#"{{"sha":"x{index}"}}"#)).collect(),
+            ("/commits", _) => vec![r#"{"sha":"c2"}"#.to_owned()],
+            ("/pulls", 1) => (10..60)
+                .map(|number| {
+                    format!(
+                        r#"{{"number":{number},"title":"Other","body":null,"html_url":"","user":null,"labels":[{{"name":"package: example"}}],"merged":true,"merged_at":"2024-01-01T00:00:00Z","merge_commit_sha":"o{number}","head":{{"sha":"h{number}"}}}}"#
+                    )
+                })
+                .collect(),
+            ("/pulls", _) => vec![
+                r#"{"number":2,"title":"Fix a bug","body":"Details","html_url":"","user":null,"labels":[{"name":"package: example"}],"merged":true,"merged_at":"2024-01-02T00:00:00Z","merge_commit_sha":"c2","head":{"sha":"b2"}}"#
+                    .to_owned(),
+            ],
+            _ => return (404, "{}".into()),
+        };
+
+        (200, format!("[{}]", items.join(",")))
+    });
+
+    let addr = RepoAddr::with_host(server.url(), "owner", "example").unwrap();
+    let repo = Gitea::open(addr)?.with_token("token");
+
+    let tags = repo.get_tags()?;
+
+    assert_eq!(tags.len(), 51);
+    assert_eq!(tags.last().map(String::as_str), Some("0.1.0"));
+    assert_eq!(
+        repo.get_unreleased_changes("example", "example-")?,
+        ["Fix a bug\n\nDetails"]
+    );
+
+    let requests = server.requests();
+
+    assert!(
+        requests
+            .iter()
+            .any(|request| request.path.ends_with("/commits")
+                && request.query.contains("sha=main")
+                && request.query.ends_with("page=2"))
+    );
+    assert!(
+        requests
+            .iter()
+            .all(|request| !request.query.ends_with("page=3"))
+    );
+
+    Ok(())
+}
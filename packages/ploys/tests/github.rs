@@ -1,4 +1,8 @@
-use ploys::client::{Client, Error, Token};
+mod mock;
+
+use ploys::client::{Client, Error, GitHubAddr, Token};
+
+use self::mock::MockServer;
 
 #[test]
 #[ignore]
@@ -43,3 +47,25 @@ fn test_project() -> Result<(), Error> {
 
     Ok(())
 }
+
+#[test]
+fn test_enterprise_server() -> Result<(), Error> {
+    let server = MockServer::start(|request| match request.path.as_str() {
+        "/api/v3/user" => (200, r#"{"login":"octocat"}"#.into()),
+        _ => (404, "{}".into()),
+    });
+
+    let client = Client::build()
+        .with_github(server.url().parse::<GitHubAddr>().unwrap())
+        .with_access_token_flow(Token::new("ghp_abc_DEF_123").unwrap())
+        .finished()?;
+
+    assert_eq!(client.login()?.user(), "octocat");
+    assert!(client.get_project("owner/example").is_err());
+    assert!(server.requests().iter().any(|request| {
+        request.path == "/api/v3/repos/owner/example/contents/Ploys.toml"
+            && request.query == "ref=HEAD"
+    }));
+
+    Ok(())
+}
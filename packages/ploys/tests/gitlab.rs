@@ -0,0 +1,141 @@
+mod mock;
+
+use ploys::changelog::{Change, Changeset, LabelMapping};
+use ploys::repository::types::gitlab::{Error, GitLab};
+use ploys::repository::{GitLike, Open, Remote, RepoAddr, Repository};
+use semver::Version;
+
+use self::mock::MockServer;
+
+#[test]
+fn test_repository() -> Result<(), Error> {
+    let server = MockServer::start(|request| {
+        let path = request
+            .path
+            .strip_prefix("/api/v4/projects/group%2Fsubgroup%2Fexample")
+            .unwrap_or_default();
+
+        match (request.method.as_str(), path) {
+            ("GET", "") => (200, r#"{"default_branch":"main"}"#.into()),
+            ("GET", "/repository/files/README.md/raw") => (200, "# Example".into()),
+            ("GET", "/repository/files/src%2Flib.rs/raw") => (404, "{}".into()),
+            ("GET", "/repository/commits/HEAD") => (200, r#"{"id":"c3"}"#.into()),
+            ("HEAD", "/repository/files/README.md") => (200, String::new()),
+            ("HEAD", _) => (404, String::new()),
+            ("POST", "/repository/branches") => (201, "{}".into()),
+            ("DELETE", "/repository/branches/release%2F0.2.0") => (204, String::new()),
+            ("POST", "/repository/commits") => (201, r#"{"id":"c4"}"#.into()),
+            ("POST", "/merge_requests") => (201, r#"{"iid":3}"#.into()),
+            ("POST", "/releases") => (201, "{}".into()),
+            ("GET", "/repository/tags") => (
+                200,
+                r#"[{"name":"0.1.0","commit":{"committed_date":"2024-01-01T00:00:00.000+00:00"}}]"#
+                    .into(),
+            ),
+            ("GET", "/repository/compare") => (200, r#"{"commits":[{"id":"c2"},{"id":"c3"}]}"#.into()),
+            ("GET", "/merge_requests") if request.query.contains("author_username") => (
+                200,
+                r#"[{"iid":1,"title":"Initial","description":null,"merged_at":null,"web_url":"","author":null,"labels":[],"sha":null,"merge_commit_sha":null,"squash_commit_sha":null}]"#
+                    .into(),
+            ),
+            ("GET", "/merge_requests") => (
+                200,
+                r#"[
+                    {"iid":2,"title":"Fix a bug","description":"Details","merged_at":"2024-01-02T00:00:00.000+00:00","web_url":"https://gitlab.com/group/subgroup/example/-/merge_requests/2","author":{"username":"one"},"labels":["package: example","type: fix"],"sha":"b2","merge_commit_sha":"c2","squash_commit_sha":null},
+                    {"iid":1,"title":"Initial","description":null,"merged_at":"2023-12-31T00:00:00.000+00:00","web_url":"","author":{"username":"one"},"labels":["package: example"],"sha":"b1","merge_commit_sha":"c1","squash_commit_sha":null}
+                ]"#
+                    .into(),
+            ),
+            _ => (404, "{}".into()),
+        }
+    });
+
+    let addr = RepoAddr::with_host(server.url(), "group/subgroup", "example").unwrap();
+    let repo = GitLab::open(addr)?.with_token("glpat-token");
+
+    assert_eq!(repo.get_file("README.md")?, Some("# Example".into()));
+    assert_eq!(repo.get_file("src/lib.rs")?, None);
+    assert_eq!(repo.get_default_branch()?, "main");
+    assert_eq!(repo.sha()?, "c3");
+    assert_eq!(
+        repo.get_compare_url("0.1.0", "0.2.0"),
+        Some(format!(
+            "{}/group/subgroup/example/-/compare/0.1.0...0.2.0",
+            server.url()
+        ))
+    );
+
+    repo.create_branch("release/0.2.0")?;
+
+    let sha = repo.commit_branch(
+        "release/0.2.0",
+        "Release `0.2.0`",
+        vec![
+            ("README.md".into(), Some(String::from("# Example\n"))),
+            ("CHANGELOG.md".into(), Some(String::from("# Changelog\n"))),
+        ],
+    )?;
+
+    assert_eq!(sha, "c4");
+    assert_eq!(
+        repo.create_pull_request("release/0.2.0", "main", "Release `0.2.0`", "")?,
+        3
+    );
+    assert_eq!(
+        repo.get_unreleased_changes("example", "")?,
+        ["Fix a bug\n\nDetails"]
+    );
+
+    let release = repo.get_changelog_release(
+        "example",
+        &Version::new(0, 2, 0),
+        "",
+        &LabelMapping::default(),
+    )?;
+
+    assert_eq!(
+        release.changesets().cloned().collect::<Vec<_>>(),
+        [Changeset::fixed().with_change(
+            Change::new("Fix a bug")
+                .with_url(
+                    "!2",
+                    "https://gitlab.com/group/subgroup/example/-/merge_requests/2"
+                )
+                .with_author("one")
+        )]
+    );
+
+    repo.update_branch("/release/0.2.0", "c3")?;
+    repo.create_release("0.2.0", "c4", "0.2.0", "Notes", false, true)?;
+
+    let requests = server.requests();
+    let commit = requests
+        .iter()
+        .find(|request| request.method == "POST" && request.path.ends_with("/repository/commits"))
+        .unwrap();
+
+    assert!(
+        commit
+            .body
+            .contains(r#""action":"update","file_path":"README.md""#)
+    );
+    assert!(
+        commit
+            .body
+            .contains(r#""action":"create","file_path":"CHANGELOG.md""#)
+    );
+    assert!(
+        requests
+            .iter()
+            .filter(|request| request.method == "POST"
+                && request.path.ends_with("/repository/branches"))
+            .all(|request| request.query.starts_with("branch=release%2F0.2.0&"))
+    );
+    assert!(
+        requests
+            .iter()
+            .any(|request| request.path.ends_with("/releases") && request.body.contains("Notes"))
+    );
+
+    Ok(())
+}
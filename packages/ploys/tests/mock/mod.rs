@@ -0,0 +1,106 @@
+//! A mock HTTP server shared by the integration tests.
+
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A request received by the mock server.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub query: String,
+    pub body: String,
+}
+
+/// A minimal HTTP server that responds to requests with the given handler.
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    /// Starts the server on a random local port.
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&Request) -> (u16, String) + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = requests.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+
+                reader.read_line(&mut line).unwrap();
+
+                let mut parts = line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_owned();
+                let target = parts.next().unwrap_or_default().to_owned();
+                let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+
+                let mut content_length = 0;
+
+                loop {
+                    let mut header = String::new();
+
+                    reader.read_line(&mut header).unwrap();
+
+                    if header.trim().is_empty() {
+                        break;
+                    }
+
+                    if let Some((name, value)) = header.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+
+                reader.read_exact(&mut body).unwrap();
+
+                let request = Request {
+                    method,
+                    path: path.to_owned(),
+                    query: query.to_owned(),
+                    body: String::from_utf8(body).unwrap(),
+                };
+
+                let (status, body) = handler(&request);
+
+                recorded.lock().unwrap().push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// Gets the server URL.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Gets the requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}